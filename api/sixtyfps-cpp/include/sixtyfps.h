@@ -17,8 +17,10 @@ LICENSE END */
 #include <vector>
 #include <memory>
 #include <algorithm>
+#include <array>
 #include <iostream> // FIXME: remove: iostream always bring it lots of code so we should not have it in this header
 #include <chrono>
+#include <functional>
 
 namespace sixtyfps::cbindgen_private {
 // Workaround https://github.com/eqrion/cbindgen/issues/43
@@ -37,6 +39,12 @@ extern const cbindgen_private::ItemVTable RectangleVTable;
 extern const cbindgen_private::ItemVTable BorderRectangleVTable;
 extern const cbindgen_private::ItemVTable TextVTable;
 extern const cbindgen_private::ItemVTable TouchAreaVTable;
+extern const cbindgen_private::ItemVTable PinchAreaVTable;
+extern const cbindgen_private::ItemVTable DragAreaVTable;
+extern const cbindgen_private::ItemVTable DropAreaVTable;
+extern const cbindgen_private::ItemVTable FocusScopeVTable;
+extern const cbindgen_private::ItemVTable ShortcutVTable;
+extern const cbindgen_private::ItemVTable TimerItemVTable;
 extern const cbindgen_private::ItemVTable ImageVTable;
 extern const cbindgen_private::ItemVTable ClippedImageVTable;
 extern const cbindgen_private::ItemVTable PathVTable;
@@ -53,6 +61,8 @@ extern const cbindgen_private::ItemVTable NativeGroupBoxVTable;
 extern const cbindgen_private::ItemVTable NativeLineEditVTable;
 extern const cbindgen_private::ItemVTable NativeScrollViewVTable;
 extern const cbindgen_private::ItemVTable NativeStandardListViewItemVTable;
+extern const cbindgen_private::ItemVTable NativeTreeViewItemVTable;
+extern const cbindgen_private::ItemVTable NativeTableHeaderSectionVTable;
 extern const cbindgen_private::ItemVTable NativeComboBoxVTable;
 }
 }
@@ -67,12 +77,22 @@ using ItemVisitorRefMut = vtable::VRefMut<cbindgen_private::ItemVisitorVTable>;
 }
 using cbindgen_private::ComponentRc;
 using cbindgen_private::EasingCurve;
+using cbindgen_private::KeyFrame;
 using cbindgen_private::PropertyAnimation;
 using cbindgen_private::Slice;
 using cbindgen_private::TextHorizontalAlignment;
 using cbindgen_private::TextVerticalAlignment;
 using cbindgen_private::TraversalOrder;
 using cbindgen_private::ImageFit;
+using cbindgen_private::KeyboardEvent;
+using cbindgen_private::KeyboardModifierState;
+using cbindgen_private::DropEvent;
+using cbindgen_private::MouseCursor;
+using cbindgen_private::AccessibleRole;
+using cbindgen_private::PointerEvent;
+using cbindgen_private::PointerEventButton;
+using cbindgen_private::PointerEventKind;
+using cbindgen_private::SortOrder;
 
 namespace private_api {
 using ItemTreeNode = cbindgen_private::ItemTreeNode<uint8_t>;
@@ -137,12 +157,18 @@ private:
 using cbindgen_private::BorderRectangle;
 using cbindgen_private::Clip;
 using cbindgen_private::ClippedImage;
+using cbindgen_private::DragArea;
+using cbindgen_private::DropArea;
 using cbindgen_private::Flickable;
+using cbindgen_private::FocusScope;
 using cbindgen_private::Image;
 using cbindgen_private::Path;
+using cbindgen_private::PinchArea;
 using cbindgen_private::Rectangle;
+using cbindgen_private::Shortcut;
 using cbindgen_private::Text;
 using cbindgen_private::TextInput;
+using cbindgen_private::TimerItem;
 using cbindgen_private::TouchArea;
 using cbindgen_private::Window;
 
@@ -156,6 +182,8 @@ using cbindgen_private::NativeSlider;
 using cbindgen_private::NativeSpinBox;
 using cbindgen_private::NativeStandardListViewItem;
 using cbindgen_private::NativeStyleMetrics;
+using cbindgen_private::NativeTableHeaderSection;
+using cbindgen_private::NativeTreeViewItem;
 
 namespace private_api {
 constexpr inline ItemTreeNode make_item_node(std::uintptr_t offset,
@@ -346,24 +374,32 @@ public:
     /// Internal function called by the view to register itself
     void attach_peer(ModelPeer p) { peers.push_back(std::move(p)); }
 
+    /// Register a dependency to this model in the currently evaluating binding, so that
+    /// the binding is re-evaluated when a row is changed, added or removed.
+    void track_changes() const { tracker.get(); }
+
 protected:
     /// Notify the views that a specific row was changed
     void row_changed(int row)
     {
+        mark_dirty();
         for_each_peers([=](auto peer) { peer->row_changed(row); });
     }
     /// Notify the views that rows were added
     void row_added(int index, int count)
     {
+        mark_dirty();
         for_each_peers([=](auto peer) { peer->row_added(index, count); });
     }
     /// Notify the views that rows were removed
     void row_removed(int index, int count)
     {
+        mark_dirty();
         for_each_peers([=](auto peer) { peer->row_removed(index, count); });
     }
 
 private:
+    void mark_dirty() { tracker.set(++change_count); }
     template<typename F>
     void for_each_peers(const F &f)
     {
@@ -378,6 +414,8 @@ private:
                     peers.end());
     }
     std::vector<ModelPeer> peers;
+    int change_count = 0;
+    Property<int> tracker { 0 };
 };
 
 /// A Model backed by an array of constant size
@@ -439,6 +477,414 @@ public:
         data.erase(data.begin() + index);
         this->row_removed(index, 1);
     }
+
+    /// Inserts a row at the given index, shifting the rows after it
+    void insert(int index, const ModelData &value)
+    {
+        data.insert(data.begin() + index, value);
+        this->row_added(index, 1);
+    }
+
+    /// Append the rows in the range [begin, end) at the end of the model
+    template<typename It>
+    void extend(It begin, It end)
+    {
+        int old_size = data.size();
+        data.insert(data.end(), begin, end);
+        if (int(data.size()) > old_size)
+            this->row_added(old_size, data.size() - old_size);
+    }
+
+    /// Remove all the rows from the model
+    void clear()
+    {
+        int old_size = data.size();
+        data.clear();
+        if (old_size > 0)
+            this->row_removed(0, old_size);
+    }
+
+    /// Exchange the data of two rows
+    void swap(int a, int b)
+    {
+        if (a == b)
+            return;
+        std::swap(data[a], data[b]);
+        this->row_changed(a);
+        this->row_changed(b);
+    }
+
+    /// Move the row at index `from` so that it ends up at index `to`.
+    /// The rows in between are reported as changed, so that the views keep their items.
+    void move_row(int from, int to)
+    {
+        if (from == to)
+            return;
+        if (from < to)
+            std::rotate(data.begin() + from, data.begin() + from + 1, data.begin() + to + 1);
+        else
+            std::rotate(data.begin() + to, data.begin() + from, data.begin() + from + 1);
+        for (int row = std::min(from, to); row <= std::max(from, to); ++row)
+            this->row_changed(row);
+    }
+
+    /// Replace the content of the model.
+    /// The rows that exist in both the old and the new content are reported as changed, and only
+    /// the difference in length is reported as added or removed rows.
+    void set_vec(std::vector<ModelData> array)
+    {
+        int old_size = data.size();
+        int new_size = array.size();
+        data = std::move(array);
+        for (int row = 0; row < std::min(old_size, new_size); ++row)
+            this->row_changed(row);
+        if (new_size > old_size)
+            this->row_added(old_size, new_size - old_size);
+        else if (old_size > new_size)
+            this->row_removed(new_size, old_size - new_size);
+    }
+
+    /// Only keep the rows for which `predicate` returns true.
+    /// Consecutive removed rows are reported together.
+    template<typename Predicate>
+    void retain(Predicate predicate)
+    {
+        // Runs of removed rows, as (index, count) in the model after the previous runs were removed
+        std::vector<std::pair<int, int>> removed;
+        std::vector<ModelData> kept;
+        for (auto &value : data) {
+            if (predicate(std::as_const(value))) {
+                kept.push_back(std::move(value));
+            } else if (!removed.empty() && removed.back().first == int(kept.size())) {
+                removed.back().second++;
+            } else {
+                removed.emplace_back(kept.size(), 1);
+            }
+        }
+        data = std::move(kept);
+        for (auto [index, count] : removed)
+            this->row_removed(index, count);
+    }
+};
+
+/// A Model that contains the rows of a source model that are accepted by a filter function.
+/// The rows are in the same order as in the source model.
+template<typename ModelData>
+class FilterModel : public Model<ModelData>
+{
+    struct Observer : AbstractRepeaterView
+    {
+        FilterModel *model;
+        Observer(FilterModel *model) : model(model) { }
+        void row_added(int index, int count) override { model->source_row_added(index, count); }
+        void row_removed(int index, int count) override
+        {
+            model->source_row_removed(index, count);
+        }
+        void row_changed(int index) override { model->source_row_changed(index); }
+    };
+
+    std::shared_ptr<Model<ModelData>> source;
+    std::function<bool(const ModelData &)> filter;
+    /// The row in the source model of each row of this model, in increasing order
+    std::vector<int> mapping;
+    std::shared_ptr<Observer> observer;
+
+    std::vector<int> accepted_rows() const
+    {
+        std::vector<int> rows;
+        for (int i = 0; i < source->row_count(); ++i) {
+            if (filter(source->row_data(i)))
+                rows.push_back(i);
+        }
+        return rows;
+    }
+
+    void source_row_added(int index, int count)
+    {
+        auto pos = std::lower_bound(mapping.begin(), mapping.end(), index) - mapping.begin();
+        for (auto it = mapping.begin() + pos; it != mapping.end(); ++it)
+            *it += count;
+        std::vector<int> added;
+        for (int row = index; row < index + count; ++row) {
+            if (filter(source->row_data(row)))
+                added.push_back(row);
+        }
+        if (!added.empty()) {
+            mapping.insert(mapping.begin() + pos, added.begin(), added.end());
+            this->row_added(pos, added.size());
+        }
+    }
+
+    void source_row_removed(int index, int count)
+    {
+        auto begin = std::lower_bound(mapping.begin(), mapping.end(), index);
+        auto end = std::lower_bound(begin, mapping.end(), index + count);
+        int pos = begin - mapping.begin();
+        int removed = end - begin;
+        for (auto it = mapping.erase(begin, end); it != mapping.end(); ++it)
+            *it -= count;
+        if (removed > 0)
+            this->row_removed(pos, removed);
+    }
+
+    void source_row_changed(int row)
+    {
+        bool accepted = filter(source->row_data(row));
+        auto it = std::lower_bound(mapping.begin(), mapping.end(), row);
+        int pos = it - mapping.begin();
+        bool found = it != mapping.end() && *it == row;
+        if (found && accepted) {
+            this->row_changed(pos);
+        } else if (found) {
+            mapping.erase(it);
+            this->row_removed(pos, 1);
+        } else if (accepted) {
+            mapping.insert(it, row);
+            this->row_added(pos, 1);
+        }
+    }
+
+public:
+    /// Creates a model with the rows of `source` for which `filter` returns true
+    FilterModel(std::shared_ptr<Model<ModelData>> source,
+                std::function<bool(const ModelData &)> filter)
+        : source(std::move(source)),
+          filter(std::move(filter)),
+          observer(std::make_shared<Observer>(this))
+    {
+        mapping = accepted_rows();
+        this->source->attach_peer(observer);
+    }
+    // The observer attached to the source model points to this model, so it cannot be copied or moved
+    FilterModel(const FilterModel &) = delete;
+    FilterModel(FilterModel &&) = delete;
+    FilterModel &operator=(const FilterModel &) = delete;
+    FilterModel &operator=(FilterModel &&) = delete;
+
+    int row_count() const override { return mapping.size(); }
+    ModelData row_data(int i) const override { return source->row_data(mapping[i]); }
+    void set_row_data(int i, const ModelData &value) override
+    {
+        source->set_row_data(mapping[i], value);
+    }
+
+    /// Applies the filter again on all the rows of the source model.
+    /// Call this when the filter function gives different results.
+    void reset()
+    {
+        auto new_mapping = accepted_rows();
+        // Both mappings are sorted: walk them together, removing or inserting the rows that differ
+        size_t pos = 0;
+        size_t new_pos = 0;
+        while (pos < mapping.size() || new_pos < new_mapping.size()) {
+            if (pos < mapping.size() && new_pos < new_mapping.size()
+                && mapping[pos] == new_mapping[new_pos]) {
+                ++pos;
+                ++new_pos;
+            } else if (pos < mapping.size()
+                       && (new_pos == new_mapping.size() || mapping[pos] < new_mapping[new_pos])) {
+                size_t end = pos;
+                while (end < mapping.size()
+                       && (new_pos == new_mapping.size() || mapping[end] < new_mapping[new_pos]))
+                    ++end;
+                mapping.erase(mapping.begin() + pos, mapping.begin() + end);
+                this->row_removed(pos, end - pos);
+            } else {
+                size_t end = new_pos;
+                while (end < new_mapping.size()
+                       && (pos == mapping.size() || new_mapping[end] < mapping[pos]))
+                    ++end;
+                mapping.insert(mapping.begin() + pos, new_mapping.begin() + new_pos,
+                               new_mapping.begin() + end);
+                this->row_added(pos, end - new_pos);
+                pos += end - new_pos;
+                new_pos = end;
+            }
+        }
+    }
+
+    /// Returns the row in the source model that corresponds to the given row of this model
+    int source_row(int row) const { return mapping[row]; }
+};
+
+/// A Model that contains the rows of a source model sorted with a comparison function.
+/// `less` must return true if the first argument is ordered before the second one. Rows that
+/// compare equal keep the order they have in the source model.
+template<typename ModelData>
+class SortModel : public Model<ModelData>
+{
+    struct Observer : AbstractRepeaterView
+    {
+        SortModel *model;
+        Observer(SortModel *model) : model(model) { }
+        void row_added(int index, int count) override { model->source_row_added(index, count); }
+        void row_removed(int index, int count) override
+        {
+            model->source_row_removed(index, count);
+        }
+        void row_changed(int index) override { model->source_row_changed(index); }
+    };
+
+    std::shared_ptr<Model<ModelData>> source;
+    std::function<bool(const ModelData &, const ModelData &)> less;
+    /// The row in the source model of each row of this model
+    std::vector<int> mapping;
+    std::shared_ptr<Observer> observer;
+
+    std::vector<int> sorted_rows() const
+    {
+        std::vector<ModelData> data;
+        for (int i = 0; i < source->row_count(); ++i)
+            data.push_back(source->row_data(i));
+        std::vector<int> rows(data.size());
+        for (size_t i = 0; i < rows.size(); ++i)
+            rows[i] = i;
+        std::stable_sort(rows.begin(), rows.end(),
+                         [&](int a, int b) { return less(data[a], data[b]); });
+        return rows;
+    }
+
+    /// Returns the position at which the source row with the given data needs to be inserted
+    int insert_position(int source_row, const ModelData &data) const
+    {
+        auto it = std::lower_bound(mapping.begin(), mapping.end(), source_row, [&](int other, int) {
+            auto other_data = source->row_data(other);
+            if (less(other_data, data))
+                return true;
+            if (less(data, other_data))
+                return false;
+            return other < source_row;
+        });
+        return it - mapping.begin();
+    }
+
+    void source_row_added(int index, int count)
+    {
+        for (auto &row : mapping) {
+            if (row >= index)
+                row += count;
+        }
+        for (int row = index; row < index + count; ++row) {
+            int pos = insert_position(row, source->row_data(row));
+            mapping.insert(mapping.begin() + pos, row);
+            this->row_added(pos, 1);
+        }
+    }
+
+    void source_row_removed(int index, int count)
+    {
+        std::vector<int> removed;
+        for (size_t pos = 0; pos < mapping.size(); ++pos) {
+            if (mapping[pos] >= index + count)
+                mapping[pos] -= count;
+            else if (mapping[pos] >= index)
+                removed.push_back(pos);
+        }
+        // Remove the consecutive rows together, starting from the end so the positions stay valid
+        while (!removed.empty()) {
+            int last = removed.back();
+            int first = last;
+            removed.pop_back();
+            while (!removed.empty() && removed.back() == first - 1) {
+                first = removed.back();
+                removed.pop_back();
+            }
+            mapping.erase(mapping.begin() + first, mapping.begin() + last + 1);
+            this->row_removed(first, last - first + 1);
+        }
+    }
+
+    void source_row_changed(int row)
+    {
+        auto it = std::find(mapping.begin(), mapping.end(), row);
+        if (it == mapping.end())
+            return;
+        int old_pos = it - mapping.begin();
+        mapping.erase(it);
+        int new_pos = insert_position(row, source->row_data(row));
+        if (new_pos == old_pos) {
+            mapping.insert(mapping.begin() + new_pos, row);
+            this->row_changed(new_pos);
+        } else {
+            this->row_removed(old_pos, 1);
+            mapping.insert(mapping.begin() + new_pos, row);
+            this->row_added(new_pos, 1);
+        }
+    }
+
+public:
+    /// Creates a model with the rows of `source` sorted according to `less`
+    SortModel(std::shared_ptr<Model<ModelData>> source,
+              std::function<bool(const ModelData &, const ModelData &)> less)
+        : source(std::move(source)),
+          less(std::move(less)),
+          observer(std::make_shared<Observer>(this))
+    {
+        mapping = sorted_rows();
+        this->source->attach_peer(observer);
+    }
+    // The observer attached to the source model points to this model, so it cannot be copied or moved
+    SortModel(const SortModel &) = delete;
+    SortModel(SortModel &&) = delete;
+    SortModel &operator=(const SortModel &) = delete;
+    SortModel &operator=(SortModel &&) = delete;
+
+    int row_count() const override { return mapping.size(); }
+    ModelData row_data(int i) const override { return source->row_data(mapping[i]); }
+    void set_row_data(int i, const ModelData &value) override
+    {
+        source->set_row_data(mapping[i], value);
+    }
+
+    /// Sorts all the rows again.
+    /// Call this when the comparison function gives different results.
+    void reset()
+    {
+        auto old_mapping = std::exchange(mapping, sorted_rows());
+        for (size_t row = 0; row < mapping.size(); ++row) {
+            if (old_mapping[row] != mapping[row])
+                this->row_changed(row);
+        }
+    }
+
+    /// Returns the row in the source model that corresponds to the given row of this model
+    int source_row(int row) const { return mapping[row]; }
+};
+
+/// A Model whose rows are the rows of a source model converted with a mapping function.
+template<typename SourceData, typename ModelData>
+class MapModel : public Model<ModelData>
+{
+    struct Observer : AbstractRepeaterView
+    {
+        MapModel *model;
+        Observer(MapModel *model) : model(model) { }
+        void row_added(int index, int count) override { model->row_added(index, count); }
+        void row_removed(int index, int count) override { model->row_removed(index, count); }
+        void row_changed(int index) override { model->row_changed(index); }
+    };
+
+    std::shared_ptr<Model<SourceData>> source;
+    std::function<ModelData(const SourceData &)> map;
+    std::shared_ptr<Observer> observer;
+
+public:
+    /// Creates a model whose rows are the rows of `source` converted by `map`
+    MapModel(std::shared_ptr<Model<SourceData>> source,
+             std::function<ModelData(const SourceData &)> map)
+        : source(std::move(source)), map(std::move(map)), observer(std::make_shared<Observer>(this))
+    {
+        this->source->attach_peer(observer);
+    }
+    // The observer attached to the source model points to this model, so it cannot be copied or moved
+    MapModel(const MapModel &) = delete;
+    MapModel(MapModel &&) = delete;
+    MapModel &operator=(const MapModel &) = delete;
+    MapModel &operator=(MapModel &&) = delete;
+
+    int row_count() const override { return source->row_count(); }
+    ModelData row_data(int i) const override { return map(source->row_data(i)); }
 };
 
 template<typename C, typename ModelData>
@@ -455,8 +901,21 @@ class Repeater
             std::optional<ComponentHandle<C>> ptr;
         };
         std::vector<ComponentWithState> data;
+        /// The components that were removed but are kept alive until their exit transition is over
+        std::vector<ComponentHandle<C>> exiting;
         bool is_dirty = true;
 
+        void remove_components(typename std::vector<ComponentWithState>::iterator begin,
+                               typename std::vector<ComponentWithState>::iterator end)
+        {
+            for (auto it = begin; it != end; ++it) {
+                if (it->ptr && (*it->ptr)->start_exit()) {
+                    exiting.push_back(*it->ptr);
+                }
+            }
+            data.erase(begin, end);
+        }
+
         void row_added(int index, int count) override
         {
             is_dirty = true;
@@ -471,7 +930,7 @@ class Repeater
         void row_removed(int index, int count) override
         {
             is_dirty = true;
-            data.erase(data.begin() + index, data.begin() + index + count);
+            remove_components(data.begin() + index, data.begin() + index + count);
             for (std::size_t i = index; i < data.size(); ++i) {
                 // all the indexes are dirty
                 data[i].state = State::Dirty;
@@ -493,7 +952,12 @@ public:
     void ensure_updated(const Parent *parent) const
     {
         if (model.is_dirty()) {
+            auto old_inner = std::move(inner);
             inner = std::make_shared<RepeaterInner>();
+            if (old_inner) {
+                inner->exiting = std::move(old_inner->exiting);
+                inner->remove_components(old_inner->data.begin(), old_inner->data.end());
+            }
             if (auto m = model.get()) {
                 m->attach_peer(inner);
             }
@@ -503,20 +967,40 @@ public:
             inner->is_dirty = false;
             if (auto m = model.get()) {
                 int count = m->row_count();
+                if (std::size_t(count) < inner->data.size()) {
+                    inner->remove_components(inner->data.begin() + count, inner->data.end());
+                }
                 inner->data.resize(count);
+                std::vector<ComponentHandle<C>> created;
                 for (int i = 0; i < count; ++i) {
                     auto &c = inner->data[i];
-                    if (!c.ptr) {
+                    bool is_new = !c.ptr;
+                    if (is_new) {
                         c.ptr = C::create(parent);
                     }
                     if (c.state == RepeaterInner::State::Dirty) {
                         (*c.ptr)->update_data(i, m->row_data(i));
                     }
+                    if (is_new) {
+                        created.push_back(*c.ptr);
+                    }
+                }
+                // The init callbacks run once all the components are updated
+                for (const auto &c : created) {
+                    c->init();
                 }
             } else {
-                inner->data.clear();
+                inner->remove_components(inner->data.begin(), inner->data.end());
             }
         }
+
+        // Drop the components whose exit transition is over
+        if (inner) {
+            auto &exiting = inner->exiting;
+            exiting.erase(std::remove_if(exiting.begin(), exiting.end(),
+                                         [](const auto &c) { return c->exit_finished(); }),
+                          exiting.end());
+        }
     }
 
     template<typename Parent>
@@ -534,9 +1018,15 @@ public:
 
     intptr_t visit(TraversalOrder order, private_api::ItemVisitorRefMut visitor) const
     {
-        for (std::size_t i = 0; i < inner->data.size(); ++i) {
-            int index = order == TraversalOrder::BackToFront ? i : inner->data.size() - 1 - i;
-            auto ref = item_at(index);
+        // The exiting components are only visited for rendering, after the others: they don't
+        // get the input events or the focus
+        std::size_t count = inner->data.size()
+                + (order == TraversalOrder::BackToFront ? inner->exiting.size() : 0);
+        for (std::size_t i = 0; i < count; ++i) {
+            int index = order == TraversalOrder::BackToFront ? i : count - 1 - i;
+            auto ref = std::size_t(index) < inner->data.size()
+                    ? item_at(index)
+                    : exiting_item_at(index - inner->data.size());
             if (ref.vtable->visit_children_item(ref, -1, order, visitor) != -1) {
                 return index;
             }
@@ -550,6 +1040,12 @@ public:
         return { &C::component_type, const_cast<C *>(&(**x.ptr)) };
     }
 
+    vtable::VRef<private_api::ComponentVTable> exiting_item_at(int i) const
+    {
+        const auto &x = inner->exiting.at(i);
+        return { &C::component_type, const_cast<C *>(&(*x)) };
+    }
+
     void compute_layout(cbindgen_private::Rect parent_rect) const
     {
         if (!inner)
@@ -598,6 +1094,24 @@ Flickable::~Flickable()
     sixtyfps_flickable_data_free(&data);
 }
 
+TimerItem::TimerItem()
+{
+    sixtyfps_timer_item_data_init(&data);
+}
+TimerItem::~TimerItem()
+{
+    sixtyfps_timer_item_data_free(&data);
+}
+
+Shortcut::Shortcut()
+{
+    sixtyfps_shortcut_data_init(&data);
+}
+Shortcut::~Shortcut()
+{
+    sixtyfps_shortcut_data_free(&data);
+}
+
 NativeStyleMetrics::NativeStyleMetrics()
 {
     sixtyfps_init_native_style_metrics(this);
@@ -617,7 +1131,80 @@ bool operator!=(const StandardListViewItem &a, const StandardListViewItem &b)
 }
 }
 
+using cbindgen_private::TreeViewItem;
+namespace cbindgen_private {
+bool operator==(const TreeViewItem &a, const TreeViewItem &b)
+{
+    static_assert(sizeof(TreeViewItem) == sizeof(std::tuple<SharedString, int, bool, bool>),
+                  "must update to cover all fields");
+    return a.text == b.text && a.depth == b.depth && a.has_children == b.has_children
+            && a.expanded == b.expanded;
+}
+bool operator!=(const TreeViewItem &a, const TreeViewItem &b)
+{
+    return !(a == b);
+}
+}
+
+using cbindgen_private::TableColumn;
+namespace cbindgen_private {
+bool operator==(const TableColumn &a, const TableColumn &b)
+{
+    static_assert(sizeof(TableColumn) == sizeof(std::tuple<SharedString, float, SortOrder>),
+                  "must update to cover all fields");
+    return a.title == b.title && a.width == b.width && a.sort_order == b.sort_order;
+}
+bool operator!=(const TableColumn &a, const TableColumn &b)
+{
+    return !(a == b);
+}
+}
+
+namespace private_api {
+/// Called by the generated code for the `@tr(...)` expressions
+template<typename... Args>
+inline SharedString translate(const SharedString &original, const SharedString &context,
+                              const SharedString &plural, int n, const Args &...arguments)
+{
+    std::array<SharedString, sizeof...(Args)> args { arguments... };
+    SharedString result;
+    cbindgen_private::sixtyfps_translate(&original, &context, &plural, n, args.data(),
+                                         args.size(), &result);
+    return result;
+}
+}
+
+/// Sets the language used to translate the strings marked with `@tr(...)`, for example
+/// "fr" or "de_CH". The bindings that use translated strings are re-evaluated.
+inline void set_language(std::string_view language)
+{
+    SharedString l(language);
+    cbindgen_private::sixtyfps_translations_set_language(&l);
+}
+
+/// Loads the gettext catalog at \a path for the given \a language. The file is read as a
+/// `.mo` file if it has the `.mo` extension, and as a `.po` file otherwise.
+/// Returns false if the file cannot be loaded.
+inline bool load_translation_catalog(std::string_view language, std::string_view path)
+{
+    SharedString l(language), p(path);
+    return cbindgen_private::sixtyfps_translations_load_catalog(&l, &p);
+}
+
+/// Changes the speed of all the animations: 1 is the normal speed, 0.5 runs the animations
+/// twice as slowly, and 0 disables them so that the properties jump to their final value.
+inline void set_animation_speed(float speed)
+{
+    cbindgen_private::sixtyfps_set_animation_speed(speed);
+}
+
 namespace private_api {
+/// Called by the generated code for the `animation-finished` callback of transitions
+inline int64_t animation_elapsed_time(const StateInfo &state)
+{
+    return cbindgen_private::sixtyfps_animation_elapsed_time(state.change_time);
+}
+
 template<int Major, int Minor, int Patch>
 struct VersionCheckHelper
 {
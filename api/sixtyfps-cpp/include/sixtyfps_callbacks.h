@@ -28,9 +28,10 @@ struct Callback<Ret(Arg...)>
     {
         cbindgen_private::sixtyfps_callback_set_handler(
                 &inner,
-                [](void *user_data, const void *arg) {
-                    auto *p = reinterpret_cast<const Pair*>(arg);
-                    *p->first = std::apply(*reinterpret_cast<F *>(user_data), p->second);
+                [](void *user_data, const void *arg, void *ret) {
+                    *reinterpret_cast<Ret *>(ret) =
+                            std::apply(*reinterpret_cast<F *>(user_data),
+                                       *reinterpret_cast<const Tuple *>(arg));
                 },
                 new F(std::move(binding)),
                 [](void *user_data) { delete reinterpret_cast<F *>(user_data); });
@@ -39,14 +40,13 @@ struct Callback<Ret(Arg...)>
     Ret emit(const Arg &...arg) const
     {
         Ret r{};
-        Pair p = std::pair{ &r, Tuple{arg...} };
-        cbindgen_private::sixtyfps_callback_emit(&inner, &p);
+        Tuple tuple{arg...};
+        cbindgen_private::sixtyfps_callback_emit(&inner, &tuple, &r);
         return r;
     }
 
 private:
     using Tuple = std::tuple<Arg...>;
-    using Pair = std::pair<Ret *, Tuple>;
     cbindgen_private::CallbackOpaque inner;
 };
 
@@ -65,7 +65,7 @@ struct Callback<void(Arg...)>
     {
         cbindgen_private::sixtyfps_callback_set_handler(
                 &inner,
-                [](void *user_data, const void *arg) {
+                [](void *user_data, const void *arg, void *) {
                     std::apply(*reinterpret_cast<F *>(user_data),
                                *reinterpret_cast<const Tuple*>(arg));
                 },
@@ -76,7 +76,8 @@ struct Callback<void(Arg...)>
     void emit(const Arg &...arg) const
     {
         Tuple tuple{arg...};
-        cbindgen_private::sixtyfps_callback_emit(&inner, &tuple);
+        // The return value is unused, but must point to something valid
+        cbindgen_private::sixtyfps_callback_emit(&inner, &tuple, &tuple);
     }
 
 private:
@@ -85,6 +86,22 @@ private:
 };
 
 
+namespace private_api {
+template<typename A, typename R>
+struct CallbackSignatureHelper
+{
+    using Result = R(A);
+};
+template<typename R>
+struct CallbackSignatureHelper<void, R>
+{
+    using Result = R();
+};
+/// Used by the generated headers: Callback<Arg, Ret> in rust maps to CallbackHelper<Arg, Ret>
+template<typename A, typename R = void>
+using CallbackHelper = Callback<typename CallbackSignatureHelper<A, R>::Result>;
+}
+
 }
 
 
@@ -192,7 +192,17 @@ void Property<Color>::set_animated_value(const Color &new_value,
                                          const cbindgen_private::PropertyAnimation &animation_data) const
 {
     cbindgen_private::sixtyfps_property_set_animated_value_color(&inner, value, new_value,
-                                                                 &animation_data);
+                                                                 &animation_data, nullptr, nullptr,
+                                                                 nullptr);
+}
+
+namespace private_api {
+inline void sixtyfps_property_set_animated_value_helper(const cbindgen_private::PropertyHandleOpaque *handle,
+        const Color &from, const Color &to, const cbindgen_private::PropertyAnimation *animation_data,
+        cbindgen_private::PropertyAnimation (*compute_animation_details)(void *),
+        void *user_data, void (*drop_user_data)(void*)) {
+    cbindgen_private::sixtyfps_property_set_animated_value_color(handle, from, to, animation_data, compute_animation_details, user_data, drop_user_data);
+}
 }
 
 }
@@ -10,6 +10,7 @@ LICENSE END */
 #pragma once
 #include <string_view>
 #include <memory>
+#include <optional>
 
 namespace sixtyfps {
 namespace cbindgen_private {
@@ -46,6 +47,26 @@ void sixtyfps_property_set_animated_binding_helper(const cbindgen_private::Prope
     cbindgen_private::sixtyfps_property_set_animated_binding_color(handle, binding, user_data, drop_user_data, animation_data, transition_data);
 }
 
+void sixtyfps_property_set_animated_value_helper(const cbindgen_private::PropertyHandleOpaque *handle,
+        int32_t from, int32_t to, const cbindgen_private::PropertyAnimation *animation_data,
+        cbindgen_private::PropertyAnimation (*compute_animation_details)(void *),
+        void *user_data, void (*drop_user_data)(void*)) {
+    cbindgen_private::sixtyfps_property_set_animated_value_int(handle, from, to, animation_data, compute_animation_details, user_data, drop_user_data);
+}
+
+void sixtyfps_property_set_animated_value_helper(const cbindgen_private::PropertyHandleOpaque *handle,
+        float from, float to, const cbindgen_private::PropertyAnimation *animation_data,
+        cbindgen_private::PropertyAnimation (*compute_animation_details)(void *),
+        void *user_data, void (*drop_user_data)(void*)) {
+    cbindgen_private::sixtyfps_property_set_animated_value_float(handle, from, to, animation_data, compute_animation_details, user_data, drop_user_data);
+}
+
+// Defined in sixtyfps_color.h
+inline void sixtyfps_property_set_animated_value_helper(const cbindgen_private::PropertyHandleOpaque *handle,
+        const Color &from, const Color &to, const cbindgen_private::PropertyAnimation *animation_data,
+        cbindgen_private::PropertyAnimation (*compute_animation_details)(void *),
+        void *user_data, void (*drop_user_data)(void*));
+
 
 
 }
@@ -97,6 +118,17 @@ struct Property
 
     inline void set_animated_value(const T &value,
                                    const cbindgen_private::PropertyAnimation &animation_data) const ;
+
+    /// Like set_animated_value, but the animation is re-computed while it runs, so that
+    /// changing its `running` field pauses or resumes it.
+    template<typename Anim>
+    inline void set_dynamic_animated_value(const T &new_value, Anim animation) const {
+        auto animation_data = animation();
+        private_api::sixtyfps_property_set_animated_value_helper(
+            &inner, value, new_value, &animation_data,
+            [](void *user_data) { return (*reinterpret_cast<Anim *>(user_data))(); },
+            new Anim(animation), [](void *user_data) { delete reinterpret_cast<Anim *>(user_data); });
+    }
     template<typename F>
     inline void set_animated_binding(F binding,
                                      const cbindgen_private::PropertyAnimation &animation_data) const {
@@ -128,6 +160,14 @@ struct Property
             });
     }
 
+    /// Like set_animated_binding, but the animation is re-computed when it starts and
+    /// while it runs, so that it can depend on other properties.
+    template<typename F, typename Anim>
+    inline void set_dynamic_animated_binding(F binding, Anim animation) const {
+        // The start instant is not changed, so the animation starts when the binding changes
+        set_animated_binding_for_transition(binding, [animation](uint64_t *) { return animation(); });
+    }
+
     bool is_dirty() const { return cbindgen_private::sixtyfps_property_is_dirty(&inner); }
 
     static void link_two_way(const Property<T> *p1, const Property<T> *p2) {
@@ -177,7 +217,8 @@ void Property<int32_t>::set_animated_value(
         const int32_t &new_value, const cbindgen_private::PropertyAnimation &animation_data) const
 {
     cbindgen_private::sixtyfps_property_set_animated_value_int(&inner, value, new_value,
-                                                               &animation_data);
+                                                               &animation_data, nullptr, nullptr,
+                                                               nullptr);
 }
 
 template<>
@@ -185,7 +226,8 @@ void Property<float>::set_animated_value(const float &new_value,
                                          const cbindgen_private::PropertyAnimation &animation_data) const
 {
     cbindgen_private::sixtyfps_property_set_animated_value_float(&inner, value, new_value,
-                                                                 &animation_data);
+                                                                 &animation_data, nullptr, nullptr,
+                                                                 nullptr);
 }
 
 
@@ -227,5 +269,43 @@ private:
     cbindgen_private::PropertyTrackerOpaque inner;
 };
 
+/// Calls a handler when the value returned by a function changes. The handler is called
+/// later, from the event loop, and not while evaluating bindings.
+struct ChangeTracker
+{
+    ChangeTracker() { cbindgen_private::sixtyfps_change_tracker_construct(&inner); }
+    ~ChangeTracker() { cbindgen_private::sixtyfps_change_tracker_drop(&inner); }
+    ChangeTracker(const ChangeTracker &) = delete;
+    ChangeTracker &operator=(const ChangeTracker &) = delete;
+
+    /// Call `notify` when the value returned by `eval` changes
+    template<typename FEval, typename FNotify>
+    void init(FEval eval, FNotify notify) const
+    {
+        using Value = std::decay_t<decltype(eval())>;
+        struct Data
+        {
+            FEval eval;
+            FNotify notify;
+            std::optional<Value> value;
+        };
+        cbindgen_private::sixtyfps_change_tracker_init(
+                &inner, new Data { std::move(eval), std::move(notify), {} },
+                [](void *user_data) { delete reinterpret_cast<Data *>(user_data); },
+                [](void *user_data) {
+                    auto data = reinterpret_cast<Data *>(user_data);
+                    auto new_value = data->eval();
+                    if (data->value && *data->value == new_value)
+                        return false;
+                    data->value = std::move(new_value);
+                    return true;
+                },
+                [](void *user_data) { reinterpret_cast<Data *>(user_data)->notify(); });
+    }
+
+private:
+    cbindgen_private::ChangeTrackerOpaque inner;
+};
+
 } // namespace sixtyfps
 
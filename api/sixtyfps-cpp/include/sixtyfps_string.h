@@ -87,7 +87,7 @@ struct SharedString
 
     /// Creates a new SharedString from the given number \a n. The string representation of the
     /// number uses a minimal formatting scheme: If \a n has no fractional part, the number will be
-    /// formatted as an integer.
+    /// formatted as an integer. Otherwise at most 6 decimals are shown, without trailing zeros.
     ///
     /// For example:
     /// \code
@@ -96,6 +96,60 @@ struct SharedString
     /// \endcode
     static SharedString from_number(double n) { return SharedString(n); }
 
+    /// Creates a new SharedString from the given number \a n with \a precision decimals, padded
+    /// to \a width characters with spaces, or with zeros after the sign if \a zero_padding is
+    /// true. With a negative precision, the number is formatted like from_number().
+    ///
+    /// For example:
+    /// \code
+    ///     auto str = sixtyfps::SharedString::from_formatted_number(3.14159, 0, 2, false); // "3.14"
+    ///     auto str2 = sixtyfps::SharedString::from_formatted_number(7, 3, 0, true); // "007"
+    /// \endcode
+    static SharedString from_formatted_number(double n, std::size_t width, int precision,
+                                              bool zero_padding)
+    {
+        SharedString s { Uninitialized {} };
+        cbindgen_private::sixtyfps_shared_string_from_formatted_number(&s, n, width, precision,
+                                                                      zero_padding);
+        return s;
+    }
+
+    /// Returns the number of characters (unicode code points) in this string.
+    std::size_t character_count() const
+    {
+        return cbindgen_private::sixtyfps_shared_string_character_count(this);
+    }
+
+    /// Returns a copy of this string converted to upper case.
+    SharedString to_uppercase() const
+    {
+        SharedString s { Uninitialized {} };
+        cbindgen_private::sixtyfps_shared_string_to_uppercase(&s, this);
+        return s;
+    }
+
+    /// Returns a copy of this string converted to lower case.
+    SharedString to_lowercase() const
+    {
+        SharedString s { Uninitialized {} };
+        cbindgen_private::sixtyfps_shared_string_to_lowercase(&s, this);
+        return s;
+    }
+
+    /// Returns the \a length characters of this string that start at the character \a start.
+    SharedString substring(std::size_t start, std::size_t length) const
+    {
+        SharedString s { Uninitialized {} };
+        cbindgen_private::sixtyfps_shared_string_substring(&s, this, start, length);
+        return s;
+    }
+
+    /// Returns true if \a other is contained in this string; otherwise returns false.
+    bool contains(std::string_view other) const
+    {
+        return std::string_view(*this).find(other) != std::string_view::npos;
+    }
+
     /// Returns true if \a is equal to \b; otherwise returns false.
     friend bool operator==(const SharedString &a, const SharedString &b)
     {
@@ -142,6 +196,11 @@ private:
     {
         cbindgen_private::sixtyfps_shared_string_from_number(this, n);
     }
+    /// The string data must be initialized by one of the sixtyfps_shared_string_* functions
+    struct Uninitialized
+    {
+    };
+    explicit SharedString(Uninitialized) { }
     void *inner; // opaque
 };
 }
@@ -24,6 +24,35 @@ inline void send_mouse_click(const ComponentHandle<Component> *component, float
         x, y, &(*component)->window);
 }
 
+template<typename Component>
+inline void send_mouse_button_click(const ComponentHandle<Component> *component, float x, float y,
+                                    PointerEventButton button)
+{
+    cbindgen_private::sixtyfps_send_mouse_button_click(
+        reinterpret_cast<const vtable::VRc<private_api::ComponentVTable>*>(component),
+        x, y, button, &(*component)->window);
+}
+
+template<typename Component>
+inline void send_mouse_drag(const ComponentHandle<Component> *component, float from_x,
+                            float from_y, float to_x, float to_y)
+{
+    cbindgen_private::sixtyfps_send_mouse_drag(
+        reinterpret_cast<const vtable::VRc<private_api::ComponentVTable>*>(component),
+        from_x, from_y, to_x, to_y, &(*component)->window);
+}
+
+using cbindgen_private::TouchPhase;
+
+template<typename Component>
+inline void send_touch_event(const ComponentHandle<Component> *component, uint64_t id, float x,
+                             float y, TouchPhase phase)
+{
+    cbindgen_private::sixtyfps_send_touch_event(
+        reinterpret_cast<const vtable::VRc<private_api::ComponentVTable>*>(component),
+        id, x, y, phase, &(*component)->window);
+}
+
 template<typename Component>
 inline void send_keyboard_string_sequence(const Component &component,
                                           const sixtyfps::SharedString &str)
@@ -31,6 +60,16 @@ inline void send_keyboard_string_sequence(const Component &component,
     cbindgen_private::send_keyboard_string_sequence(&str, &component.window);
 }
 
+template<typename Component>
+inline SharedString accessibility_tree_dump(const ComponentHandle<Component> *component)
+{
+    SharedString result;
+    cbindgen_private::sixtyfps_accessibility_tree_dump(
+        reinterpret_cast<const vtable::VRc<private_api::ComponentVTable>*>(component),
+        &result);
+    return result;
+}
+
 #define assert_eq(A, B)                                                                            \
     sixtyfps::testing::private_api::assert_eq_impl(A, B, #A, #B, __FILE__, __LINE__)
 
@@ -47,6 +47,10 @@ class Component {
     send_keyboard_string_sequence(s: String) {
         this.comp.send_keyboard_string_sequence(s)
     }
+
+    accessibility_tree_dump(): String {
+        return this.comp.accessibility_tree_dump()
+    }
 }
 
 /**
@@ -225,5 +229,8 @@ module.exports = {
     ArrayModel: ArrayModel,
     Timer: {
         singleShot: native.singleshot_timer,
-    }
+    },
+    setLanguage: native.set_language,
+    loadTranslationCatalog: native.load_translation_catalog,
+    setAnimationSpeed: native.set_animation_speed,
 };
@@ -93,6 +93,10 @@ fn attach_peer(&self, peer: sixtyfps_corelib::model::ModelPeer) {
         self.notify.attach(peer)
     }
 
+    fn track_changes(&self) {
+        self.notify.track_changes()
+    }
+
     fn set_row_data(&self, row: usize, data: Self::Data) {
         crate::run_with_global_contect(&|cx, persistent_context| {
             let row = JsNumber::new(cx, row as f64).as_value(cx);
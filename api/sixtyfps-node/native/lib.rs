@@ -228,7 +228,14 @@ fn to_eval_value<'cx>(
                     .collect::<Result<_, _>>()?,
             ))
         }
-        Type::Enumeration(_) => todo!(),
+        Type::Enumeration(e) => {
+            let value = val.to_string(cx)?.value();
+            if !e.values.contains(&value) {
+                return cx
+                    .throw_error(format!("'{}' is not a value of the enum {}", value, e.name));
+            }
+            Ok(Value::EnumerationValue(e.name.clone(), value))
+        }
         Type::Invalid
         | Type::Void
         | Type::Builtin(_)
@@ -281,10 +288,10 @@ fn to_js_value<'cx>(
             &format!("#{:02x}{:02x}{:02x}{:02x}", c.red(), c.green(), c.blue(), c.alpha()),
         )
         .as_value(cx),
-        Value::PathElements(_)
-        | Value::EasingCurve(_)
-        | Value::EnumerationValue(..)
-        | Value::Model(_) => todo!("converting {:?} to js has not been implemented", val),
+        Value::EnumerationValue(_, value) => JsString::new(cx, value.as_str()).as_value(cx),
+        Value::PathElements(_) | Value::EasingCurve(_) | Value::Model(_) => {
+            todo!("converting {:?} to js has not been implemented", val)
+        }
     })
 }
 
@@ -477,6 +484,15 @@ fn to_js_value<'cx>(
             Ok(JsUndefined::new().as_value(&mut cx))
         }
 
+        method accessibility_tree_dump(mut cx) {
+            let this = cx.this();
+            let lock = cx.lock();
+            let comp = this.borrow(&lock).0.clone();
+            let component = comp.ok_or(()).or_else(|()| cx.throw_error("Invalid type"))?;
+            let dump = sixtyfps_corelib::accessibility::dump_accessibility_tree(&vtable::VRc::into_dyn(component));
+            Ok(JsString::new(&mut cx, dump).as_value(&mut cx))
+        }
+
         method send_keyboard_string_sequence(mut cx) {
             let sequence = cx.argument::<JsString>(0)?.value();
             let this = cx.this();
@@ -542,9 +558,33 @@ fn singleshot_timer(mut cx: FunctionContext) -> JsResult<JsValue> {
     m.export_function("load", load)?;
     m.export_function("mock_elapsed_time", mock_elapsed_time)?;
     m.export_function("singleshot_timer", singleshot_timer)?;
+    m.export_function("set_language", set_language)?;
+    m.export_function("load_translation_catalog", load_translation_catalog)?;
+    m.export_function("set_animation_speed", set_animation_speed)?;
     Ok(())
 });
 
+/// Change the language used to translate the strings
+fn set_language(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let language = cx.argument::<JsString>(0)?.value();
+    sixtyfps_corelib::translations::set_language(&language);
+    Ok(JsUndefined::new().as_value(&mut cx))
+}
+
+fn set_animation_speed(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let speed = cx.argument::<JsNumber>(0)?.value();
+    sixtyfps_corelib::animations::set_animation_speed(speed as f32);
+    Ok(JsUndefined::new().as_value(&mut cx))
+}
+
+fn load_translation_catalog(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let language = cx.argument::<JsString>(0)?.value();
+    let path = cx.argument::<JsString>(1)?.value();
+    sixtyfps_corelib::translations::load_catalog(&language, std::path::Path::new(&path))
+        .or_else(|e| cx.throw_error(e.to_string()))?;
+    Ok(JsUndefined::new().as_value(&mut cx))
+}
+
 /// let some time ellapse for testing purposes
 fn mock_elapsed_time(mut cx: FunctionContext) -> JsResult<JsValue> {
     let ms = cx.argument::<JsNumber>(0)?.value();
@@ -152,16 +152,31 @@ struct MyStruct {
 
 pub use sixtyfps_macros::sixtyfps;
 
+pub use sixtyfps_corelib::accessibility::{
+    set_accessibility_adapter, AccessibilityAdapter, AccessibleNode,
+};
+pub use sixtyfps_corelib::animations::set_animation_speed;
 #[cfg(not(target_arch = "wasm32"))]
 pub use sixtyfps_corelib::font::register_application_font_from_memory;
+pub use sixtyfps_corelib::items::{
+    AccessibleRole, DropEvent, KeyboardEvent, KeyboardModifierState, PointerEvent,
+    PointerEventButton, PointerEventKind, SortOrder,
+};
 pub use sixtyfps_corelib::model::{
-    Model, ModelHandle, ModelNotify, ModelPeer, StandardListViewItem, VecModel,
+    FilterModel, MapModel, Model, ModelHandle, ModelNotify, ModelPeer, SortModel,
+    StandardListViewItem, TableColumn, TreeIndex, TreeModel, TreeModelNotify, TreeModelPeer,
+    TreeNode, TreeViewItem, TreeViewModel, VecModel, VecTreeModel,
 };
 pub use sixtyfps_corelib::sharedvector::SharedVector;
 pub use sixtyfps_corelib::string::SharedString;
 pub use sixtyfps_corelib::timers::{Timer, TimerMode};
 pub use sixtyfps_corelib::{Color, RgbaColor};
 
+/// Translation of the strings marked with `@tr(...)` in the `.60` markup, using gettext catalogs.
+pub mod translations {
+    pub use sixtyfps_corelib::translations::{add_catalog, load_catalog, set_language, Catalog};
+}
+
 // FIXME: this should not be in this namespace
 // but the name is `sixtyfps::StateInfo` in builtin.60
 #[doc(hidden)]
@@ -174,7 +189,7 @@ pub mod re_exports {
     pub use core::iter::FromIterator;
     pub use once_cell::sync::Lazy;
     pub use once_cell::unsync::OnceCell;
-    pub use sixtyfps_corelib::animations::EasingCurve;
+    pub use sixtyfps_corelib::animations::{animation_elapsed_time, EasingCurve, KeyFrame};
     pub use sixtyfps_corelib::callbacks::Callback;
     pub use sixtyfps_corelib::component::{
         init_component_items, Component, ComponentRefPin, ComponentVTable,
@@ -195,8 +210,11 @@ pub mod re_exports {
     pub use sixtyfps_corelib::items::*;
     pub use sixtyfps_corelib::layout::*;
     pub use sixtyfps_corelib::model::*;
-    pub use sixtyfps_corelib::properties::{set_state_binding, Property, PropertyTracker};
+    pub use sixtyfps_corelib::properties::{
+        set_state_binding, ChangeTracker, Property, PropertyTracker,
+    };
     pub use sixtyfps_corelib::slice::Slice;
+    pub use sixtyfps_corelib::translations::translate;
     pub use sixtyfps_corelib::Color;
     pub use sixtyfps_corelib::ComponentVTable_static;
     pub use sixtyfps_corelib::Resource;
@@ -286,6 +304,76 @@ pub fn send_mouse_click<
         sixtyfps_corelib::tests::sixtyfps_send_mouse_click(&dyn_rc, x, y, rc.component_window());
     }
 
+    /// Simulate a mouse click with the given button
+    pub fn send_mouse_button_click<
+        X: vtable::HasStaticVTable<sixtyfps_corelib::component::ComponentVTable> + HasWindow + 'static,
+        Component: Into<vtable::VRc<sixtyfps_corelib::component::ComponentVTable, X>> + Clone,
+    >(
+        component: &Component,
+        x: f32,
+        y: f32,
+        button: crate::re_exports::PointerEventButton,
+    ) {
+        let rc = component.clone().into();
+        let dyn_rc = vtable::VRc::into_dyn(rc.clone());
+        sixtyfps_corelib::tests::sixtyfps_send_mouse_button_click(
+            &dyn_rc,
+            x,
+            y,
+            button,
+            rc.component_window(),
+        );
+    }
+
+    /// Simulate a drag with the left mouse button from the position (from_x, from_y) to (to_x, to_y)
+    pub fn send_mouse_drag<
+        X: vtable::HasStaticVTable<sixtyfps_corelib::component::ComponentVTable> + HasWindow + 'static,
+        Component: Into<vtable::VRc<sixtyfps_corelib::component::ComponentVTable, X>> + Clone,
+    >(
+        component: &Component,
+        from_x: f32,
+        from_y: f32,
+        to_x: f32,
+        to_y: f32,
+    ) {
+        let rc = component.clone().into();
+        let dyn_rc = vtable::VRc::into_dyn(rc.clone());
+        sixtyfps_corelib::tests::sixtyfps_send_mouse_drag(
+            &dyn_rc,
+            from_x,
+            from_y,
+            to_x,
+            to_y,
+            rc.component_window(),
+        );
+    }
+
+    pub use sixtyfps_corelib::input::TouchPhase;
+
+    /// Simulate a touch event. Several touch points can be active at the same time, they are
+    /// distinguished by their `id`.
+    pub fn send_touch_event<
+        X: vtable::HasStaticVTable<sixtyfps_corelib::component::ComponentVTable> + HasWindow + 'static,
+        Component: Into<vtable::VRc<sixtyfps_corelib::component::ComponentVTable, X>> + Clone,
+    >(
+        component: &Component,
+        id: u64,
+        x: f32,
+        y: f32,
+        phase: TouchPhase,
+    ) {
+        let rc = component.clone().into();
+        let dyn_rc = vtable::VRc::into_dyn(rc.clone());
+        sixtyfps_corelib::tests::sixtyfps_send_touch_event(
+            &dyn_rc,
+            id,
+            x,
+            y,
+            phase,
+            rc.component_window(),
+        );
+    }
+
     /// Simulate a change in keyboard modifiers being pressed
     pub fn set_current_keyboard_modifiers<
         X: vtable::HasStaticVTable<sixtyfps_corelib::component::ComponentVTable> + HasWindow,
@@ -355,6 +443,18 @@ pub fn set_window_scale_factor<
         let component = component.clone().into();
         component.component_window().set_scale_factor(factor)
     }
+
+    /// Returns a textual dump of the accessibility tree of the component, with one element
+    /// per line, indented by depth: the role, the quoted label and the description in parentheses.
+    pub fn accessibility_tree_dump<
+        X: vtable::HasStaticVTable<sixtyfps_corelib::component::ComponentVTable> + 'static,
+        Component: Into<vtable::VRc<sixtyfps_corelib::component::ComponentVTable, X>> + Clone,
+    >(
+        component: &Component,
+    ) -> String {
+        let rc = component.clone().into();
+        sixtyfps_corelib::accessibility::dump_accessibility_tree(&vtable::VRc::into_dyn(rc))
+    }
 }
 
 /// Include the code generated with the sixtyfps-build crate from the build script. After calling `sixtyfps_build::compile`
@@ -177,26 +177,28 @@ fn fill_token_vec(stream: impl Iterator<Item = TokenTree>, vec: &mut Vec<parser:
                         SyntaxKind::AndAnd
                     }
                     '|' => {
-                        // Since the '|' alone does not exist or cannot be part of any other token that ||
-                        // just consider it as '||' and skip the joint ones.  FIXME. do that properly
+                        // Two joint '|' form the '||' token
                         if let Some(last) = vec.last_mut() {
-                            if last.kind == SyntaxKind::OrOr && prev_spacing == Spacing::Joint {
+                            if last.kind == SyntaxKind::Pipe && prev_spacing == Spacing::Joint {
+                                last.kind = SyntaxKind::OrOr;
+                                last.text = "||".into();
                                 continue;
                             }
                         }
-                        SyntaxKind::OrOr
+                        SyntaxKind::Pipe
                     }
                     '%' => {
-                        // % can only exist after number literal
+                        // % after a number literal is the percent unit
                         if let Some(last) = vec.last_mut() {
                             if last.kind == SyntaxKind::NumberLiteral {
                                 last.text = format!("{}%", last.text).into();
                                 continue;
                             }
                         }
-                        SyntaxKind::Error
+                        SyntaxKind::Percent
                     }
                     '$' => SyntaxKind::Dollar,
+                    '@' => SyntaxKind::At,
                     _ => SyntaxKind::Error,
                 };
                 prev_spacing = p.spacing();
@@ -0,0 +1,99 @@
+/* LICENSE BEGIN
+    This file is part of the SixtyFPS Project -- https://sixtyfps.io
+    Copyright (c) 2020 Olivier Goffart <olivier.goffart@sixtyfps.io>
+    Copyright (c) 2020 Simon Hausmann <simon.hausmann@sixtyfps.io>
+
+    SPDX-License-Identifier: GPL-3.0-only
+    This file is also available under commercial licensing terms.
+    Please contact info@sixtyfps.io for more information.
+LICENSE END */
+/*!
+The parser of the format strings of `@tr(...)` and `@format(...)`, shared by the compiler,
+which validates them, and by the runtime library, which formats the translated strings.
+
+A format string is made of literal text and of placeholders in braces, such as `{}` or `{0}`.
+`{{` and `}}` are escaped braces. This crate only splits the string: the meaning of the
+content of the placeholders is up to the caller.
+
+**NOTE**: This library is an **internal** crate for the [SixtyFPS project](https://sixtyfps.io).
+This crate should **not be used directly** by applications using SixtyFPS.
+*/
+
+#![warn(missing_docs)]
+
+/// A part of a format string
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Piece<'a> {
+    /// Literal text, with the escaped braces already replaced by a single brace
+    Literal(&'a str),
+    /// The content of a placeholder, between the braces
+    Placeholder(&'a str),
+}
+
+/// The errors of [`parse`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Error {
+    /// A `}` that does not close a placeholder and is not escaped
+    UnescapedClosingBrace,
+    /// A `{` without the `}` closing the placeholder
+    UnterminatedPlaceholder,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::UnescapedClosingBrace => {
+                f.write_str("Unescaped `}` in format string, use `}}` instead")
+            }
+            Error::UnterminatedPlaceholder => {
+                f.write_str("Unterminated placeholder in format string")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Splits a format string into literal texts and placeholders
+pub fn parse(format: &str) -> Result<Vec<Piece<'_>>, Error> {
+    let mut pieces = vec![];
+    let mut rest = format;
+    while let Some(pos) = rest.find(&['{', '}'][..]) {
+        if pos > 0 {
+            pieces.push(Piece::Literal(&rest[..pos]));
+        }
+        let brace = &rest[pos..pos + 1];
+        rest = &rest[pos + 1..];
+        if let Some(after_escape) = rest.strip_prefix(brace) {
+            pieces.push(Piece::Literal(brace));
+            rest = after_escape;
+        } else if brace == "}" {
+            return Err(Error::UnescapedClosingBrace);
+        } else {
+            let end = rest.find('}').ok_or(Error::UnterminatedPlaceholder)?;
+            pieces.push(Piece::Placeholder(&rest[..end]));
+            rest = &rest[end + 1..];
+        }
+    }
+    if !rest.is_empty() {
+        pieces.push(Piece::Literal(rest));
+    }
+    Ok(pieces)
+}
+
+#[test]
+fn test_parse() {
+    use Piece::*;
+    assert_eq!(parse(""), Ok(vec![]));
+    assert_eq!(parse("Hello"), Ok(vec![Literal("Hello")]));
+    assert_eq!(
+        parse("{} of {1:05.2}{n}"),
+        Ok(vec![Placeholder(""), Literal(" of "), Placeholder("1:05.2"), Placeholder("n")])
+    );
+    assert_eq!(
+        parse("{{x}} {{"),
+        Ok(vec![Literal("{"), Literal("x"), Literal("}"), Literal(" "), Literal("{")])
+    );
+    assert_eq!(parse("a } b"), Err(Error::UnescapedClosingBrace));
+    assert_eq!(parse("a {0"), Err(Error::UnterminatedPlaceholder));
+}
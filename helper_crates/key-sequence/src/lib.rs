@@ -0,0 +1,286 @@
+/* LICENSE BEGIN
+    This file is part of the SixtyFPS Project -- https://sixtyfps.io
+    Copyright (c) 2020 Olivier Goffart <olivier.goffart@sixtyfps.io>
+    Copyright (c) 2020 Simon Hausmann <simon.hausmann@sixtyfps.io>
+
+    SPDX-License-Identifier: GPL-3.0-only
+    This file is also available under commercial licensing terms.
+    Please contact info@sixtyfps.io for more information.
+LICENSE END */
+/*!
+The parser of the key sequences of the `Shortcut` element, such as `"Ctrl+S"`, shared by the compiler,
+which validates the key sequences set with a string literal, and by the runtime library, which
+maps them to a key code and keyboard modifiers.
+
+A key sequence is made of modifiers followed by a key, separated by `+`. The modifiers are `Ctrl`
+(or `Control`), `Shift`, `Alt` and `Meta` (or `Logo`, `Cmd`, `Command`, `Super`). The key is a letter,
+a digit, or one of the [`KEY_NAMES`]. Common aliases such as `Enter`, `Esc`, `Backspace` or `Del`
+are also accepted. The modifiers and the keys are matched in any case.
+
+**NOTE**: This library is an **internal** crate for the [SixtyFPS project](https://sixtyfps.io).
+This crate should **not be used directly** by applications using SixtyFPS.
+*/
+
+#![warn(missing_docs)]
+
+/// The names of the keys, which are the names of the variants of the `KeyCode` of the runtime library
+pub const KEY_NAMES: &[&str] = &[
+    "Key1",
+    "Key2",
+    "Key3",
+    "Key4",
+    "Key5",
+    "Key6",
+    "Key7",
+    "Key8",
+    "Key9",
+    "Key0",
+    "A",
+    "B",
+    "C",
+    "D",
+    "E",
+    "F",
+    "G",
+    "H",
+    "I",
+    "J",
+    "K",
+    "L",
+    "M",
+    "N",
+    "O",
+    "P",
+    "Q",
+    "R",
+    "S",
+    "T",
+    "U",
+    "V",
+    "W",
+    "X",
+    "Y",
+    "Z",
+    "Escape",
+    "F1",
+    "F2",
+    "F3",
+    "F4",
+    "F5",
+    "F6",
+    "F7",
+    "F8",
+    "F9",
+    "F10",
+    "F11",
+    "F12",
+    "F13",
+    "F14",
+    "F15",
+    "F16",
+    "F17",
+    "F18",
+    "F19",
+    "F20",
+    "F21",
+    "F22",
+    "F23",
+    "F24",
+    "Snapshot",
+    "Scroll",
+    "Pause",
+    "Insert",
+    "Home",
+    "Delete",
+    "End",
+    "PageDown",
+    "PageUp",
+    "Left",
+    "Up",
+    "Right",
+    "Down",
+    "Back",
+    "Return",
+    "Space",
+    "Compose",
+    "Caret",
+    "Numlock",
+    "Numpad0",
+    "Numpad1",
+    "Numpad2",
+    "Numpad3",
+    "Numpad4",
+    "Numpad5",
+    "Numpad6",
+    "Numpad7",
+    "Numpad8",
+    "Numpad9",
+    "AbntC1",
+    "AbntC2",
+    "NumpadAdd",
+    "Apostrophe",
+    "Apps",
+    "Asterisk",
+    "At",
+    "Ax",
+    "Backslash",
+    "Calculator",
+    "Capital",
+    "Colon",
+    "Comma",
+    "Convert",
+    "NumpadDecimal",
+    "NumpadDivide",
+    "Equals",
+    "Grave",
+    "Kana",
+    "Kanji",
+    "LAlt",
+    "LBracket",
+    "LControl",
+    "LShift",
+    "LWin",
+    "Mail",
+    "MediaSelect",
+    "MediaStop",
+    "Minus",
+    "NumpadMultiply",
+    "Mute",
+    "MyComputer",
+    "NavigateForward",
+    "NavigateBackward",
+    "NextTrack",
+    "NoConvert",
+    "NumpadComma",
+    "NumpadEnter",
+    "NumpadEquals",
+    "OEM102",
+    "Period",
+    "PlayPause",
+    "Plus",
+    "Power",
+    "PrevTrack",
+    "RAlt",
+    "RBracket",
+    "RControl",
+    "RShift",
+    "RWin",
+    "Semicolon",
+    "Slash",
+    "Sleep",
+    "Stop",
+    "NumpadSubtract",
+    "Sysrq",
+    "Tab",
+    "Underline",
+    "Unlabeled",
+    "VolumeDown",
+    "VolumeUp",
+    "Wake",
+    "WebBack",
+    "WebFavorites",
+    "WebForward",
+    "WebHome",
+    "WebRefresh",
+    "WebSearch",
+    "WebStop",
+    "Yen",
+    "Copy",
+    "Paste",
+    "Cut",
+];
+
+/// The modifiers of a key sequence
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Modifiers {
+    /// `Ctrl` or `Control`
+    pub control: bool,
+    /// `Shift`
+    pub shift: bool,
+    /// `Alt`
+    pub alt: bool,
+    /// `Meta`, `Logo`, `Cmd`, `Command` or `Super`
+    pub meta: bool,
+}
+
+/// The errors of [`parse`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Error<'a> {
+    /// A modifier that is not one of the known modifiers
+    UnknownModifier(&'a str),
+    /// The key sequence is empty, or ends with a `+`
+    MissingKey,
+    /// A key that is neither a letter, a digit, nor one of the [`KEY_NAMES`] or their aliases
+    UnknownKey(&'a str),
+}
+
+impl std::fmt::Display for Error<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::UnknownModifier(modifier) => write!(
+                f,
+                "Unknown modifier '{}' in the key sequence. The modifiers are Ctrl, Shift, Alt and Meta",
+                modifier
+            ),
+            Error::MissingKey => f.write_str("Missing key in the key sequence"),
+            Error::UnknownKey(key) => write!(f, "Unknown key '{}' in the key sequence", key),
+        }
+    }
+}
+
+impl std::error::Error for Error<'_> {}
+
+/// Returns the entry of [`KEY_NAMES`] equal to `name`, ignoring the case
+fn key_name(name: &str) -> Option<&'static str> {
+    KEY_NAMES.iter().copied().find(|key_name| key_name.eq_ignore_ascii_case(name))
+}
+
+/// Parses a key sequence into the name of the key, which is one of the [`KEY_NAMES`], and the
+/// modifiers that must be pressed
+pub fn parse(keys: &str) -> Result<(&'static str, Modifiers), Error<'_>> {
+    let mut parts = keys.split('+').map(str::trim).collect::<Vec<_>>();
+    let key = parts.pop().unwrap_or_default();
+    let mut modifiers = Modifiers::default();
+    for modifier in parts {
+        match modifier.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => modifiers.control = true,
+            "shift" => modifiers.shift = true,
+            "alt" => modifiers.alt = true,
+            "meta" | "logo" | "cmd" | "command" | "super" => modifiers.meta = true,
+            _ => return Err(Error::UnknownModifier(modifier)),
+        }
+    }
+    let mut chars = key.chars();
+    let name = match (chars.next(), chars.next()) {
+        (None, _) => return Err(Error::MissingKey),
+        (Some(ch), None) if ch.is_ascii_alphabetic() => key_name(key),
+        (Some(ch), None) if ch.is_ascii_digit() => key_name(&format!("Key{}", ch)),
+        (Some('\t'), None) => Some("Tab"),
+        _ => match key.to_ascii_lowercase().as_str() {
+            "enter" => Some("Return"),
+            "esc" => Some("Escape"),
+            "backspace" => Some("Back"),
+            "del" => Some("Delete"),
+            "ins" => Some("Insert"),
+            "pgup" => Some("PageUp"),
+            "pgdown" => Some("PageDown"),
+            _ => key_name(key),
+        },
+    };
+    Ok((name.ok_or(Error::UnknownKey(key))?, modifiers))
+}
+
+#[test]
+fn test_parse() {
+    let ctrl_shift = Modifiers { control: true, shift: true, ..Default::default() };
+    assert_eq!(parse("Ctrl+Shift+S"), Ok(("S", ctrl_shift)));
+    assert_eq!(parse("ctrl + SHIFT + s"), Ok(("S", ctrl_shift)));
+    assert_eq!(parse("Cmd+5"), Ok(("Key5", Modifiers { meta: true, ..Default::default() })));
+    assert_eq!(parse("pageup"), Ok(("PageUp", Modifiers::default())));
+    assert_eq!(parse("pgdown"), Ok(("PageDown", Modifiers::default())));
+    assert_eq!(parse("Alt+f5"), Ok(("F5", Modifiers { alt: true, ..Default::default() })));
+    assert_eq!(parse("Ctrl+Shfit+S"), Err(Error::UnknownModifier("Shfit")));
+    assert_eq!(parse("Ctrl+Foo"), Err(Error::UnknownKey("Foo")));
+    assert_eq!(parse("Ctrl+"), Err(Error::MissingKey));
+    assert_eq!(parse(""), Err(Error::MissingKey));
+}
@@ -8,6 +8,8 @@
 # This file is also available under commercial licensing terms.
 # Please contact info@sixtyfps.io for more information.
 # LICENSE END
+cargo publish --manifest-path helper_crates/format-string/Cargo.toml
+cargo publish --manifest-path helper_crates/key-sequence/Cargo.toml
 cargo publish --manifest-path sixtyfps_runtime/corelib_macros/Cargo.toml
 cargo publish --manifest-path sixtyfps_compiler/Cargo.toml
 cargo publish --manifest-path sixtyfps_runtime/corelib/Cargo.toml --features x11
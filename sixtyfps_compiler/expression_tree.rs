@@ -59,6 +59,30 @@ pub enum BuiltinFunction {
     StringToFloat,
     /// the "42".is_float()
     StringIsFloat,
+    /// the "abc".length()
+    StringLength,
+    /// the "abc".to_upper()
+    StringToUpper,
+    /// the "abc".to_lower()
+    StringToLower,
+    /// the "abc".contains("b")
+    StringContains,
+    /// the "abc".substring(start, length)
+    StringSubstring,
+    /// the 3.14159.to_fixed(2)
+    NumberToFixed,
+    /// the model.length
+    ArrayLength,
+    /// Formats a number with a width, a precision (-1 for the default) and zero padding.
+    /// Generated from the placeholders of `@format(...)`
+    FormatNumber,
+    /// `@tr(...)`. The arguments are the message, the context, the plural form (string literals),
+    /// the count for the plural form, followed by the arguments to format, as strings.
+    Translate,
+    /// The time elapsed in the animations since the state described by the StateInfo argument
+    /// changed, taking the animation speed into account.
+    /// Generated for the `animation-finished` callback of transitions
+    AnimationElapsedTime,
 }
 
 #[derive(Debug, Clone)]
@@ -67,6 +91,7 @@ pub enum BuiltinMacroFunction {
     Min,
     Max,
     CubicBezier,
+    Steps,
 }
 
 impl BuiltinFunction {
@@ -99,6 +124,42 @@ pub fn ty(&self) -> Type {
             BuiltinFunction::StringIsFloat => {
                 Type::Function { return_type: Box::new(Type::Bool), args: vec![Type::String] }
             }
+            BuiltinFunction::StringLength => {
+                Type::Function { return_type: Box::new(Type::Int32), args: vec![Type::String] }
+            }
+            BuiltinFunction::StringToUpper | BuiltinFunction::StringToLower => {
+                Type::Function { return_type: Box::new(Type::String), args: vec![Type::String] }
+            }
+            BuiltinFunction::StringContains => Type::Function {
+                return_type: Box::new(Type::Bool),
+                args: vec![Type::String, Type::String],
+            },
+            BuiltinFunction::StringSubstring => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::String, Type::Int32, Type::Int32],
+            },
+            BuiltinFunction::NumberToFixed => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::Float32, Type::Int32],
+            },
+            BuiltinFunction::ArrayLength => {
+                Type::Function { return_type: Box::new(Type::Int32), args: vec![Type::Model] }
+            }
+            BuiltinFunction::FormatNumber => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::Float32, Type::Int32, Type::Int32, Type::Bool],
+            },
+            BuiltinFunction::Translate => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::String, Type::String, Type::String, Type::Int32],
+            },
+            BuiltinFunction::AnimationElapsedTime => Type::Function {
+                return_type: Box::new(Type::Duration),
+                args: vec![Type::Object {
+                    fields: Default::default(),
+                    name: Some("sixtyfps::StateInfo".into()),
+                }],
+            },
         }
     }
 }
@@ -227,6 +288,9 @@ pub enum Expression {
     /// Reference to the callback <name> in the <element>
     PropertyReference(NamedReference),
 
+    /// Reference to the function <name> declared with `function` in the <element>
+    FunctionReference(NamedReference),
+
     /// Reference to a function built into the run-time, implemented natively
     BuiltinFunctionReference(BuiltinFunction),
 
@@ -288,6 +352,13 @@ pub enum Expression {
         name: String,
     },
 
+    /// Access to the row of an array or a model. An index out of bounds gives the default value
+    ArrayIndex {
+        /// This expression should have Type::Array type
+        array: Box<Expression>,
+        index: Box<Expression>,
+    },
+
     /// Cast an expression to the given type
     Cast {
         from: Box<Expression>,
@@ -371,7 +442,8 @@ pub fn ty(&self) -> Type {
             Expression::CallbackReference(NamedReference { element, name }) => {
                 element.upgrade().unwrap().borrow().lookup_property(name)
             }
-            Expression::PropertyReference(NamedReference { element, name }) => {
+            Expression::PropertyReference(NamedReference { element, name })
+            | Expression::FunctionReference(NamedReference { element, name }) => {
                 element.upgrade().unwrap().borrow().lookup_property(name)
             }
             Expression::BuiltinFunctionReference(funcref) => funcref.ty(),
@@ -405,6 +477,10 @@ pub fn ty(&self) -> Type {
                 Type::Component(c) => c.root_element.borrow().lookup_property(name.as_str()),
                 _ => Type::Invalid,
             },
+            Expression::ArrayIndex { array, .. } => match array.ty() {
+                Type::Array(ty) => *ty,
+                _ => Type::Invalid,
+            },
             Expression::Cast { to, .. } => to.clone(),
             Expression::CodeBlock(sub) => sub.last().map_or(Type::Void, |e| e.ty()),
             Expression::FunctionCall { function, .. } => match function.ty() {
@@ -472,6 +548,7 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
             Expression::BoolLiteral(_) => {}
             Expression::CallbackReference { .. } => {}
             Expression::PropertyReference { .. } => {}
+            Expression::FunctionReference { .. } => {}
             Expression::FunctionParameterReference { .. } => {}
             Expression::BuiltinFunctionReference { .. } => {}
             Expression::MemberFunction { base, member, .. } => {
@@ -481,6 +558,10 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
             Expression::BuiltinMacroReference { .. } => {}
             Expression::ElementReference(_) => {}
             Expression::ObjectAccess { base, .. } => visitor(&**base),
+            Expression::ArrayIndex { array, index } => {
+                visitor(&**array);
+                visitor(&**index);
+            }
             Expression::RepeaterIndexReference { .. } => {}
             Expression::RepeaterModelReference { .. } => {}
             Expression::Cast { from, .. } => visitor(&**from),
@@ -544,6 +625,7 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
             Expression::BoolLiteral(_) => {}
             Expression::CallbackReference { .. } => {}
             Expression::PropertyReference { .. } => {}
+            Expression::FunctionReference { .. } => {}
             Expression::FunctionParameterReference { .. } => {}
             Expression::BuiltinFunctionReference { .. } => {}
             Expression::MemberFunction { base, member, .. } => {
@@ -553,6 +635,10 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
             Expression::BuiltinMacroReference { .. } => {}
             Expression::ElementReference(_) => {}
             Expression::ObjectAccess { base, .. } => visitor(&mut **base),
+            Expression::ArrayIndex { array, index } => {
+                visitor(&mut **array);
+                visitor(&mut **index);
+            }
             Expression::RepeaterIndexReference { .. } => {}
             Expression::RepeaterModelReference { .. } => {}
             Expression::Cast { from, .. } => visitor(&mut **from),
@@ -618,6 +704,7 @@ pub fn is_constant(&self) -> bool {
             Expression::BoolLiteral(_) => true,
             Expression::CallbackReference { .. } => false,
             Expression::PropertyReference { .. } => false,
+            Expression::FunctionReference { .. } => false,
             Expression::BuiltinFunctionReference { .. } => false,
             Expression::MemberFunction { .. } => false,
             Expression::ElementReference(_) => false,
@@ -626,6 +713,7 @@ pub fn is_constant(&self) -> bool {
             Expression::FunctionParameterReference { .. } => false,
             Expression::BuiltinMacroReference { .. } => false,
             Expression::ObjectAccess { base, .. } => base.is_constant(),
+            Expression::ArrayIndex { array, index } => array.is_constant() && index.is_constant(),
             Expression::Cast { from, to } => {
                 from.is_constant() && !matches!(to, Type::Length | Type::LogicalLength)
             }
@@ -751,9 +839,10 @@ pub fn maybe_convert_to(
         } else if ty == Type::Invalid || target_type == Type::Invalid {
             self
         } else if matches!((&ty, &target_type, &self), (Type::Array(a), Type::Array(b), Expression::Array{..})
-            if a.can_convert(b) || **a == Type::Invalid)
+            if a.can_convert(b) || **a == Type::Invalid || matches!((&**a, &**b), (Type::Array(_), Type::Array(_))))
         {
-            // Special case for converting array literals
+            // Special case for converting array literals.
+            // The elements of nested array literals are converted recursively
             match (self, target_type) {
                 (Expression::Array { values, .. }, Type::Array(target_type)) => Expression::Array {
                     values: values
@@ -895,6 +984,16 @@ pub struct PathElement {
 pub enum EasingCurve {
     Linear,
     CubicBezier(f32, f32, f32, f32),
+    EaseInElastic,
+    EaseOutElastic,
+    EaseInOutElastic,
+    EaseInBounce,
+    EaseOutBounce,
+    EaseInOutBounce,
+    EaseInBack,
+    EaseOutBack,
+    EaseInOutBack,
+    Steps(u32),
     // CubicBesizerNonConst([Box<Expression>; 4]),
     // Custom(Box<dyn Fn(f32)->f32>),
 }
@@ -150,3 +150,9 @@ pub fn as_flickable_viewport_property<'a>(e: &ElementRc, name: &'a str) -> Optio
         None
     }
 }
+
+/// Returns true if the bindings of the animation element depend on other properties, in which case the
+/// animation needs to be computed when it starts and while it runs, rather than once when the component is created
+pub fn is_dynamic_animation(animation: &ElementRc) -> bool {
+    !animation.borrow().bindings.values().all(|b| b.is_constant())
+}
@@ -55,6 +55,7 @@ pub enum Declaration {
         Struct(Struct),
         Function(Function),
         Var(Var),
+        Enum(Enum),
     }
 
     #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -192,6 +193,26 @@ fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
         }
     }
 
+    /// An `enum class`
+    #[derive(Default, Debug)]
+    pub struct Enum {
+        pub name: String,
+        pub values: Vec<String>,
+    }
+
+    impl Display for Enum {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+            indent(f)?;
+            writeln!(f, "enum class {} {{", self.name)?;
+            for value in &self.values {
+                indent(f)?;
+                writeln!(f, "    {},", value)?;
+            }
+            indent(f)?;
+            writeln!(f, "}};")
+        }
+    }
+
     pub trait CppType {
         fn cpp_type(&self) -> Option<String>;
     }
@@ -203,7 +224,9 @@ pub trait CppType {
 };
 use crate::langtype::Type;
 use crate::layout::LayoutGeometry;
-use crate::object_tree::{Component, Document, Element, ElementRc, RepeatedElementInfo};
+use crate::object_tree::{
+    Component, Document, Element, ElementRc, PropertyVisibility, RepeatedElementInfo,
+};
 use cpp_ast::*;
 use itertools::Itertools;
 use std::collections::{BTreeMap, HashMap};
@@ -234,6 +257,9 @@ fn cpp_type(&self) -> Option<String> {
             Type::Array(i) => Some(format!("std::shared_ptr<sixtyfps::Model<{}>>", i.cpp_type()?)),
             Type::Resource => Some("sixtyfps::Resource".to_owned()),
             Type::Builtin(elem) => elem.native_class.cpp_type.clone(),
+            Type::Enumeration(enumeration) if enumeration.node.is_some() => {
+                Some(enumeration.name.clone())
+            }
             Type::Enumeration(enumeration) => Some(format!("sixtyfps::{}", enumeration.name)),
             _ => None,
         }
@@ -286,7 +312,42 @@ fn new_struct_with_bindings(
 }
 
 fn property_animation_code(component: &Rc<Component>, animation: &ElementRc) -> String {
-    new_struct_with_bindings("sixtyfps::PropertyAnimation", &animation.borrow().bindings, component)
+    let animation = animation.borrow();
+    let bindings_initialization: Vec<String> = animation
+        .bindings
+        .iter()
+        .map(|(prop, initializer)| {
+            if prop == "keyframes" {
+                let values = match &initializer.expression {
+                    Expression::Array { values, .. } => values,
+                    _ => panic!("internal error: keyframes must be an array"),
+                };
+                return values
+                    .iter()
+                    .map(|keyframe| match keyframe {
+                        Expression::Object { values, .. } => format!(
+                            "var.{}.push_back(sixtyfps::KeyFrame{{ float({}), float({}) }});",
+                            prop,
+                            compile_expression(&values["position"], component),
+                            compile_expression(&values["value"], component)
+                        ),
+                        _ => panic!("internal error: keyframes must be objects"),
+                    })
+                    .join("\n");
+            }
+            let initializer = compile_expression(initializer, component);
+            format!("var.{} = {};", prop, initializer)
+        })
+        .collect();
+
+    format!(
+        r#"[&](){{
+            sixtyfps::PropertyAnimation var{{}};
+            {}
+            return var;
+        }}()"#,
+        bindings_initialization.join("\n")
+    )
 }
 
 fn property_set_value_code(
@@ -296,6 +357,19 @@ fn property_set_value_code(
     value_expr: &str,
 ) -> String {
     match element.property_animations.get(property_name) {
+        Some(crate::object_tree::PropertyAnimation::Static(animation))
+            if super::is_dynamic_animation(animation) =>
+        {
+            let animation_code = property_animation_code(component, animation);
+            format!(
+                "set_dynamic_animated_value({value}, [this]() {{
+                    [[maybe_unused]] auto self = this;
+                    return {animation};
+                }})",
+                value = value_expr,
+                animation = animation_code
+            )
+        }
         Some(crate::object_tree::PropertyAnimation::Static(animation)) => {
             let animation_code = property_animation_code(component, animation);
             format!(
@@ -318,6 +392,10 @@ fn handle_property_binding(
     let component = item.enclosing_component.upgrade().unwrap();
     let id = &item.id;
     let prop_type = item.lookup_property(prop_name);
+    if let Type::Function { .. } = &prop_type {
+        // The body of the function is generated as a member function of the component
+        return;
+    }
     if let Type::Callback { args, .. } = &prop_type {
         let callback_accessor_prefix = if item.property_declarations.contains_key(prop_name) {
             String::new()
@@ -381,6 +459,18 @@ fn handle_property_binding(
                 format!("sixtyfps::set_state_binding({}, {});", cpp_prop, binding_code)
             } else {
                 match item.property_animations.get(prop_name) {
+                    Some(crate::object_tree::PropertyAnimation::Static(anim))
+                        if super::is_dynamic_animation(anim) =>
+                    {
+                        let anim = property_animation_code(&component, anim);
+                        format!(
+                            "{}.set_dynamic_animated_binding({}, [this]() {{
+                                [[maybe_unused]] auto self = this;
+                                return {};
+                            }});",
+                            cpp_prop, binding_code, anim
+                        )
+                    }
                     Some(crate::object_tree::PropertyAnimation::Static(anim)) => {
                         let anim = property_animation_code(&component, anim);
                         format!("{}.set_animated_binding({}, {});", cpp_prop, binding_code, anim)
@@ -539,6 +629,9 @@ pub fn generate(doc: &Document, diag: &mut BuildDiagnostics) -> Option<impl std:
     for ty in doc.root_component.used_structs.borrow().iter() {
         if let Type::Object { fields, name: Some(name) } = ty {
             generate_struct(&mut file, name, fields, diag);
+        } else if let Type::Enumeration(en) = ty {
+            file.declarations
+                .push(Declaration::Enum(Enum { name: en.name.clone(), values: en.values.clone() }));
         }
     }
     for glob in doc.root_component.used_global.borrow().iter() {
@@ -650,6 +743,43 @@ fn generate_component(
     let mut init = vec!["[[maybe_unused]] auto self = this;".into()];
 
     for (cpp_name, property_decl) in component.root_element.borrow().property_declarations.iter() {
+        if let Type::Function { args, return_type } = &property_decl.property_type {
+            let params = args
+                .iter()
+                .enumerate()
+                .map(|(i, t)| {
+                    format!(
+                        "[[maybe_unused]] {} arg_{}",
+                        get_cpp_type(t, &property_decl.type_node, diag),
+                        i
+                    )
+                })
+                .join(", ");
+            let return_type = get_cpp_type(&return_type, &property_decl.type_node, diag);
+            let body = component
+                .root_element
+                .borrow()
+                .bindings
+                .get(cpp_name)
+                .map_or_else(String::new, |body| compile_expression(body, component));
+            component_struct.members.push((
+                if component.is_global() { Access::Public } else { Access::Private },
+                Declaration::Function(Function {
+                    name: cpp_name.clone(),
+                    signature: format!("({}) const -> {}", params, return_type),
+                    statements: Some(vec![
+                        "[[maybe_unused]] auto self = this;".into(),
+                        if return_type == "void" {
+                            format!("{};", body)
+                        } else {
+                            format!("return {};", body)
+                        },
+                    ]),
+                    ..Default::default()
+                }),
+            ));
+            continue;
+        }
         let ty = if let Type::Callback { args, return_type } = &property_decl.property_type {
             let param_types = args
                 .iter()
@@ -718,28 +848,30 @@ fn generate_component(
                     }),
                 ));
 
-                let prop_setter: Vec<String> = vec![
-                    "[[maybe_unused]] auto self = this;".into(),
-                    format!(
-                        "{}.{};",
-                        access,
-                        property_set_value_code(
-                            &component,
-                            &*component.root_element.borrow(),
-                            cpp_name,
-                            "value"
-                        )
-                    ),
-                ];
-                component_struct.members.push((
-                    Access::Public,
-                    Declaration::Function(Function {
-                        name: format!("set_{}", cpp_name),
-                        signature: format!("(const {} &value) const", cpp_type),
-                        statements: Some(prop_setter),
-                        ..Default::default()
-                    }),
-                ));
+                if property_decl.visibility != PropertyVisibility::Output {
+                    let prop_setter: Vec<String> = vec![
+                        "[[maybe_unused]] auto self = this;".into(),
+                        format!(
+                            "{}.{};",
+                            access,
+                            property_set_value_code(
+                                &component,
+                                &*component.root_element.borrow(),
+                                cpp_name,
+                                "value"
+                            )
+                        ),
+                    ];
+                    component_struct.members.push((
+                        Access::Public,
+                        Declaration::Function(Function {
+                            name: format!("set_{}", cpp_name),
+                            signature: format!("(const {} &value) const", cpp_type),
+                            statements: Some(prop_setter),
+                            ..Default::default()
+                        }),
+                    ));
+                }
             }
             format!("sixtyfps::Property<{}>", cpp_type)
         };
@@ -801,6 +933,43 @@ fn generate_component(
                 }),
             ));
         }
+        if let Some(repeated) = &parent_element.borrow().repeated {
+            let (start_exit, exit_finished) = if let Some(exit) = &repeated.exit_transition {
+                (
+                    vec![
+                        format!(
+                            "{}.set(true);",
+                            access_named_reference(&exit.exiting, component, "this")
+                        ),
+                        "return true;".into(),
+                    ],
+                    vec![format!(
+                        "return {}.get();",
+                        access_named_reference(&exit.exit_finished, component, "this")
+                    )],
+                )
+            } else {
+                (vec!["return false;".into()], vec!["return true;".into()])
+            };
+            component_struct.members.push((
+                Access::Public, // Because Repeater accesses it
+                Declaration::Function(Function {
+                    name: "start_exit".into(),
+                    signature: "() const -> bool".into(),
+                    statements: Some(start_exit),
+                    ..Function::default()
+                }),
+            ));
+            component_struct.members.push((
+                Access::Public, // Because Repeater accesses it
+                Declaration::Function(Function {
+                    name: "exit_finished".into(),
+                    signature: "() const -> bool".into(),
+                    statements: Some(exit_finished),
+                    ..Function::default()
+                }),
+            ));
+        }
         let parent_component_id = self::component_id(
             &component
                 .parent_element
@@ -924,6 +1093,27 @@ fn generate_component(
         component_struct.friends.push("sixtyfps::private_api::ComponentWindow".into());
     }
 
+    let mut change_tracker_init = vec![];
+    crate::object_tree::recurse_elem(&component.root_element, &(), &mut |elem, _| {
+        for (nr, handler) in &elem.borrow().change_callbacks {
+            let tracker_id = format!("change_tracker_{}", change_tracker_init.len());
+            change_tracker_init.push(format!(
+                "self->{tracker}.init([self]() {{ return {prop}.get(); }}, [self]() {{ {handler}; }});",
+                tracker = tracker_id,
+                prop = access_named_reference(nr, component, "self"),
+                handler = compile_expression(handler, component),
+            ));
+            component_struct.members.push((
+                Access::Private,
+                Declaration::Var(Var {
+                    ty: "sixtyfps::ChangeTracker".into(),
+                    name: tracker_id,
+                    init: None,
+                }),
+            ));
+        }
+    });
+
     if !component.is_global() {
         let maybe_constructor_param = if constructor_parent_arg.is_empty() { "" } else { "parent" };
 
@@ -935,6 +1125,32 @@ fn generate_component(
         create_code.extend(
             component.setup_code.borrow().iter().map(|code| compile_expression(code, component)),
         );
+        create_code.extend(change_tracker_init);
+
+        let mut init_code = vec![];
+        crate::object_tree::recurse_elem(&component.root_element, &(), &mut |elem, _| {
+            init_code.extend(
+                elem.borrow()
+                    .init_code
+                    .iter()
+                    .map(|code| format!("{};", compile_expression(code, component))),
+            );
+        });
+        if component.is_repeated() {
+            // The Repeater calls init() once the model data is set
+            init_code.insert(0, "[[maybe_unused]] auto self = this;".into());
+            component_struct.members.push((
+                Access::Public, // Because Repeater accesses it
+                Declaration::Function(Function {
+                    name: "init".into(),
+                    signature: "() const -> void".into(),
+                    statements: Some(init_code),
+                    ..Function::default()
+                }),
+            ));
+        } else {
+            create_code.extend(init_code);
+        }
         create_code
             .push(format!("return sixtyfps::ComponentHandle<{0}>{{ self_rc }};", component_id));
 
@@ -1301,6 +1517,7 @@ fn compile_expression(
             "{}.emit",
             access_named_reference(nr, component, "self")
         ),
+        Expression::FunctionReference(nr) => access_named_reference(nr, component, "self"),
         Expression::BuiltinFunctionReference(funcref) => match funcref {
             BuiltinFunction::GetWindowScaleFactor => {
                 "self->window.scale_factor".into()
@@ -1319,6 +1536,9 @@ fn compile_expression(
             BuiltinFunction::ShowPopupWindow => {
                 "self->window.show_popup".into()
             }
+            BuiltinFunction::Translate => {
+                "sixtyfps::private_api::translate".into()
+            }
 
            /*  std::from_chars is unfortunately not yet implemented in gcc
             BuiltinFunction::SringIsFloat => {
@@ -1337,6 +1557,33 @@ fn compile_expression(
                 "[](const auto &a){ auto e1 = std::end(a); auto e2 = const_cast<char*>(e1); auto r = std::strtod(std::begin(a), &e2); return e1 == e2 ? r : 0; }"
                     .into()
             }
+            BuiltinFunction::ArrayLength => {
+                "[](const auto &model){ if (!model) return 0; model->track_changes(); return int(model->row_count()); }".into()
+            }
+            BuiltinFunction::StringLength => {
+                "[](const sixtyfps::SharedString &s){ return int(s.character_count()); }".into()
+            }
+            BuiltinFunction::StringToUpper => {
+                "[](const sixtyfps::SharedString &s){ return s.to_uppercase(); }".into()
+            }
+            BuiltinFunction::StringToLower => {
+                "[](const sixtyfps::SharedString &s){ return s.to_lowercase(); }".into()
+            }
+            BuiltinFunction::StringContains => {
+                "[](const sixtyfps::SharedString &s, const sixtyfps::SharedString &needle){ return s.contains(needle); }".into()
+            }
+            BuiltinFunction::StringSubstring => {
+                "[](const sixtyfps::SharedString &s, int start, int length){ return s.substring(start > 0 ? start : 0, length > 0 ? length : 0); }".into()
+            }
+            BuiltinFunction::NumberToFixed => {
+                "[](double x, int digits){ return sixtyfps::SharedString::from_formatted_number(x, 0, digits, false); }".into()
+            }
+            BuiltinFunction::FormatNumber => {
+                "[](double x, int width, int precision, bool zero_padding){ return sixtyfps::SharedString::from_formatted_number(x, width > 0 ? width : 0, precision, zero_padding); }".into()
+            }
+            BuiltinFunction::AnimationElapsedTime => {
+                "[](const sixtyfps::StateInfo &state){ return sixtyfps::private_api::animation_elapsed_time(state); }".into()
+            }
 
         },
         Expression::ElementReference(_) => todo!("Element references are only supported in the context of built-in function calls at the moment"),
@@ -1378,6 +1625,15 @@ fn compile_expression(
             }
             _ => panic!("Expression::ObjectAccess's base expression is not an Object type"),
         },
+        Expression::ArrayIndex { array, index } => {
+            let ty = expr.ty().cpp_type().unwrap_or_else(|| "FIXME: report error".to_owned());
+            format!(
+                "[&](const auto &model, int index) -> {ty} {{ if (model) {{ model->track_changes(); if (index >= 0 && index < model->row_count()) return model->row_data(index); }} return {ty}(); }}({array}, {index})",
+                ty = ty,
+                array = compile_expression(array, component),
+                index = compile_expression(index, component),
+            )
+        }
         Expression::Cast { from, to } => {
             let f = compile_expression(&*from, component);
             match (from.ty(), to) {
@@ -1540,6 +1796,29 @@ fn compile_expression(
             "sixtyfps::EasingCurve(sixtyfps::EasingCurve::Tag::CubicBezier, {}, {}, {}, {})",
             a, b, c, d
         ),
+        Expression::EasingCurve(EasingCurve::Steps(n)) => {
+            format!("sixtyfps::EasingCurve(sixtyfps::EasingCurve::Tag::Steps, {}u)", n)
+        }
+        Expression::EasingCurve(curve) => {
+            let tag = match curve {
+                EasingCurve::EaseInElastic => "EaseInElastic",
+                EasingCurve::EaseOutElastic => "EaseOutElastic",
+                EasingCurve::EaseInOutElastic => "EaseInOutElastic",
+                EasingCurve::EaseInBounce => "EaseInBounce",
+                EasingCurve::EaseOutBounce => "EaseOutBounce",
+                EasingCurve::EaseInOutBounce => "EaseInOutBounce",
+                EasingCurve::EaseInBack => "EaseInBack",
+                EasingCurve::EaseOutBack => "EaseOutBack",
+                EasingCurve::EaseInOutBack => "EaseInOutBack",
+                EasingCurve::Linear | EasingCurve::CubicBezier(..) | EasingCurve::Steps(_) => {
+                    unreachable!()
+                }
+            };
+            format!("sixtyfps::EasingCurve(sixtyfps::EasingCurve::Tag::{})", tag)
+        }
+        Expression::EnumerationValue(value) if value.enumeration.node.is_some() => {
+            format!("{}::{}", value.enumeration.name, value.to_string())
+        }
         Expression::EnumerationValue(value) => {
             format!("sixtyfps::{}::{}", value.enumeration.name, value.to_string())
         }
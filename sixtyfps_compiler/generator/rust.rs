@@ -17,9 +17,9 @@
 use crate::expression_tree::{
     BuiltinFunction, EasingCurve, Expression, NamedReference, OperatorClass, Path,
 };
-use crate::langtype::Type;
+use crate::langtype::{Enumeration, Type};
 use crate::layout::LayoutGeometry;
-use crate::object_tree::{Component, Document, ElementRc};
+use crate::object_tree::{Component, Document, ElementRc, PropertyVisibility};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use std::{collections::BTreeMap, rc::Rc};
@@ -50,6 +50,10 @@ fn rust_type(
             let inner = rust_type(&o, span)?;
             Ok(quote!(sixtyfps::re_exports::ModelHandle<#inner>))
         }
+        Type::Enumeration(e) if e.node.is_some() => {
+            let e = format_ident!("{}", e.name);
+            Ok(quote!(#e))
+        }
         Type::Enumeration(e) => {
             let e = format_ident!("{}", e.name);
             Ok(quote!(sixtyfps::re_exports::#e))
@@ -74,6 +78,8 @@ pub fn generate(doc: &Document, diag: &mut BuildDiagnostics) -> Option<TokenStre
         .filter_map(|ty| {
             if let Type::Object { fields, name: Some(name) } = ty {
                 Some((format_ident!("{}", name), generate_struct(name, fields, diag)))
+            } else if let Type::Enumeration(en) = ty {
+                Some((format_ident!("{}", en.name), generate_enum(en)))
             } else {
                 None
             }
@@ -137,6 +143,24 @@ pub struct #component_id {
     }
 }
 
+fn generate_enum(en: &Enumeration) -> TokenStream {
+    let enum_id = format_ident!("{}", en.name);
+    let values = en.values.iter().map(|value| format_ident!("{}", value)).collect::<Vec<_>>();
+    let default_value = &values[en.default_value];
+    quote! {
+        #[allow(non_camel_case_types)]
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum #enum_id {
+            #(#values),*
+        }
+        impl Default for #enum_id {
+            fn default() -> Self {
+                Self::#default_value
+            }
+        }
+    }
+}
+
 fn handle_property_binding(
     component: &Rc<Component>,
     item_rc: &ElementRc,
@@ -144,9 +168,13 @@ fn handle_property_binding(
     binding_expression: &Expression,
     init: &mut Vec<TokenStream>,
 ) {
-    let rust_property = access_member(item_rc, prop_name, component, quote!(_self), false);
     let prop_type = item_rc.borrow().lookup_property(prop_name);
-    if matches!(prop_type, Type::Callback{..}) {
+    if matches!(prop_type, Type::Function { .. }) {
+        // The body of the function is generated as a method of the component
+        return;
+    }
+    let rust_property = access_member(item_rc, prop_name, component, quote!(_self), false);
+    if matches!(prop_type, Type::Callback { .. }) {
         let tokens_for_expression = compile_expression(binding_expression, &component);
         init.push(quote!(
             #rust_property.set_handler({
@@ -191,6 +219,19 @@ fn handle_property_binding(
                 quote! { sixtyfps::re_exports::set_state_binding(#rust_property, #binding_tokens); }
             } else {
                 match item_rc.borrow().property_animations.get(prop_name) {
+                    Some(crate::object_tree::PropertyAnimation::Static(anim))
+                        if super::is_dynamic_animation(anim) =>
+                    {
+                        let anim = property_animation_tokens(component, anim);
+                        quote! {
+                            let self_weak = sixtyfps::re_exports::VRc::downgrade(&self_pinned);
+                            #rust_property.set_dynamic_animated_binding(#binding_tokens, move || {
+                                let self_pinned = self_weak.upgrade().unwrap();
+                                let _self = self_pinned.as_pin_ref();
+                                #anim
+                            });
+                        }
+                    }
                     Some(crate::object_tree::PropertyAnimation::Static(anim)) => {
                         let anim = property_animation_tokens(component, anim);
                         quote! { #rust_property.set_animated_binding(#binding_tokens, #anim); }
@@ -251,8 +292,51 @@ fn generate_component(
     let mut declared_callbacks_types = vec![];
     let mut declared_callbacks_ret = vec![];
     let mut property_and_callback_accessors: Vec<TokenStream> = vec![];
+    let mut declared_functions: Vec<TokenStream> = vec![];
     for (prop_name, property_decl) in component.root_element.borrow().property_declarations.iter() {
         let prop_ident = format_ident!("{}", prop_name);
+        if let Type::Function { args, return_type } = &property_decl.property_type {
+            let function_ident = format_ident!("fn_{}", prop_name);
+            let function_args = args
+                .iter()
+                .map(|a| rust_type(a, &property_decl.type_node.span()))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap_or_else(|err| {
+                    diag.push_internal_error(err.into());
+                    vec![]
+                });
+            let body = component
+                .root_element
+                .borrow()
+                .bindings
+                .get(prop_name)
+                .map(|body| compile_expression(body, component));
+            declared_functions.push(if **return_type == Type::Void {
+                quote!(
+                    #[allow(dead_code, unused)]
+                    fn #function_ident(self: ::core::pin::Pin<&Self>, args: (#(#function_args,)*)) {
+                        use sixtyfps::re_exports::*;
+                        let _self = self;
+                        #body;
+                    }
+                )
+            } else {
+                let return_type = rust_type(&return_type, &property_decl.type_node.span())
+                    .unwrap_or_else(|err| {
+                        diag.push_internal_error(err.into());
+                        quote!()
+                    });
+                quote!(
+                    #[allow(dead_code, unused)]
+                    fn #function_ident(self: ::core::pin::Pin<&Self>, args: (#(#function_args,)*)) -> #return_type {
+                        use sixtyfps::re_exports::*;
+                        let _self = self;
+                        (#body) as _
+                    }
+                )
+            });
+            continue;
+        }
         if let Type::Callback { args, return_type } = &property_decl.property_type {
             declared_callbacks.push(prop_ident.clone());
             let callback_args = args
@@ -333,21 +417,23 @@ pub fn #getter_ident(&self) -> #rust_property_type {
                     }
                 ));
 
-                let set_value = property_set_value_tokens(
-                    component,
-                    &component.root_element,
-                    prop_name,
-                    quote!(value),
-                );
-                property_and_callback_accessors.push(quote!(
-                    #[allow(dead_code)]
-                    pub fn #setter_ident(&self, value: #rust_property_type) {
-                        #[allow(unused_imports)]
-                        use sixtyfps::re_exports::*;
-                        let _self = vtable::VRc::as_pin_ref(&self.0);
-                        #prop.#set_value
-                    }
-                ));
+                if property_decl.visibility != PropertyVisibility::Output {
+                    let set_value = property_set_value_tokens(
+                        component,
+                        &component.root_element,
+                        prop_name,
+                        quote!(value),
+                    );
+                    property_and_callback_accessors.push(quote!(
+                        #[allow(dead_code)]
+                        pub fn #setter_ident(&self, value: #rust_property_type) {
+                            #[allow(unused_imports)]
+                            use sixtyfps::re_exports::*;
+                            let _self = vtable::VRc::as_pin_ref(&self.0);
+                            #prop.#set_value
+                        }
+                    ));
+                }
             }
 
             if property_decl.is_alias.is_none() {
@@ -403,11 +489,47 @@ pub fn #setter_ident(&self, value: #rust_property_type) {
                     Default::default()
                 },
             ));
+            let mut repeated_init_code = Vec::new();
+            crate::object_tree::recurse_elem(&base_component.root_element, &(), &mut |elem, _| {
+                for code in &elem.borrow().init_code {
+                    let code = compile_expression(code, base_component);
+                    repeated_init_code.push(quote!({ let _ = #code; }));
+                }
+            });
+            let init_fn = quote! {
+                fn init(self: ::core::pin::Pin<&Self>) {
+                    #![allow(unused)]
+                    use sixtyfps::re_exports::*;
+                    let _self = self;
+                    #(#repeated_init_code)*
+                }
+            };
+            let exit_fn = if let Some(exit) = &repeated.exit_transition {
+                let exiting = access_named_reference(&exit.exiting, base_component, quote!(_self));
+                let exit_finished =
+                    access_named_reference(&exit.exit_finished, base_component, quote!(_self));
+                quote! {
+                    fn start_exit(self: ::core::pin::Pin<&Self>) -> bool {
+                        let _self = self;
+                        #exiting.set(true);
+                        true
+                    }
+                    fn exit_finished(self: ::core::pin::Pin<&Self>) -> bool {
+                        let _self = self;
+                        #exit_finished.get()
+                    }
+                }
+            } else {
+                quote!()
+            };
+
             extra_components.push(if repeated.is_conditional_element {
                 quote! {
                      impl sixtyfps::re_exports::RepeatedComponent for #rep_inner_component_id {
                         type Data = ();
                         fn update(&self, _: usize, _: Self::Data) { }
+                        #init_fn
+                        #exit_fn
                     }
                 }
             } else {
@@ -466,6 +588,8 @@ fn update(&self, index: usize, data: Self::Data) {
                             self.index.set(index);
                             self.model_data.set(data);
                         }
+                        #init_fn
+                        #exit_fn
                         #extra_fn
                     }
                 }
@@ -564,6 +688,36 @@ fn update(&self, index: usize, data: Self::Data) {
         }
     });
 
+    let mut change_tracker_names = Vec::new();
+    crate::object_tree::recurse_elem(&component.root_element, &(), &mut |elem, _| {
+        for (nr, handler) in &elem.borrow().change_callbacks {
+            let tracker_id = format_ident!("change_tracker_{}", change_tracker_names.len());
+            let prop = access_named_reference(nr, component, quote!(_self));
+            let handler = compile_expression(handler, component);
+            init.push(quote!(
+                _self.#tracker_id.init(
+                    {
+                        let self_weak = sixtyfps::re_exports::VRc::downgrade(&self_pinned);
+                        move || {
+                            let self_pinned = self_weak.upgrade().unwrap();
+                            let _self = self_pinned.as_pin_ref();
+                            #prop.get()
+                        }
+                    },
+                    {
+                        let self_weak = sixtyfps::re_exports::VRc::downgrade(&self_pinned);
+                        move || {
+                            let self_pinned = self_weak.upgrade().unwrap();
+                            let _self = self_pinned.as_pin_ref();
+                            let _ = #handler;
+                        }
+                    },
+                );
+            ));
+            change_tracker_names.push(tracker_id);
+        }
+    });
+
     let resource_symbols: Vec<proc_macro2::TokenStream> = component
         .embedded_file_resources
         .borrow()
@@ -656,6 +810,16 @@ fn drop(self: core::pin::Pin<&mut #inner_component_id>) {
         init.push(compile_expression(extra_init_code, component));
     }
 
+    // The init code of repeated components is run by the Repeater, once the model data is set
+    if !component.is_repeated() {
+        crate::object_tree::recurse_elem(&component.root_element, &(), &mut |elem, _| {
+            for code in &elem.borrow().init_code {
+                let code = compile_expression(code, component);
+                init.push(quote!({ let _ = #code; }));
+            }
+        });
+    }
+
     let (item_tree_impl, component_impl) = if component.is_global() {
         (None, None)
     } else {
@@ -796,6 +960,7 @@ fn from(value: #public_component_id) -> Self {
             #(#declared_property_vars : sixtyfps::re_exports::Property<#declared_property_types>,)*
             #(#declared_callbacks : sixtyfps::re_exports::Callback<(#(#declared_callbacks_types,)*), #declared_callbacks_ret>,)*
             #(#repeated_element_names : sixtyfps::re_exports::Repeater<#repeated_element_components>,)*
+            #(#change_tracker_names : sixtyfps::re_exports::ChangeTracker,)*
             #(#self_weak : sixtyfps::re_exports::OnceCell<sixtyfps::re_exports::VWeak<sixtyfps::re_exports::ComponentVTable, #inner_component_id>>,)*
             #(parent : sixtyfps::re_exports::VWeak<sixtyfps::re_exports::ComponentVTable, #parent_component_type>,)*
             #(#global_name : ::core::pin::Pin<::std::rc::Rc<#global_type>>,)*
@@ -815,6 +980,7 @@ pub fn new(#(parent: sixtyfps::re_exports::VWeak::<sixtyfps::re_exports::Compone
                     #(#declared_property_vars : ::core::default::Default::default(),)*
                     #(#declared_callbacks : ::core::default::Default::default(),)*
                     #(#repeated_element_names : ::core::default::Default::default(),)*
+                    #(#change_tracker_names : ::core::default::Default::default(),)*
                     #(#self_weak : ::core::default::Default::default(),)*
                     #(parent : parent as sixtyfps::re_exports::VWeak::<sixtyfps::re_exports::ComponentVTable, #parent_component_type>,)*
                     #(#global_name : #global_type::new(),)*
@@ -826,6 +992,7 @@ pub fn new(#(parent: sixtyfps::re_exports::VWeak::<sixtyfps::re_exports::Compone
             }
             #item_tree_impl
 
+            #(#declared_functions)*
         }
 
         #public_interface
@@ -870,6 +1037,21 @@ fn property_animation_tokens(
     let animation = animation.borrow();
     let bindings = animation.bindings.iter().map(|(prop, initializer)| {
         let prop_ident = format_ident!("{}", prop);
+        if prop == "keyframes" {
+            let values = match &initializer.expression {
+                Expression::Array { values, .. } => values,
+                _ => panic!("internal error: keyframes must be an array"),
+            };
+            let keyframes = values.iter().map(|keyframe| match keyframe {
+                Expression::Object { values, .. } => {
+                    let position = compile_expression(&values["position"], component);
+                    let value = compile_expression(&values["value"], component);
+                    quote!(sixtyfps::re_exports::KeyFrame { position: #position as _, value: #value as _ })
+                }
+                _ => panic!("internal error: keyframes must be objects"),
+            });
+            return quote!(#prop_ident: sixtyfps::re_exports::SharedVector::from_slice(&[#(#keyframes),*]));
+        }
         let initializer = compile_expression(initializer, component);
         quote!(#prop_ident: #initializer as _)
     });
@@ -887,6 +1069,19 @@ fn property_set_value_tokens(
     value_tokens: TokenStream,
 ) -> TokenStream {
     match element.borrow().property_animations.get(property_name) {
+        Some(crate::object_tree::PropertyAnimation::Static(animation))
+            if super::is_dynamic_animation(animation) =>
+        {
+            let animation_tokens = property_animation_tokens(component, animation);
+            quote!(set_dynamic_animated_value(#value_tokens, {
+                let self_weak = sixtyfps::re_exports::VRc::downgrade(&self.0);
+                move || {
+                    let self_pinned = self_weak.upgrade().unwrap();
+                    let _self = self_pinned.as_pin_ref();
+                    #animation_tokens
+                }
+            }))
+        }
         Some(crate::object_tree::PropertyAnimation::Static(animation)) => {
             let animation_tokens = property_animation_tokens(component, animation);
             quote!(set_animated_value(#value_tokens, #animation_tokens))
@@ -962,6 +1157,46 @@ fn access_member(
     }
 }
 
+/// Returns the code to call the function `nr` with the given tuple of arguments, from within
+/// `component`. The function can be declared in a parent component or in a global.
+fn call_function(
+    nr: &NamedReference,
+    component: &Rc<Component>,
+    component_rust: TokenStream,
+    args: TokenStream,
+) -> TokenStream {
+    let element = nr.element.upgrade().unwrap();
+    let enclosing_component = element.borrow().enclosing_component.upgrade().unwrap();
+    if Rc::ptr_eq(component, &enclosing_component) {
+        let inner_component_id = inner_component_id(&enclosing_component);
+        let function_ident = format_ident!("fn_{}", nr.name);
+        quote!(#inner_component_id::#function_ident(#component_rust, #args))
+    } else if enclosing_component.is_global() {
+        let mut root_component = component.clone();
+        let mut component_rust = component_rust;
+        while let Some(p) = root_component.parent_element.upgrade() {
+            root_component = p.borrow().enclosing_component.upgrade().unwrap();
+            component_rust = quote!(#component_rust.parent.upgrade().unwrap().as_pin_ref());
+        }
+        let global_id = format_ident!("global_{}", enclosing_component.id);
+        call_function(nr, &enclosing_component, quote!(#component_rust.#global_id.as_ref()), args)
+    } else {
+        call_function(
+            nr,
+            &component
+                .parent_element
+                .upgrade()
+                .unwrap()
+                .borrow()
+                .enclosing_component
+                .upgrade()
+                .unwrap(),
+            quote!(#component_rust.parent.upgrade().unwrap().as_pin_ref()),
+            args,
+        )
+    }
+}
+
 /// Call access_member  for a NamedReference
 fn access_named_reference(
     nr: &NamedReference,
@@ -983,7 +1218,7 @@ fn compile_expression(expr: &Expression, component: &Rc<Component>) -> TokenStre
             let f = compile_expression(&*from, &component);
             match (from.ty(), to) {
                 (Type::Float32, Type::String) | (Type::Int32, Type::String) => {
-                    quote!(sixtyfps::re_exports::SharedString::from(format!("{}", #f).as_str()))
+                    quote!(sixtyfps::re_exports::SharedString::from_number(#f as f64))
                 }
                 (Type::Float32, Type::Model) | (Type::Int32, Type::Model) => {
                     quote!(sixtyfps::re_exports::ModelHandle::new(std::rc::Rc::<usize>::new(#f as usize)))
@@ -1025,7 +1260,7 @@ fn compile_expression(expr: &Expression, component: &Rc<Component>) -> TokenStre
             BuiltinFunction::Round => quote!((|a| (a as f64).round())),
             BuiltinFunction::Ceil => quote!((|a| (a as f64).ceil())),
             BuiltinFunction::Floor => quote!((|a| (a as f64).floor())),
-            BuiltinFunction::SetFocusItem | BuiltinFunction::ShowPopupWindow => {
+            BuiltinFunction::SetFocusItem | BuiltinFunction::ShowPopupWindow | BuiltinFunction::Translate => {
                 panic!("internal error: should be handled directly in CallFunction")
             }
             BuiltinFunction::StringToFloat => {
@@ -1034,6 +1269,37 @@ fn compile_expression(expr: &Expression, component: &Rc<Component>) -> TokenStre
             BuiltinFunction::StringIsFloat => {
                 quote!((|x: SharedString| { <f64 as ::core::str::FromStr>::from_str(x.as_str()).is_ok() } ))
             }
+            BuiltinFunction::ArrayLength => {
+                quote!((|x: ModelHandle<_>| -> i32 { x.track_changes(); x.row_count() as i32 }))
+            }
+            BuiltinFunction::StringLength => {
+                quote!((|x: SharedString| -> i32 { x.chars().count() as i32 }))
+            }
+            BuiltinFunction::StringToUpper => {
+                quote!((|x: SharedString| SharedString::from(x.to_uppercase().as_str())))
+            }
+            BuiltinFunction::StringToLower => {
+                quote!((|x: SharedString| SharedString::from(x.to_lowercase().as_str())))
+            }
+            BuiltinFunction::StringContains => {
+                quote!((|x: SharedString, y: SharedString| x.contains(y.as_str())))
+            }
+            BuiltinFunction::StringSubstring => {
+                quote!((|x: SharedString, start, length| -> SharedString {
+                    x.chars().skip((start as i32).max(0) as usize).take((length as i32).max(0) as usize).collect::<String>().as_str().into()
+                }))
+            }
+            BuiltinFunction::NumberToFixed => {
+                quote!((|x, digits| SharedString::from_formatted_number(x as f64, 0, digits as i32, false)))
+            }
+            BuiltinFunction::FormatNumber => {
+                quote!((|x, width, precision, zero_padding: bool| SharedString::from_formatted_number(x as f64, (width as i32).max(0) as usize, precision as i32, zero_padding)))
+            }
+            BuiltinFunction::AnimationElapsedTime => {
+                quote!((|state: sixtyfps::StateInfo| -> i64 {
+                    sixtyfps::re_exports::animation_elapsed_time(state.change_time).map_or(i64::MAX, |d| d.as_millis() as i64)
+                }))
+            }
         },
         Expression::ElementReference(_) => todo!("Element references are only supported in the context of built-in function calls at the moment"),
         Expression::MemberFunction{ .. } => panic!("member function expressions must not appear in the code generator anymore"),
@@ -1079,6 +1345,20 @@ fn compile_expression(expr: &Expression, component: &Rc<Component>) -> TokenStre
             }
             _ => panic!("Expression::ObjectAccess's base expression is not an Object type"),
         },
+        Expression::ArrayIndex { array, index } => {
+            let array = compile_expression(array, component);
+            let index = compile_expression(index, component);
+            quote!(match (#array, (#index) as isize) {
+                (model, index) => {
+                    model.track_changes();
+                    if index >= 0 && (index as usize) < model.row_count() {
+                        model.row_data(index as usize)
+                    } else {
+                        ::core::default::Default::default()
+                    }
+                }
+            })
+        }
         Expression::CodeBlock(sub) => {
             let map = sub.iter().map(|e| compile_expression(e, &component));
             quote!({ #(#map);* })
@@ -1088,6 +1368,7 @@ fn compile_expression(expr: &Expression, component: &Rc<Component>) -> TokenStre
             component,
             quote!(_self),
         ),
+        Expression::FunctionReference(_) => panic!("function reference must be called"),
         Expression::FunctionCall { function, arguments } => {
             match &**function {
                 Expression::BuiltinFunctionReference(BuiltinFunction::SetFocusItem) => {
@@ -1128,6 +1409,35 @@ fn compile_expression(expr: &Expression, component: &Rc<Component>) -> TokenStre
                         panic!("internal error: argument to SetFocusItem must be an element")
                     }
                 }
+                Expression::FunctionReference(nr) => {
+                    let args = if let Type::Function { args, .. } = function.ty() {
+                        args
+                    } else {
+                        panic!("internal error: a function reference must have a function type")
+                    };
+                    let a = arguments.iter().zip(args.iter()).map(|(a, ty)| {
+                        let a = compile_expression(a, &component);
+                        match ty {
+                            Type::Bool => quote!((#a) as bool),
+                            Type::Int32 => quote!((#a) as i32),
+                            Type::Float32 => quote!((#a) as f32),
+                            _ => quote!((#a).clone()),
+                        }
+                    });
+                    call_function(nr, component, quote!(_self), quote!((#(#a,)*)))
+                }
+                Expression::BuiltinFunctionReference(BuiltinFunction::Translate) => {
+                    let string_literal = |e: &Expression| match e {
+                        Expression::StringLiteral(s) => s.clone(),
+                        _ => panic!("internal error: the strings of a translation must be literals"),
+                    };
+                    let original = string_literal(&arguments[0]);
+                    let context = string_literal(&arguments[1]);
+                    let plural = string_literal(&arguments[2]);
+                    let n = compile_expression(&arguments[3], &component);
+                    let values = arguments[4..].iter().map(|a| compile_expression(a, &component));
+                    quote!(sixtyfps::re_exports::translate(#original, #context, #plural, (#n) as i32, &[#(#values),*]))
+                }
                 _ => {
                     let f = compile_expression(function, &component);
                     let a = arguments.iter().map(|a| compile_expression(a, &component));
@@ -1260,16 +1570,31 @@ fn compile_expression(expr: &Expression, component: &Rc<Component>) -> TokenStre
             let name = format_ident!("{}", name);
             quote!(#name)
         }
-        Expression::EasingCurve(EasingCurve::Linear) => {
-            quote!(sixtyfps::re_exports::EasingCurve::Linear)
-        }
-        Expression::EasingCurve(EasingCurve::CubicBezier(a, b, c, d)) => {
-            quote!(sixtyfps::re_exports::EasingCurve::CubicBezier([#a, #b, #c, #d]))
+        Expression::EasingCurve(curve) => {
+            let curve = match curve {
+                EasingCurve::Linear => quote!(Linear),
+                EasingCurve::CubicBezier(a, b, c, d) => quote!(CubicBezier([#a, #b, #c, #d])),
+                EasingCurve::EaseInElastic => quote!(EaseInElastic),
+                EasingCurve::EaseOutElastic => quote!(EaseOutElastic),
+                EasingCurve::EaseInOutElastic => quote!(EaseInOutElastic),
+                EasingCurve::EaseInBounce => quote!(EaseInBounce),
+                EasingCurve::EaseOutBounce => quote!(EaseOutBounce),
+                EasingCurve::EaseInOutBounce => quote!(EaseInOutBounce),
+                EasingCurve::EaseInBack => quote!(EaseInBack),
+                EasingCurve::EaseOutBack => quote!(EaseOutBack),
+                EasingCurve::EaseInOutBack => quote!(EaseInOutBack),
+                EasingCurve::Steps(n) => quote!(Steps(#n)),
+            };
+            quote!(sixtyfps::re_exports::EasingCurve::#curve)
         }
         Expression::EnumerationValue(value) => {
             let base_ident = format_ident!("{}", value.enumeration.name);
             let value_ident = format_ident!("{}", value.to_string());
-            quote!(sixtyfps::re_exports::#base_ident::#value_ident)
+            if value.enumeration.node.is_some() {
+                quote!(#base_ident::#value_ident)
+            } else {
+                quote!(sixtyfps::re_exports::#base_ident::#value_ident)
+            }
         }
     }
 }
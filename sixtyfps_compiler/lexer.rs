@@ -95,6 +95,13 @@ pub fn lex_string(text: &str) -> usize {
     end
 }
 
+/// Returns the value of a string literal token, or None if it is not a string literal
+pub fn unescape_string(string: &str) -> Option<String> {
+    let string = string.strip_prefix('"')?.strip_suffix('"')?;
+    // TODO: remove slashes
+    Some(string.into())
+}
+
 pub fn lex_number(text: &str) -> usize {
     let mut len = 0;
     let mut chars = text.chars();
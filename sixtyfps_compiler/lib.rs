@@ -42,6 +42,7 @@
 mod passes {
     pub mod apply_default_properties_from_style;
     pub mod check_expressions;
+    pub mod check_shortcut_keys;
     pub mod collect_globals;
     pub mod collect_structs;
     pub mod compile_paths;
@@ -50,6 +51,7 @@ mod passes {
     pub mod embed_resources;
     pub mod focus_item;
     pub mod inlining;
+    pub mod lower_animation_sequences;
     pub mod lower_layout;
     pub mod lower_popups;
     pub mod lower_states;
@@ -159,9 +161,11 @@ pub async fn run_passes(
     compiler_config: &CompilerConfiguration,
 ) {
     passes::resolving::resolve_expressions(doc, diag);
+    passes::lower_animation_sequences::lower_animation_sequences(doc);
     passes::inlining::inline(doc);
     passes::check_expressions::check_expressions(doc, diag);
     passes::compile_paths::compile_paths(&doc.root_component, &doc.local_registry, diag);
+    passes::check_shortcut_keys::check_shortcut_keys(&doc.root_component, diag);
     passes::unique_id::assign_unique_id(&doc.root_component);
     passes::focus_item::determine_initial_focus_item(&doc.root_component, diag);
     passes::materialize_fake_properties::materialize_fake_properties(&doc.root_component);
@@ -14,11 +14,11 @@
 use crate::diagnostics::{FileDiagnostics, Spanned, SpannedWithSourceFile};
 use crate::expression_tree::Unit;
 use crate::expression_tree::{Expression, ExpressionSpanned, NamedReference};
-use crate::langtype::{NativeClass, Type};
+use crate::langtype::{Enumeration, NativeClass, Type};
 use crate::parser::{identifier_text, syntax_nodes, SyntaxKind, SyntaxNodeWithSourceFile};
 use crate::typeregister::TypeRegister;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::rc::{Rc, Weak};
 
 /// The full document (a complete file)
@@ -66,6 +66,45 @@ pub fn from_node(
                 local_registry.insert_type(ty.clone());
                 inner_structs.push(ty);
             };
+        let process_enum = |n: syntax_nodes::EnumDeclaration,
+                            diag: &mut FileDiagnostics,
+                            local_registry: &mut TypeRegister| {
+            let name = match identifier_text(&n.DeclaredIdentifier()) {
+                Some(name) => name,
+                None => {
+                    assert!(diag.has_error());
+                    return;
+                }
+            };
+            let mut values: Vec<String> = vec![];
+            for value_node in n.EnumValue() {
+                let value = match identifier_text(&value_node) {
+                    Some(value) => value,
+                    None => continue,
+                };
+                if values.contains(&value) {
+                    diag.push_error(format!("Duplicated enum value '{}'", value), &value_node);
+                } else {
+                    values.push(value);
+                }
+            }
+            if values.is_empty() {
+                diag.push_error(
+                    format!("The enum '{}' must have at least one value", name),
+                    &n.DeclaredIdentifier(),
+                );
+                return;
+            }
+            local_registry.insert_type_with_name(
+                Type::Enumeration(Rc::new(Enumeration {
+                    name: name.clone(),
+                    values,
+                    default_value: 0,
+                    node: Some(n),
+                })),
+                name,
+            );
+        };
 
         for n in node.children() {
             match n.kind() {
@@ -73,6 +112,7 @@ pub fn from_node(
                 SyntaxKind::StructDeclaration => {
                     process_struct(n.into(), diag, &mut local_registry)
                 }
+                SyntaxKind::EnumDeclaration => process_enum(n.into(), diag, &mut local_registry),
                 SyntaxKind::ExportsList => {
                     for n in n.children() {
                         match n.kind() {
@@ -82,6 +122,9 @@ pub fn from_node(
                             SyntaxKind::StructDeclaration => {
                                 process_struct(n.into(), diag, &mut local_registry)
                             }
+                            SyntaxKind::EnumDeclaration => {
+                                process_enum(n.into(), diag, &mut local_registry)
+                            }
                             _ => {}
                         }
                     }
@@ -173,16 +216,18 @@ pub fn from_node(
         tr: &TypeRegister,
     ) -> Rc<Self> {
         let mut child_insertion_point = None;
+        let root_element = Element::from_node(
+            node.Element(),
+            "root".into(),
+            Type::Invalid,
+            &mut child_insertion_point,
+            diag,
+            tr,
+        );
+        check_no_enter_exit_transitions(&root_element, diag);
         let c = Component {
             id: identifier_text(&node.DeclaredIdentifier()).unwrap_or_default(),
-            root_element: Element::from_node(
-                node.Element(),
-                "root".into(),
-                Type::Invalid,
-                &mut child_insertion_point,
-                diag,
-                tr,
-            ),
+            root_element,
             child_insertion_point: RefCell::new(child_insertion_point),
             ..Default::default()
         };
@@ -202,12 +247,38 @@ pub fn is_global(&self) -> bool {
             _ => false,
         }
     }
+
+    /// This component was created for a repeated element (`for` or `if`)
+    pub fn is_repeated(&self) -> bool {
+        self.parent_element.upgrade().map_or(false, |e| e.borrow().repeated.is_some())
+    }
+}
+
+/// The qualifier of a property declaration, which tells how the property can be accessed
+/// from other components and from the native API.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PropertyVisibility {
+    /// `private property`: only accessible from within the component
+    Private,
+    /// `in property`: can be set from outside, but not assigned from within the component
+    Input,
+    /// `out property`: can only be read from outside
+    Output,
+    /// `in-out property`, or a property without qualifier
+    InOut,
+}
+
+impl Default for PropertyVisibility {
+    fn default() -> Self {
+        PropertyVisibility::InOut
+    }
 }
 
 #[derive(Clone, Debug, Default)]
 pub struct PropertyDeclaration {
     pub property_type: Type,
     pub type_node: Option<SyntaxNodeWithSourceFile>,
+    pub visibility: PropertyVisibility,
     /// Tells if getter and setter will be added to expose in the native language API
     pub expose_in_public_api: bool,
     /// Public API property exposed as an alias: it shouldn't be generated but instead forward to the alias.
@@ -278,6 +349,21 @@ pub struct Element {
     pub states: Vec<State>,
     pub transitions: Vec<Transition>,
 
+    /// The `enter { ... }` and `exit { ... }` blocks of an element created by `if` or `for`.
+    /// They are lowered to states and transitions by the lower_states pass.
+    pub enter_exit_transitions: Vec<EnterExitTransition>,
+
+    /// The `changed prop => { ... }` handlers: the property, and the code to run when it changes
+    pub change_callbacks: Vec<(NamedReference, Expression)>,
+
+    /// The `init => { ... }` handlers, run once when the component containing this element is created
+    pub init_code: Vec<Expression>,
+
+    /// The `sequence { ... }` blocks of animations. Each sequence is a list of steps, and each step
+    /// contains the animation elements of one `animate` within the sequence.
+    /// This is lowered to delays during the resolving pass.
+    pub animation_sequences: Vec<Vec<Vec<ElementRc>>>,
+
     /// true when this item's geometry is handled by a layout
     pub child_of_layout: bool,
 
@@ -310,6 +396,14 @@ pub struct ListViewInfo {
     pub listview_width: NamedReference,
 }
 
+#[derive(Debug, Clone)]
+pub struct ExitTransitionInfo {
+    /// Boolean property set to true by the repeater when the element is removed
+    pub exiting: NamedReference,
+    /// Boolean property that becomes true once the exit animations are over
+    pub exit_finished: NamedReference,
+}
+
 #[derive(Debug, Clone)]
 /// If the parent element is a repeated element, this has information about the models
 pub struct RepeatedElementInfo {
@@ -322,6 +416,8 @@ pub struct RepeatedElementInfo {
     pub is_conditional_element: bool,
     /// When the for is the delegate of a ListView
     pub is_listview: Option<ListViewInfo>,
+    /// When the element has an `exit` transition. Set by the lower_states pass
+    pub exit_transition: Option<ExitTransitionInfo>,
 }
 
 pub type ElementRc = Rc<RefCell<Element>>;
@@ -371,8 +467,10 @@ pub fn from_node(
             node.ChildrenPlaceholder().map(|n| error_on(&n, "sub elements"));
             node.CallbackConnection().for_each(|n| error_on(&n, "callback connections"));
             node.PropertyAnimation().for_each(|n| error_on(&n, "animations"));
+            node.AnimationSequence().for_each(|n| error_on(&n, "animations"));
             node.States().for_each(|n| error_on(&n, "states"));
             node.Transitions().for_each(|n| error_on(&n, "transitions"));
+            node.PropertyChangedCallback().for_each(|n| error_on(&n, "change callbacks"));
             (Type::Void, String::new())
         };
         let mut r = Element { id, base_type, node: Some(node.clone()), ..Default::default() };
@@ -396,11 +494,23 @@ pub fn from_node(
                 );
             }
 
+            let visibility = match prop_decl
+                .child_token(SyntaxKind::Identifier)
+                .map(|t| crate::parser::normalize_identifier(t.text()))
+                .as_deref()
+            {
+                Some("private") => PropertyVisibility::Private,
+                Some("in") => PropertyVisibility::Input,
+                Some("out") => PropertyVisibility::Output,
+                _ => PropertyVisibility::InOut,
+            };
+
             r.property_declarations.insert(
                 prop_name.clone(),
                 PropertyDeclaration {
                     property_type: prop_type,
                     type_node: Some(type_node.into()),
+                    visibility,
                     ..Default::default()
                 },
             );
@@ -465,13 +575,58 @@ pub fn from_node(
             );
         }
 
+        for func_node in node.Function() {
+            let name = identifier_text(&func_node.DeclaredIdentifier()).unwrap();
+            let name_token =
+                func_node.DeclaredIdentifier().child_token(SyntaxKind::Identifier).unwrap();
+            if r.property_declarations
+                .get(&name)
+                .map_or(false, |d| matches!(d.property_type, Type::Function { .. }))
+            {
+                diag.push_error("Duplicated function".into(), &name_token);
+                continue;
+            } else if !matches!(r.lookup_property(&name), Type::Invalid) {
+                diag.push_error(format!("Cannot override '{}'", name), &name_token);
+                continue;
+            }
+            let args = func_node
+                .ArgumentDeclaration()
+                .map(|arg| type_from_node(arg.Type(), diag, tr))
+                .collect();
+            let return_type = Box::new(
+                func_node
+                    .ReturnType()
+                    .map_or(Type::Void, |ret_ty| type_from_node(ret_ty.Type(), diag, tr)),
+            );
+            r.property_declarations.insert(
+                name.clone(),
+                PropertyDeclaration {
+                    property_type: Type::Function { return_type, args },
+                    type_node: Some(func_node.clone().into()),
+                    ..Default::default()
+                },
+            );
+            r.bindings.insert(name, ExpressionSpanned::new_uncompiled(func_node.into()));
+        }
+
         for con_node in node.CallbackConnection() {
             let name = match identifier_text(&con_node) {
                 Some(x) => x,
                 None => continue,
             };
             let prop_type = r.lookup_property(&name);
-            if let Type::Callback { args, .. } = prop_type {
+            if name == "init" && prop_type == Type::Invalid {
+                if let Some(arg) = con_node.DeclaredIdentifier().next() {
+                    diag.push_error("'init' does not have arguments".into(), &arg);
+                } else if !r.init_code.is_empty() {
+                    diag.push_error(
+                        "Duplicated callback".into(),
+                        &con_node.child_token(SyntaxKind::Identifier).unwrap(),
+                    );
+                } else {
+                    r.init_code.push(Expression::Uncompiled(con_node.clone().into()));
+                }
+            } else if let Type::Callback { args, .. } = prop_type {
                 let num_arg = con_node.DeclaredIdentifier().count();
                 if num_arg > args.len() {
                     diag.push_error(
@@ -501,7 +656,8 @@ pub fn from_node(
             }
         }
 
-        for anim in node.PropertyAnimation() {
+        let mut add_animation = |r: &mut Element, anim: &syntax_nodes::PropertyAnimation| {
+            let mut anim_elements = vec![];
             if let Some(star) = anim.child_token(SyntaxKind::Star) {
                 diag.push_error(
                     "catch-all property is only allowed within transitions".into(),
@@ -512,13 +668,10 @@ pub fn from_node(
                 match QualifiedTypeName::from_node(prop_name_token.clone()).members.as_slice() {
                     [prop_name] => {
                         let prop_type = r.lookup_property(&prop_name);
-                        if let Some(anim_element) = animation_element_from_node(
-                            &anim,
-                            &prop_name_token,
-                            prop_type,
-                            diag,
-                            tr,
-                        ) {
+                        if let Some(anim_element) =
+                            animation_element_from_node(anim, &prop_name_token, prop_type, diag, tr)
+                        {
+                            anim_elements.push(anim_element.clone());
                             if r.property_animations
                                 .insert(prop_name.clone(), PropertyAnimation::Static(anim_element))
                                 .is_some()
@@ -533,6 +686,17 @@ pub fn from_node(
                     ),
                 }
             }
+            anim_elements
+        };
+
+        for anim in node.PropertyAnimation() {
+            add_animation(&mut r, &anim);
+        }
+
+        for sequence in node.AnimationSequence() {
+            let steps =
+                sequence.PropertyAnimation().map(|anim| add_animation(&mut r, &anim)).collect();
+            r.animation_sequences.push(steps);
         }
 
         let mut children_placeholder = None;
@@ -549,14 +713,16 @@ pub fn from_node(
                 }
                 if let Some(element_node) = se.child_node(SyntaxKind::Element) {
                     let parent_type = r.borrow().base_type.clone();
-                    r.borrow_mut().children.push(Element::from_node(
+                    let child = Element::from_node(
                         element_node.into(),
                         id,
                         parent_type,
                         component_child_insertion_point,
                         diag,
                         tr,
-                    ));
+                    );
+                    check_no_enter_exit_transitions(&child, diag);
+                    r.borrow_mut().children.push(child);
                 } else {
                     assert!(diag.has_error());
                 }
@@ -621,13 +787,9 @@ pub fn from_node(
             if let Some(star) = trs.child_token(SyntaxKind::Star) {
                 diag.push_error("TODO: catch-all not yet implemented".into(), &star);
             };
-            let trans = Transition {
-                is_out: identifier_text(&trs).unwrap_or_default() == "out",
-                state_id: identifier_text(&trs.DeclaredIdentifier()).unwrap_or_default(),
-                property_animations: trs
-                    .PropertyAnimation()
-                    .flat_map(|pa| pa.QualifiedName().map(move |qn| (pa.clone(), qn)))
-                    .filter_map(|(pa, qn)| {
+            let mut transition_animations = |pa: syntax_nodes::PropertyAnimation| {
+                pa.QualifiedName()
+                    .filter_map(|qn| {
                         let (ne, prop_type) =
                             lookup_property_from_qualified_name(qn.clone(), &r, diag);
                         if prop_type == Type::Invalid {
@@ -637,12 +799,136 @@ pub fn from_node(
                         animation_element_from_node(&pa, &qn, prop_type, diag, tr)
                             .map(|anim_element| (ne, anim_element))
                     })
-                    .collect(),
+                    .collect::<Vec<_>>()
+            };
+            let mut property_animations: Vec<_> =
+                trs.PropertyAnimation().flat_map(&mut transition_animations).collect();
+            for sequence in trs.AnimationSequence() {
+                let mut steps = vec![];
+                for pa in sequence.PropertyAnimation() {
+                    let step = transition_animations(pa);
+                    steps.push(step.iter().map(|(_, anim_element)| anim_element.clone()).collect());
+                    property_animations.extend(step);
+                }
+                r.borrow_mut().animation_sequences.push(steps);
+            }
+            let mut animation_finished = None;
+            for con_node in trs.CallbackConnection() {
+                let name = identifier_text(&con_node).unwrap_or_default();
+                if name != "animation_finished" {
+                    diag.push_error(
+                        format!("'{}' is not a callback of transitions", name),
+                        &con_node.child_token(SyntaxKind::Identifier).unwrap(),
+                    );
+                } else if let Some(arg) = con_node.DeclaredIdentifier().next() {
+                    diag.push_error("'animation-finished' does not have arguments".into(), &arg);
+                } else if animation_finished.is_some() {
+                    diag.push_error(
+                        "Duplicated callback".into(),
+                        &con_node.child_token(SyntaxKind::Identifier).unwrap(),
+                    );
+                } else {
+                    animation_finished = Some(Expression::Uncompiled(con_node.into()));
+                }
+            }
+            let trans = Transition {
+                is_out: identifier_text(&trs).unwrap_or_default() == "out",
+                state_id: identifier_text(&trs.DeclaredIdentifier()).unwrap_or_default(),
+                property_animations,
+                animation_finished,
                 node: trs.DeclaredIdentifier().into(),
             };
             r.borrow_mut().transitions.push(trans);
         }
 
+        for ee in node.EnterExitTransition() {
+            let property_changes = ee
+                .StatePropertyChange()
+                .map(|s| {
+                    let (ne, _) = lookup_property_from_qualified_name(s.QualifiedName(), &r, diag);
+                    (ne, Expression::Uncompiled(s.BindingExpression().into()))
+                })
+                .collect();
+            let property_animations = ee
+                .PropertyAnimation()
+                .flat_map(|pa| {
+                    if let Some(star) = pa.child_token(SyntaxKind::Star) {
+                        diag.push_error(
+                            "catch-all property is only allowed within transitions".into(),
+                            &star,
+                        )
+                    };
+                    pa.QualifiedName()
+                        .filter_map(|qn| {
+                            let (ne, prop_type) =
+                                lookup_property_from_qualified_name(qn.clone(), &r, diag);
+                            if prop_type == Type::Invalid {
+                                debug_assert!(diag.has_error()); // Error should have been reported already
+                                return None;
+                            }
+                            animation_element_from_node(&pa, &qn, prop_type, diag, tr)
+                                .map(|anim_element| (ne, anim_element))
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+            let is_exit = identifier_text(&ee).unwrap_or_default() == "exit";
+            if r.borrow().enter_exit_transitions.iter().any(|t| t.is_exit == is_exit) {
+                diag.push_error(
+                    format!("Duplicated '{}' transition", if is_exit { "exit" } else { "enter" }),
+                    &ee,
+                );
+                continue;
+            }
+            r.borrow_mut().enter_exit_transitions.push(EnterExitTransition {
+                is_exit,
+                property_changes,
+                property_animations,
+                node: ee.into(),
+            });
+        }
+
+        for changed in node.PropertyChangedCallback() {
+            let name_node = changed.DeclaredIdentifier();
+            let name = match identifier_text(&name_node) {
+                Some(name) => name,
+                None => continue,
+            };
+            let prop_type = r.borrow().lookup_property(&name);
+            if !prop_type.is_property_type() {
+                diag.push_error(
+                    match prop_type {
+                        Type::Invalid => {
+                            format!("Unknown property {}{}", name, name_for_looup_errors)
+                        }
+                        Type::Callback { .. } => {
+                            format!(
+                                "'{}' is a callback. Only properties can have a change callback",
+                                name
+                            )
+                        }
+                        _ => format!("'{}' is not a property", name),
+                    },
+                    &name_node,
+                );
+                continue;
+            }
+            if !r.borrow().property_declarations.contains_key(&name)
+                && r.borrow().property_visibility(&name) == Some(PropertyVisibility::Private)
+            {
+                diag.push_error(private_property_error(&name), &name_node);
+                continue;
+            }
+            if r.borrow().change_callbacks.iter().any(|(nr, _)| nr.name == name) {
+                diag.push_error(format!("Duplicated change callback for '{}'", name), &name_node);
+                continue;
+            }
+            r.borrow_mut().change_callbacks.push((
+                NamedReference { element: Rc::downgrade(&r), name },
+                Expression::Uncompiled(changed.into()),
+            ));
+        }
+
         r
     }
 
@@ -673,6 +959,7 @@ fn from_repeated_node(
             index_id: node.RepeatedIndex().and_then(|r| identifier_text(&r)).unwrap_or_default(),
             is_conditional_element: false,
             is_listview,
+            exit_transition: None,
         };
         let e = Element::from_node(
             node.Element(),
@@ -699,6 +986,7 @@ fn from_conditional_node(
             index_id: String::new(),
             is_conditional_element: true,
             is_listview: None,
+            exit_transition: None,
         };
         let e = Element::from_node(
             node.Element(),
@@ -721,6 +1009,18 @@ pub fn lookup_property(&self, name: &str) -> Type {
             .unwrap_or_else(|| self.base_type.lookup_property(name))
     }
 
+    /// Return the visibility of a property declared in this element or in one of its base
+    /// components, or None if the property is not declared with `property` (builtin properties)
+    pub fn property_visibility(&self, name: &str) -> Option<PropertyVisibility> {
+        if let Some(decl) = self.property_declarations.get(name) {
+            return Some(decl.visibility);
+        }
+        match &self.base_type {
+            Type::Component(c) => c.root_element.borrow().property_visibility(name),
+            _ => None,
+        }
+    }
+
     /// Return the Span of this element in the AST for error reporting
     pub fn span(&self) -> crate::diagnostics::Span {
         self.node.as_ref().map(|n| n.span()).unwrap_or_default()
@@ -753,6 +1053,17 @@ fn parse_bindings(
                     },
                     &name_token,
                 );
+            } else if !self.property_declarations.contains_key(&name) {
+                match self.property_visibility(&name) {
+                    Some(PropertyVisibility::Output) => diag.push_error(
+                        format!("Cannot assign to output property '{}'", name),
+                        &name_token,
+                    ),
+                    Some(PropertyVisibility::Private) => {
+                        diag.push_error(private_property_error(&name), &name_token)
+                    }
+                    _ => (),
+                }
             }
             if self.bindings.insert(name, ExpressionSpanned::new_uncompiled(b)).is_some() {
                 diag.push_error("Duplicated property binding".into(), &name_token);
@@ -775,6 +1086,24 @@ pub fn native_class(&self) -> Option<Rc<NativeClass>> {
     }
 }
 
+/// The error reported when a private property is accessed from another component
+pub fn private_property_error(name: &str) -> String {
+    format!(
+        "The property '{}' is private. Annotate it with 'in', 'out' or 'in-out' to make it accessible from other components",
+        name
+    )
+}
+
+/// Report an error for the `enter` and `exit` blocks of an element that is not created by `if` or `for`
+fn check_no_enter_exit_transitions(elem: &ElementRc, diag: &mut FileDiagnostics) {
+    for t in &elem.borrow().enter_exit_transitions {
+        diag.push_error(
+            "'enter' and 'exit' can only be used in elements created with 'if' or 'for'".into(),
+            &t.node,
+        );
+    }
+}
+
 /// Create a Type for this node
 pub fn type_from_node(
     node: syntax_nodes::Type,
@@ -844,6 +1173,38 @@ fn animation_element_from_node(
             }),
             diag,
         );
+        if let Type::Builtin(builtin_base) = &anim_element.base_type {
+            for (prop, expr) in &builtin_base.default_bindings {
+                anim_element.bindings.entry(prop.clone()).or_insert_with(|| expr.clone().into());
+            }
+        }
+        for (idx, keyframes) in anim.KeyFrames().enumerate() {
+            if idx > 0 {
+                diag.push_error("Duplicated keyframes".into(), &keyframes);
+                continue;
+            }
+            if !matches!(
+                prop_type,
+                Type::Float32 | Type::Int32 | Type::Length | Type::LogicalLength
+            ) {
+                diag.push_error(
+                    format!("Keyframes cannot be used to animate a property of type {}", prop_type),
+                    &keyframes,
+                );
+                continue;
+            }
+            let mut fields = BTreeMap::new();
+            fields.insert("position".to_owned(), Type::Float32);
+            fields.insert("value".to_owned(), prop_type.clone());
+            anim_element.property_declarations.insert(
+                "keyframes".into(),
+                Type::Array(Box::new(Type::Object { fields, name: None })).into(),
+            );
+            anim_element.bindings.insert(
+                "keyframes".into(),
+                ExpressionSpanned::new_uncompiled(keyframes.clone().into()),
+            );
+        }
         Some(Rc::new(RefCell::new(anim_element)))
     }
 }
@@ -1037,9 +1398,37 @@ fn visit_element_expressions_simple(
         for (_, a) in &mut t.property_animations {
             visit_element_expressions_simple(a, &mut vis);
         }
+        if let Some(e) = &mut t.animation_finished {
+            vis(e, None, &|| Type::Void);
+        }
     }
     elem.borrow_mut().transitions = transitions;
 
+    let mut enter_exit = std::mem::take(&mut elem.borrow_mut().enter_exit_transitions);
+    for t in &mut enter_exit {
+        for (ne, e) in &mut t.property_changes {
+            vis(e, Some(ne.name.as_ref()), &|| {
+                ne.element.upgrade().unwrap().borrow().lookup_property(ne.name.as_ref())
+            });
+        }
+        for (_, a) in &mut t.property_animations {
+            visit_element_expressions_simple(a, &mut vis);
+        }
+    }
+    elem.borrow_mut().enter_exit_transitions = enter_exit;
+
+    let mut change_callbacks = std::mem::take(&mut elem.borrow_mut().change_callbacks);
+    for (_, e) in &mut change_callbacks {
+        vis(e, None, &|| Type::Void);
+    }
+    elem.borrow_mut().change_callbacks = change_callbacks;
+
+    let mut init_code = std::mem::take(&mut elem.borrow_mut().init_code);
+    for e in &mut init_code {
+        vis(e, None, &|| Type::Void);
+    }
+    elem.borrow_mut().init_code = init_code;
+
     let mut property_animations = std::mem::take(&mut elem.borrow_mut().property_animations);
     for anim_elem in property_animations.values_mut() {
         match anim_elem {
@@ -1064,7 +1453,9 @@ pub fn visit_all_named_references_in_element(
     fn recurse_expression(expr: &mut Expression, vis: &mut impl FnMut(&mut NamedReference)) {
         expr.visit_mut(|sub| recurse_expression(sub, vis));
         match expr {
-            Expression::PropertyReference(r) | Expression::CallbackReference(r) => vis(r),
+            Expression::PropertyReference(r)
+            | Expression::CallbackReference(r)
+            | Expression::FunctionReference(r) => vis(r),
             Expression::TwoWayBinding(r, _) => vis(r),
             // This is not really a named reference, but the result is the same, it need to be updated
             // FIXME: this should probably be lowered into a PropertyReference
@@ -1093,6 +1484,21 @@ fn recurse_expression(expr: &mut Expression, vis: &mut impl FnMut(&mut NamedRefe
         }
     }
     elem.borrow_mut().transitions = transitions;
+    let mut enter_exit = std::mem::take(&mut elem.borrow_mut().enter_exit_transitions);
+    for t in &mut enter_exit {
+        for (r, _) in &mut t.property_changes {
+            vis(r);
+        }
+        for (r, _) in &mut t.property_animations {
+            vis(r)
+        }
+    }
+    elem.borrow_mut().enter_exit_transitions = enter_exit;
+    let mut change_callbacks = std::mem::take(&mut elem.borrow_mut().change_callbacks);
+    for (r, _) in &mut change_callbacks {
+        vis(r);
+    }
+    elem.borrow_mut().change_callbacks = change_callbacks;
     let mut repeated = std::mem::take(&mut elem.borrow_mut().repeated);
     if let Some(r) = &mut repeated {
         if let Some(lv) = &mut r.is_listview {
@@ -1102,6 +1508,10 @@ fn recurse_expression(expr: &mut Expression, vis: &mut impl FnMut(&mut NamedRefe
             vis(&mut lv.listview_height);
             vis(&mut lv.listview_width);
         }
+        if let Some(exit) = &mut r.exit_transition {
+            vis(&mut exit.exiting);
+            vis(&mut exit.exit_finished);
+        }
     }
     elem.borrow_mut().repeated = repeated;
 }
@@ -1149,12 +1559,25 @@ pub struct State {
     pub property_changes: Vec<(NamedReference, Expression)>,
 }
 
+#[derive(Debug, Clone)]
+pub struct EnterExitTransition {
+    /// false for `enter`, true for `exit`
+    pub is_exit: bool,
+    /// The value of the properties before the element entered, or after it exited
+    pub property_changes: Vec<(NamedReference, Expression)>,
+    pub property_animations: Vec<(NamedReference, ElementRc)>,
+    /// Node pointing to the `enter` or `exit` block
+    pub node: SyntaxNodeWithSourceFile,
+}
+
 #[derive(Debug, Clone)]
 pub struct Transition {
     /// false for 'to', true for 'out'
     pub is_out: bool,
     pub state_id: String,
     pub property_animations: Vec<(NamedReference, ElementRc)>,
+    /// The code of the `animation-finished` callback
+    pub animation_finished: Option<Expression>,
     /// Node pointing to the state name
     pub node: SyntaxNodeWithSourceFile,
 }
@@ -1218,6 +1641,16 @@ struct NamedExport {
             },
         ));
 
+        exports.extend(doc.ExportsList().flat_map(|exports| exports.EnumDeclaration()).map(|en| {
+            let name = identifier_text(&en.DeclaredIdentifier())
+                .expect("internal error: cannot export enum without name");
+            NamedExport {
+                internal_name_ident: en.DeclaredIdentifier().into(),
+                internal_name: name.clone(),
+                exported_name: name,
+            }
+        }));
+
         if exports.is_empty() {
             if let Some(internal_name) = inner_components.last().as_ref().map(|x| x.id.clone()) {
                 exports.push(NamedExport {
@@ -1230,7 +1663,9 @@ struct NamedExport {
 
         let mut resolve_export_to_inner_component_or_import =
             |export: &NamedExport| match type_registry.lookup(export.internal_name.as_str()) {
-                ty @ Type::Component(_) | ty @ Type::Object { .. } => Some(ty),
+                ty @ Type::Component(_) | ty @ Type::Object { .. } | ty @ Type::Enumeration(_) => {
+                    Some(ty)
+                }
                 Type::Invalid => {
                     diag.push_error(
                         format!("'{}' not found", export.internal_name),
@@ -272,27 +272,43 @@ fn source_file(&self) -> Option<&SourceFile> {
         Dot -> ".",
         Question -> "?",
         Dollar -> "$",
+        Pipe -> "|",
+        Percent -> "%",
+        At -> "@",
     }
     // syntax kind
     {
-        Document -> [ *Component, *ExportsList, *ImportSpecifier, *StructDeclaration ],
+        Document -> [ *Component, *ExportsList, *ImportSpecifier, *StructDeclaration, *EnumDeclaration ],
         Component -> [ DeclaredIdentifier, Element ],
         /// Note: This is in fact the same as Component as far as the parser is concerned
         SubElement -> [ Element ],
         Element -> [ ?QualifiedName, *PropertyDeclaration, *Binding, *CallbackConnection,
-                     *CallbackDeclaration, *SubElement, *RepeatedElement, *PropertyAnimation,
-                     *TwoWayBinding, *States, *Transitions, ?ChildrenPlaceholder ],
+                     *CallbackDeclaration, *Function, *SubElement, *RepeatedElement, *PropertyAnimation,
+                     *TwoWayBinding, *States, *Transitions, *PropertyChangedCallback, *AnimationSequence,
+                     *EnterExitTransition, ?ChildrenPlaceholder ],
         RepeatedElement -> [ ?DeclaredIdentifier, ?RepeatedIndex, Expression , Element],
         RepeatedIndex -> [],
         ConditionalElement -> [ Expression , Element],
         CallbackDeclaration -> [ DeclaredIdentifier, *Type, ?ReturnType ],
+        /// `function name(arg: type) -> type { ... }`
+        Function -> [ DeclaredIdentifier, *ArgumentDeclaration, ?ReturnType, CodeBlock ],
+        /// `arg: type` in a function declaration
+        ArgumentDeclaration -> [ DeclaredIdentifier, Type ],
         /// `-> type`  (but without the ->)
         ReturnType -> [Type],
         CallbackConnection -> [ *DeclaredIdentifier,  CodeBlock ],
-        /// Declaration of a propery.
+        /// `changed name => { ... }`  (the DeclaredIdentifier is the name of the property)
+        PropertyChangedCallback -> [ DeclaredIdentifier, CodeBlock ],
+        /// Declaration of a propery, with an optional `in`, `out`, `in-out` or `private` qualifier
         PropertyDeclaration-> [ Type , DeclaredIdentifier, ?BindingExpression, ?TwoWayBinding ],
         /// QualifiedName are the properties name
-        PropertyAnimation-> [ *QualifiedName, *Binding ],
+        PropertyAnimation-> [ *QualifiedName, *Binding, *KeyFrames ],
+        /// `keyframes { 50%: value; }` within an animation
+        KeyFrames -> [ *KeyFrame ],
+        /// `50%: value`: the first expression is the position, the second is the value
+        KeyFrame -> [ 2 Expression ],
+        /// `sequence { animate ... }`: each animation starts when the previous one is finished
+        AnimationSequence -> [ *PropertyAnimation ],
         /// wraps Identifiers, like `Rectangle` or `SomeModule.SomeType`
         QualifiedName-> [],
         /// Wraps single identifier (to disambiguate when there are other identifier in the production)
@@ -308,11 +324,24 @@ fn source_file(&self) -> Option<&SourceFile> {
         // FIXME: the test should test that as alternative rather than several of them (but it can also be a literal)
         Expression-> [ ?Expression, ?BangExpression, ?FunctionCallExpression, ?SelfAssignment,
                        ?ConditionalExpression, ?QualifiedName, ?BinaryExpression, ?Array, ?ObjectLiteral,
-                       ?UnaryOpExpression, ?CodeBlock],
+                       ?UnaryOpExpression, ?CodeBlock, ?AtTr, ?AtFormat, ?IndexExpression,
+                       ?MemberAccess],
         /// `foo!bar`
         BangExpression -> [Expression],
+        /// `@tr("context" => "message {}" | "plural {n}" % count, args...)`
+        AtTr -> [ ?TrContext, ?TrPlural, *Expression ],
+        /// `"context" =>` in a `@tr(...)`
+        TrContext -> [],
+        /// `| "plural" % count` in a `@tr(...)`
+        TrPlural -> [ Expression ],
+        /// `@format("{0} of {1:.2}", args...)`
+        AtFormat -> [ *Expression ],
         /// expression()
         FunctionCallExpression -> [*Expression],
+        /// `expression[index]`
+        IndexExpression -> [2 Expression],
+        /// `expression.foo`, when the expression is not a qualified name
+        MemberAccess -> [Expression],
         /// `expression += expression`
         SelfAssignment -> [2 Expression],
         /// `condition ? first : second`
@@ -336,9 +365,11 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// `transitions: [...]`
         Transitions -> [*Transition],
         /// There is an idientfier "in" or "out", the DeclaredIdentifier is the state name
-        Transition -> [DeclaredIdentifier, *PropertyAnimation],
+        Transition -> [DeclaredIdentifier, *PropertyAnimation, *AnimationSequence, *CallbackConnection],
+        /// There is an identifier "enter" or "exit"
+        EnterExitTransition -> [*StatePropertyChange, *PropertyAnimation],
         /// Export a set of declared components by name
-        ExportsList -> [ *ExportSpecifier, ?Component, *StructDeclaration ],
+        ExportsList -> [ *ExportSpecifier, ?Component, *StructDeclaration, *EnumDeclaration ],
         /// Declare the first identifier to be exported, either under its name or instead
         /// under the name of the second identifier.
         ExportSpecifier -> [ ExportIdentifier, ?ExportName ],
@@ -361,6 +392,10 @@ fn source_file(&self) -> Option<&SourceFile> {
         ArrayType -> [ Type ],
         /// `struct Foo := { ... }
         StructDeclaration -> [DeclaredIdentifier, ObjectType],
+        /// `enum Foo := { bar, baz }`
+        EnumDeclaration -> [DeclaredIdentifier, *EnumValue],
+        /// The identifier of a value within an EnumDeclaration
+        EnumValue -> [],
 
     }
 }
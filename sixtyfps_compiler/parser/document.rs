@@ -9,7 +9,7 @@
 LICENSE END */
 use super::expressions::parse_expression;
 use super::prelude::*;
-use super::r#type::{parse_struct_declaration, parse_type};
+use super::r#type::{parse_enum_declaration, parse_struct_declaration, parse_type};
 use super::statements::parse_statement;
 
 #[cfg_attr(test, parser_test)]
@@ -20,6 +20,8 @@
 /// Type := Base {} export { Type }
 /// import { Base } from "somewhere"; Type := Base {}
 /// struct Foo := { foo: foo }
+/// enum Foo := { bar, baz }
+/// export enum Foo := { bar }
 /// ```
 pub fn parse_document(p: &mut impl Parser) -> bool {
     let mut p = p.start_node(SyntaxKind::Document);
@@ -41,6 +43,11 @@ pub fn parse_document(p: &mut impl Parser) -> bool {
                     return false;
                 }
             }
+            "enum" => {
+                if !parse_enum_declaration(&mut *p) {
+                    return false;
+                }
+            }
             _ => {
                 if !parse_component(&mut *p) {
                     return false;
@@ -111,11 +118,16 @@ pub fn parse_element(p: &mut impl Parser) -> bool {
 /// if (condition) : Sub {}
 /// clicked => {}
 /// callback foobar;
+/// function foo(x: int) -> int { x + 1 }
 /// property<int> width;
+/// in property<int> value;
 /// animate someProp { }
 /// animate * { }
+/// sequence { animate x { } animate y { } }
+/// enter { opacity: 0; animate opacity { } }
 /// $children
 /// double_binding <=> element.property;
+/// changed width => {}
 /// ```
 fn parse_element_content(p: &mut impl Parser) {
     let mut had_parse_error = false;
@@ -125,6 +137,12 @@ fn parse_element_content(p: &mut impl Parser) {
             SyntaxKind::Eof => return,
             SyntaxKind::Identifier => match p.nth(1).kind() {
                 SyntaxKind::Colon => parse_property_binding(&mut *p),
+                SyntaxKind::LBrace if p.peek().as_str() == "sequence" => {
+                    parse_animation_sequence(&mut *p);
+                }
+                SyntaxKind::LBrace if matches!(p.peek().as_str(), "enter" | "exit") => {
+                    parse_enter_exit_transition(&mut *p);
+                }
                 SyntaxKind::ColonEqual | SyntaxKind::LBrace => parse_sub_element(&mut *p),
                 SyntaxKind::FatArrow | SyntaxKind::LParent if p.peek().as_str() != "if" => {
                     parse_callback_connection(&mut *p)
@@ -136,12 +154,30 @@ fn parse_element_content(p: &mut impl Parser) {
                 SyntaxKind::Identifier if p.peek().as_str() == "callback" => {
                     parse_callback_declaration(&mut *p);
                 }
+                SyntaxKind::Identifier if p.peek().as_str() == "function" => {
+                    parse_function(&mut *p);
+                }
+                SyntaxKind::Identifier
+                    if p.peek().as_str() == "changed"
+                        && p.nth(2).kind() == SyntaxKind::FatArrow =>
+                {
+                    parse_property_changed_callback(&mut *p);
+                }
                 SyntaxKind::Identifier | SyntaxKind::Star if p.peek().as_str() == "animate" => {
                     parse_property_animation(&mut *p);
                 }
                 SyntaxKind::LAngle if p.peek().as_str() == "property" => {
                     parse_property_declaration(&mut *p);
                 }
+                SyntaxKind::Identifier
+                    if p.nth(1).as_str() == "property"
+                        && matches!(
+                            p.peek().as_str(),
+                            "in" | "out" | "in-out" | "in_out" | "private"
+                        ) =>
+                {
+                    parse_property_declaration(&mut *p);
+                }
                 SyntaxKind::LParent if p.peek().as_str() == "if" => {
                     parse_if_element(&mut *p);
                 }
@@ -350,6 +386,22 @@ fn parse_callback_connection(p: &mut impl Parser) {
     parse_code_block(&mut *p);
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,PropertyChangedCallback
+/// changed foo => {}
+/// changed text => { count += 1; root.notify(text); }
+/// ```
+fn parse_property_changed_callback(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::PropertyChangedCallback);
+    p.consume(); // "changed"
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    p.expect(SyntaxKind::FatArrow);
+    parse_code_block(&mut *p);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,TwoWayBinding
 /// foo <=> bar;
@@ -403,15 +455,63 @@ fn parse_callback_declaration(p: &mut impl Parser) {
     p.expect(SyntaxKind::Semicolon);
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,Function
+/// function foo() -> int { 42 }
+/// function clamp(value: float, min: float, max: float) -> float { value < min ? min : value > max ? max : value }
+/// function end_coma(a: int, b: string,) -> string { b }
+/// function without_return() { debug("foo"); }
+/// ```
+/// Must consume at least one token
+fn parse_function(p: &mut impl Parser) {
+    debug_assert_eq!(p.peek().as_str(), "function");
+    let mut p = p.start_node(SyntaxKind::Function);
+    p.consume(); // "function"
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    if !p.expect(SyntaxKind::LParent) {
+        return;
+    }
+    while p.peek().kind() != SyntaxKind::RParent {
+        {
+            let mut p = p.start_node(SyntaxKind::ArgumentDeclaration);
+            {
+                let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+                p.expect(SyntaxKind::Identifier);
+            }
+            p.expect(SyntaxKind::Colon);
+            parse_type(&mut *p);
+        }
+        if !p.test(SyntaxKind::Comma) {
+            break;
+        }
+    }
+    p.expect(SyntaxKind::RParent);
+    if p.test(SyntaxKind::Arrow) {
+        let mut p = p.start_node(SyntaxKind::ReturnType);
+        parse_type(&mut *p);
+    }
+    parse_code_block(&mut *p);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,PropertyDeclaration
 /// property<int> foobar;
 /// property<string> text: "Something";
 /// property<string> text <=> two.way;
+/// in property<int> value;
+/// out property<bool> pressed: false;
+/// in-out property<string> text;
+/// private property<int> count;
 /// ```
 fn parse_property_declaration(p: &mut impl Parser) {
-    debug_assert_eq!(p.peek().as_str(), "property");
     let mut p = p.start_node(SyntaxKind::PropertyDeclaration);
+    if p.peek().as_str() != "property" {
+        p.consume(); // in, out, in-out or private
+    }
+    debug_assert_eq!(p.peek().as_str(), "property");
     p.consume(); // property
     p.expect(SyntaxKind::LAngle);
     parse_type(&mut *p);
@@ -442,6 +542,7 @@ fn parse_property_declaration(p: &mut impl Parser) {
 /// animate x { duration: 1000; }
 /// animate x, foo.y {  }
 /// animate * {  }
+/// animate x { duration: 1000; keyframes { 20%: 5; 50%: foo + 1; } }
 /// ```
 fn parse_property_animation(p: &mut impl Parser) {
     debug_assert_eq!(p.peek().as_str(), "animate");
@@ -467,6 +568,7 @@ fn parse_property_animation(p: &mut impl Parser) {
             SyntaxKind::Eof => return,
             SyntaxKind::Identifier => match p.nth(1).kind() {
                 SyntaxKind::Colon => parse_property_binding(&mut *p),
+                SyntaxKind::LBrace if p.peek().as_str() == "keyframes" => parse_keyframes(&mut *p),
                 _ => {
                     p.consume();
                     p.error("Only bindings are allowed in animations");
@@ -480,6 +582,65 @@ fn parse_property_animation(p: &mut impl Parser) {
     }
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,KeyFrames
+/// keyframes { }
+/// keyframes { 50%: 10; }
+/// keyframes { 25%: 10px; 75%: foo * 2; }
+/// ```
+fn parse_keyframes(p: &mut impl Parser) {
+    debug_assert_eq!(p.peek().as_str(), "keyframes");
+    let mut p = p.start_node(SyntaxKind::KeyFrames);
+    p.consume(); // keyframes
+    p.expect(SyntaxKind::LBrace);
+    loop {
+        match p.nth(0).kind() {
+            SyntaxKind::RBrace => {
+                p.consume();
+                return;
+            }
+            SyntaxKind::Eof => return,
+            _ => {
+                let mut p = p.start_node(SyntaxKind::KeyFrame);
+                parse_expression(&mut *p);
+                p.expect(SyntaxKind::Colon);
+                parse_expression(&mut *p);
+                if !p.expect(SyntaxKind::Semicolon) {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg_attr(test, parser_test)]
+/// ```test,AnimationSequence
+/// sequence { }
+/// sequence { animate x { duration: 100ms; } animate y, z { duration: 50ms; } }
+/// ```
+fn parse_animation_sequence(p: &mut impl Parser) {
+    debug_assert_eq!(p.peek().as_str(), "sequence");
+    let mut p = p.start_node(SyntaxKind::AnimationSequence);
+    p.consume(); // sequence
+    p.expect(SyntaxKind::LBrace);
+    loop {
+        match p.nth(0).kind() {
+            SyntaxKind::RBrace => {
+                p.consume();
+                return;
+            }
+            SyntaxKind::Eof => return,
+            SyntaxKind::Identifier if p.peek().as_str() == "animate" => {
+                parse_property_animation(&mut *p);
+            }
+            _ => {
+                p.consume();
+                p.error("Expected 'animate'");
+            }
+        }
+    }
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,States
 /// states []
@@ -553,6 +714,8 @@ fn parse_transitions(p: &mut impl Parser) {
 /// in pressed : {}
 /// in pressed: { animate x { duration: 88ms; } }
 /// out pressed: { animate x { duration: 88ms; } }
+/// in pressed: { sequence { animate x { duration: 88ms; } animate y { duration: 88ms; } } }
+/// out pressed: { animate x { duration: 88ms; } animation-finished => { foo(); } }
 /// ```
 fn parse_transition(p: &mut impl Parser) -> bool {
     if !matches!(p.peek().as_str(), "in" | "out") {
@@ -580,6 +743,16 @@ fn parse_transition(p: &mut impl Parser) -> bool {
             SyntaxKind::Identifier if p.peek().as_str() == "animate" => {
                 parse_property_animation(&mut *p);
             }
+            SyntaxKind::Identifier
+                if p.peek().as_str() == "sequence" && p.nth(1).kind() == SyntaxKind::LBrace =>
+            {
+                parse_animation_sequence(&mut *p);
+            }
+            SyntaxKind::Identifier
+                if matches!(p.nth(1).kind(), SyntaxKind::FatArrow | SyntaxKind::LParent) =>
+            {
+                parse_callback_connection(&mut *p);
+            }
             _ => {
                 p.consume();
                 p.error("Expected 'animate'");
@@ -588,6 +761,39 @@ fn parse_transition(p: &mut impl Parser) -> bool {
     }
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,EnterExitTransition
+/// enter { }
+/// enter { opacity: 0; animate opacity { duration: 88ms; } }
+/// exit { x: -100px; foo.y: 3px; animate x { duration: 88ms; } }
+/// ```
+fn parse_enter_exit_transition(p: &mut impl Parser) {
+    debug_assert!(matches!(p.peek().as_str(), "enter" | "exit"));
+    let mut p = p.start_node(SyntaxKind::EnterExitTransition);
+    p.consume(); // "enter" or "exit"
+    p.expect(SyntaxKind::LBrace);
+    loop {
+        match p.nth(0).kind() {
+            SyntaxKind::RBrace => {
+                p.consume();
+                return;
+            }
+            SyntaxKind::Eof => return,
+            SyntaxKind::Identifier
+                if p.peek().as_str() == "animate" && p.nth(1).kind() != SyntaxKind::Colon =>
+            {
+                parse_property_animation(&mut *p);
+            }
+            _ => {
+                let mut p = p.start_node(SyntaxKind::StatePropertyChange);
+                parse_qualified_name(&mut *p);
+                p.expect(SyntaxKind::Colon);
+                parse_binding_expression(&mut *p);
+            }
+        }
+    }
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,ExportsList
 /// export { Type }
@@ -623,6 +829,8 @@ fn parse_export(p: &mut impl Parser) -> bool {
         }
     } else if p.peek().as_str() == "struct" {
         parse_struct_declaration(&mut *p)
+    } else if p.peek().as_str() == "enum" {
+        parse_enum_declaration(&mut *p)
     } else {
         parse_component(&mut *p)
     }
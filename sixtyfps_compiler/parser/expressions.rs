@@ -32,6 +32,12 @@
 /// aa == cc && bb && (xxx || fff) && 3 + aaa == bbb
 /// [array]
 /// {object:42}
+/// @tr("hello")
+/// @format("{}", 42)
+/// array[3]
+/// model[index + 1].name
+/// foo.bar[0][1].baz
+/// (some_function()).member
 /// ```
 pub fn parse_expression(p: &mut impl Parser) {
     parse_expression_helper(p, OperatorPrecedence::Default)
@@ -90,18 +96,41 @@ fn parse_expression_helper(p: &mut impl Parser, precedence: OperatorPrecedence)
             p.consume();
             parse_expression_helper(&mut *p, OperatorPrecedence::Unary);
         }
+        SyntaxKind::At => parse_at_keyword(&mut *p),
         _ => {
             p.error("invalid expression");
             return;
         }
     }
 
-    if p.nth(0).kind() == SyntaxKind::LParent {
-        {
-            let _ = p.start_node_at(checkpoint.clone(), SyntaxKind::Expression);
+    loop {
+        match p.nth(0).kind() {
+            SyntaxKind::LParent => {
+                {
+                    let _ = p.start_node_at(checkpoint.clone(), SyntaxKind::Expression);
+                }
+                let mut p = p.start_node_at(checkpoint.clone(), SyntaxKind::FunctionCallExpression);
+                parse_function_arguments(&mut *p);
+            }
+            SyntaxKind::LBracket => {
+                {
+                    let _ = p.start_node_at(checkpoint.clone(), SyntaxKind::Expression);
+                }
+                let mut p = p.start_node_at(checkpoint.clone(), SyntaxKind::IndexExpression);
+                p.consume(); // "["
+                parse_expression(&mut *p);
+                p.expect(SyntaxKind::RBracket);
+            }
+            SyntaxKind::Dot => {
+                {
+                    let _ = p.start_node_at(checkpoint.clone(), SyntaxKind::Expression);
+                }
+                let mut p = p.start_node_at(checkpoint.clone(), SyntaxKind::MemberAccess);
+                p.consume(); // "."
+                p.expect(SyntaxKind::Identifier);
+            }
+            _ => break,
         }
-        let mut p = p.start_node_at(checkpoint.clone(), SyntaxKind::FunctionCallExpression);
-        parse_function_arguments(&mut *p);
     }
 
     if precedence >= OperatorPrecedence::Mul {
@@ -206,6 +235,80 @@ fn parse_bang_expression(p: &mut impl Parser) {
     parse_expression_helper(&mut *p, OperatorPrecedence::Bang);
 }
 
+fn parse_at_keyword(p: &mut impl Parser) {
+    debug_assert_eq!(p.peek().kind(), SyntaxKind::At);
+    match p.nth(1).as_str() {
+        "tr" => parse_tr(p),
+        "format" => parse_format(p),
+        _ => {
+            p.consume();
+            p.error("Expected 'tr' or 'format' after '@'");
+        }
+    }
+}
+
+#[cfg_attr(test, parser_test)]
+/// ```test,AtTr
+/// @tr("foo")
+/// @tr("foo{0}", bar(42))
+/// @tr("context" => "ccc{}", 0.3)
+/// @tr("foo" | "foos{n}" % aaa)
+/// @tr("context" => "{} foo" | "{} foos ({n})" % aaa, b + c)
+/// ```
+fn parse_tr(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::AtTr);
+    p.expect(SyntaxKind::At);
+    debug_assert_eq!(p.peek().as_str(), "tr");
+    p.expect(SyntaxKind::Identifier);
+    p.expect(SyntaxKind::LParent);
+
+    if p.nth(1).kind() == SyntaxKind::FatArrow {
+        let mut p = p.start_node(SyntaxKind::TrContext);
+        p.expect(SyntaxKind::StringLiteral);
+        p.expect(SyntaxKind::FatArrow);
+    }
+
+    p.expect(SyntaxKind::StringLiteral);
+
+    if p.peek().kind() == SyntaxKind::Pipe {
+        let mut p = p.start_node(SyntaxKind::TrPlural);
+        p.consume();
+        p.expect(SyntaxKind::StringLiteral);
+        p.expect(SyntaxKind::Percent);
+        parse_expression(&mut *p);
+    }
+
+    while p.test(SyntaxKind::Comma) {
+        if p.peek().kind() == SyntaxKind::RParent {
+            break;
+        }
+        parse_expression(&mut *p);
+    }
+    p.expect(SyntaxKind::RParent);
+}
+
+#[cfg_attr(test, parser_test)]
+/// ```test,AtFormat
+/// @format("foo")
+/// @format("{} {1:.2}", bar(42), 4 + 5)
+/// @format("{:05}", aaa,)
+/// ```
+fn parse_format(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::AtFormat);
+    p.expect(SyntaxKind::At);
+    debug_assert_eq!(p.peek().as_str(), "format");
+    p.expect(SyntaxKind::Identifier);
+    p.expect(SyntaxKind::LParent);
+    p.expect(SyntaxKind::StringLiteral);
+    while p.test(SyntaxKind::Comma) {
+        if p.peek().kind() == SyntaxKind::RParent {
+            break;
+        }
+        parse_expression(&mut *p);
+    }
+    p.expect(SyntaxKind::RParent);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,Array
 /// [ a, b, c , d]
@@ -0,0 +1,32 @@
+/* LICENSE BEGIN
+    This file is part of the SixtyFPS Project -- https://sixtyfps.io
+    Copyright (c) 2020 Olivier Goffart <olivier.goffart@sixtyfps.io>
+    Copyright (c) 2020 Simon Hausmann <simon.hausmann@sixtyfps.io>
+
+    SPDX-License-Identifier: GPL-3.0-only
+    This file is also available under commercial licensing terms.
+    Please contact info@sixtyfps.io for more information.
+LICENSE END */
+//! This pass checks that the `keys` of the `Shortcut` elements set with a string literal are a valid
+//! key sequence, as parsed by `sixtyfps_key_sequence::parse` in the run-time.
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::Expression;
+use crate::object_tree::*;
+use std::rc::Rc;
+
+pub fn check_shortcut_keys(component: &Rc<Component>, diag: &mut BuildDiagnostics) {
+    recurse_elem(&component.root_element, &(), &mut |elem, _| {
+        let elem = elem.borrow();
+        if elem.native_class().map_or(true, |n| n.class_name != "Shortcut") {
+            return;
+        }
+        if let Some(binding) = elem.bindings.get("keys") {
+            if let Expression::StringLiteral(keys) = &binding.expression {
+                if let Err(error) = sixtyfps_key_sequence::parse(keys) {
+                    diag.push_error(error.to_string(), binding);
+                }
+            }
+        }
+    })
+}
@@ -8,7 +8,7 @@
     Please contact info@sixtyfps.io for more information.
 LICENSE END */
 
-//! Passes that fills the root component used_structs
+//! Passes that fills the root component used_structs, with the structs and enums declared in .60 files
 
 use crate::expression_tree::Expression;
 use crate::object_tree::*;
@@ -33,10 +33,12 @@ pub fn collect_structs(root_component: &Rc<Component>, _diag: &mut BuildDiagnost
     });
 
     visit_all_expressions(root_component, |expr, _| {
-        expr.visit_recursive(&mut |expr| {
-            if let Expression::Object { ty, .. } = expr {
-                maybe_collect_object(ty)
+        expr.visit_recursive(&mut |expr| match expr {
+            Expression::Object { ty, .. } => maybe_collect_object(ty),
+            Expression::EnumerationValue(value) => {
+                maybe_collect_object(&Type::Enumeration(value.enumeration.clone()))
             }
+            _ => {}
         })
     });
 
@@ -76,6 +78,7 @@ fn visit_named_object(ty: &Type, visitor: &mut impl FnMut(&String, &Type)) {
             }
         }
         Type::Array(x) => visit_named_object(&x, visitor),
+        Type::Enumeration(en) if en.node.is_some() => visitor(&en.name, ty),
         _ => {}
     }
 }
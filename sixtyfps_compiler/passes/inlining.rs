@@ -68,6 +68,13 @@ fn inline_element(
     elem_mut
         .transitions
         .extend(inlined_component.root_element.borrow().transitions.iter().cloned());
+    elem_mut
+        .change_callbacks
+        .extend(inlined_component.root_element.borrow().change_callbacks.iter().cloned());
+    // The init code of the component runs before the one of the element using it
+    let element_init_code = std::mem::take(&mut elem_mut.init_code);
+    elem_mut.init_code = inlined_component.root_element.borrow().init_code.clone();
+    elem_mut.init_code.extend(element_init_code);
 
     // Map the old element to the new
     let mut mapping = HashMap::new();
@@ -171,6 +178,25 @@ fn duplicate_element_with_mapping(
             .iter()
             .map(|t| duplicate_transition(t, mapping, root_component))
             .collect(),
+        enter_exit_transitions: elem
+            .enter_exit_transitions
+            .iter()
+            .map(|t| EnterExitTransition {
+                is_exit: t.is_exit,
+                property_changes: t.property_changes.clone(),
+                property_animations: t
+                    .property_animations
+                    .iter()
+                    .map(|(r, anim)| {
+                        (r.clone(), duplicate_element_with_mapping(anim, mapping, root_component))
+                    })
+                    .collect(),
+                node: t.node.clone(),
+            })
+            .collect(),
+        change_callbacks: elem.change_callbacks.clone(),
+        init_code: elem.init_code.clone(),
+        animation_sequences: Default::default(), // lowered by the lower_animation_sequences pass
         child_of_layout: elem.child_of_layout,
         item_index: Default::default(), // Not determined yet
     }));
@@ -242,6 +268,7 @@ fn duplicate_transition(
                 (r.clone(), duplicate_element_with_mapping(anim, mapping, root_component))
             })
             .collect(),
+        animation_finished: t.animation_finished.clone(),
         node: t.node.clone(),
     }
 }
@@ -0,0 +1,58 @@
+/* LICENSE BEGIN
+    This file is part of the SixtyFPS Project -- https://sixtyfps.io
+    Copyright (c) 2020 Olivier Goffart <olivier.goffart@sixtyfps.io>
+    Copyright (c) 2020 Simon Hausmann <simon.hausmann@sixtyfps.io>
+
+    SPDX-License-Identifier: GPL-3.0-only
+    This file is also available under commercial licensing terms.
+    Please contact info@sixtyfps.io for more information.
+LICENSE END */
+//! Pass that lowers the `sequence { ... }` blocks of animations: each step of a sequence
+//! is delayed by the delay and duration of all the previous steps
+
+use crate::expression_tree::*;
+use crate::object_tree::*;
+
+pub fn lower_animation_sequences(doc: &Document) {
+    for component in doc.inner_components.iter() {
+        recurse_elem(&component.root_element, &(), &mut |elem, _| {
+            lower_sequences_in_element(&mut elem.borrow_mut())
+        });
+    }
+}
+
+/// Each step of a `sequence { ... }` is delayed by the delay and duration of all the previous steps
+fn lower_sequences_in_element(elem: &mut Element) {
+    for sequence in std::mem::take(&mut elem.animation_sequences) {
+        let mut offset: Option<Expression> = None;
+        for step in sequence {
+            let mut step_delay = None;
+            for anim in &step {
+                let mut anim = anim.borrow_mut();
+                let own_delay = anim.bindings.get("delay").map(|b| b.expression.clone());
+                let delay = match (offset.clone(), own_delay) {
+                    (Some(offset), Some(own_delay)) => Some(Expression::BinaryExpression {
+                        lhs: Box::new(offset),
+                        rhs: Box::new(own_delay),
+                        op: '+',
+                    }),
+                    (offset, own_delay) => offset.or(own_delay),
+                };
+                if let Some(delay) = &delay {
+                    anim.bindings.insert("delay".into(), delay.clone().into());
+                }
+                let duration = anim.bindings.get("duration").map(|b| b.expression.clone());
+                step_delay = Some((delay, duration));
+            }
+            offset = match step_delay {
+                Some((Some(delay), Some(duration))) => Some(Expression::BinaryExpression {
+                    lhs: Box::new(delay),
+                    rhs: Box::new(duration),
+                    op: '+',
+                }),
+                Some((delay, duration)) => delay.or(duration),
+                None => offset,
+            };
+        }
+    }
+}
@@ -33,6 +33,7 @@ fn lower_state_in_element(
     state_info_type: &Type,
     diag: &mut BuildDiagnostics,
 ) {
+    let enter_exit = lower_enter_exit_transitions(root_element);
     if root_element.borrow().states.is_empty() {
         return;
     }
@@ -91,9 +92,101 @@ fn lower_state_in_element(
     );
     root_element.borrow_mut().bindings.insert(state_property_name, state_value.into());
 
+    if let Some(entered) = enter_exit.entered {
+        // Evaluate the state and the properties while in the enter state before leaving it,
+        // so that the animations of the `enter` transition start from these values
+        let mut code = vec![state_property.clone()];
+        code.extend(enter_exit.enter_properties.into_iter().map(Expression::PropertyReference));
+        code.push(Expression::SelfAssignment {
+            lhs: Box::new(Expression::PropertyReference(entered)),
+            rhs: Box::new(Expression::BoolLiteral(true)),
+            op: '=',
+        });
+        root_element.borrow_mut().init_code.insert(0, Expression::CodeBlock(code));
+    }
+    if let Some((exiting, property_animations)) = enter_exit.exit {
+        let exit_finished = declare_hidden_property(root_element, "exit_finished", Type::Bool);
+        let finished = transition_finished(
+            &state_property,
+            states_id[EXIT_STATE],
+            false,
+            &property_animations,
+        );
+        root_element.borrow_mut().bindings.insert(exit_finished.name.clone(), finished.into());
+        if let Some(repeated) = &mut root_element.borrow_mut().repeated {
+            repeated.exit_transition = Some(ExitTransitionInfo { exiting, exit_finished });
+        }
+    }
+
     lower_transitions_in_element(root_element, state_property, states_id, diag);
 }
 
+/// Name of the implicit states of the `enter` and `exit` blocks. They cannot clash with the
+/// name of a state declared in the .60 file
+const ENTER_STATE: &str = "$enter";
+const EXIT_STATE: &str = "$exit";
+
+#[derive(Default)]
+struct EnterExitProperties {
+    /// Property set to true by the init code, which leaves the enter state
+    entered: Option<NamedReference>,
+    /// The properties changed by the enter state
+    enter_properties: Vec<NamedReference>,
+    /// Property set to true by the repeater when removing the element, and the exit animations
+    exit: Option<(NamedReference, Vec<(NamedReference, ElementRc)>)>,
+}
+
+/// Lower the `enter` and `exit` blocks of an element created by `if` or `for` into states and transitions.
+///
+/// The element is in the enter state until the init code ran, and in the exit state once the
+/// repeater removed it. The states are put first so they have priority over the other states.
+fn lower_enter_exit_transitions(elem: &ElementRc) -> EnterExitProperties {
+    let mut result = EnterExitProperties::default();
+    let enter_exit = std::mem::take(&mut elem.borrow_mut().enter_exit_transitions);
+    if elem.borrow().repeated.is_none() {
+        // Errors were reported when creating the element
+        return result;
+    }
+    for t in enter_exit {
+        let (state_id, condition) = if t.is_exit {
+            let exiting = declare_hidden_property(elem, "exiting", Type::Bool);
+            result.exit = Some((exiting.clone(), t.property_animations.clone()));
+            (EXIT_STATE, Expression::PropertyReference(exiting))
+        } else {
+            let entered = declare_hidden_property(elem, "entered", Type::Bool);
+            result.entered = Some(entered.clone());
+            result.enter_properties = t.property_changes.iter().map(|(p, _)| p.clone()).collect();
+            let not_entered = Expression::UnaryOp {
+                sub: Box::new(Expression::PropertyReference(entered)),
+                op: '!',
+            };
+            (ENTER_STATE, not_entered)
+        };
+        let state = State {
+            id: state_id.into(),
+            condition: Some(condition),
+            property_changes: t.property_changes,
+        };
+        let transition = Transition {
+            is_out: !t.is_exit,
+            state_id: state_id.into(),
+            property_animations: t.property_animations,
+            animation_finished: None,
+            node: t.node,
+        };
+        let mut elem = elem.borrow_mut();
+        // The exit state comes first, as it has priority over the enter state
+        let pos = if t.is_exit {
+            0
+        } else {
+            elem.states.iter().take_while(|s| s.id == EXIT_STATE).count()
+        };
+        elem.states.insert(pos, state);
+        elem.transitions.push(transition);
+    }
+    result
+}
+
 fn lower_transitions_in_element(
     elem: &ElementRc,
     state_property: Expression,
@@ -114,6 +207,18 @@ fn lower_transitions_in_element(
             &0
         });
 
+        if let Some(code) = transition.animation_finished {
+            check_animations_finish(&transition.property_animations, diag);
+            lower_animation_finished(
+                elem,
+                &state_property,
+                *state,
+                transition.is_out,
+                &transition.property_animations,
+                code,
+            );
+        }
+
         for (p, animation) in transition.property_animations {
             let t = TransitionPropertyAnimation {
                 state_id: *state,
@@ -146,6 +251,164 @@ fn lower_transitions_in_element(
     }
 }
 
+/// The end of the animations of a transition with an `animation-finished` callback is computed from
+/// their `delay`, `duration` and `loop_count`, so report the animations for which this does not work
+fn check_animations_finish(
+    property_animations: &[(NamedReference, ElementRc)],
+    diag: &mut BuildDiagnostics,
+) {
+    fn literal_value(e: &Expression) -> Option<f64> {
+        match e {
+            Expression::NumberLiteral(value, _) => Some(*value),
+            Expression::UnaryOp { sub, op: '-' } => literal_value(sub).map(|v| -v),
+            Expression::Cast { from, .. } => literal_value(from),
+            _ => None,
+        }
+    }
+    for (_, anim) in property_animations {
+        let anim = anim.borrow();
+        if let Some(loop_count) = anim.bindings.get("loop_count") {
+            if !literal_value(&loop_count.expression).map_or(false, |v| v >= 0.) {
+                diag.push_error(
+                    "In a transition with 'animation-finished', the loop_count must be a non-negative number literal".into(),
+                    loop_count,
+                );
+            }
+        }
+        if let Some(running) = anim.bindings.get("running") {
+            if !matches!(running.expression, Expression::BoolLiteral(true)) {
+                diag.push_error(
+                    "In a transition with 'animation-finished', the animations cannot be paused"
+                        .into(),
+                    running,
+                );
+            }
+        }
+        if let Some(stiffness) = anim.bindings.get("stiffness") {
+            diag.push_error(
+                "In a transition with 'animation-finished', the animations cannot be spring animations".into(),
+                stiffness,
+            );
+        }
+    }
+}
+
+/// Lower the `animation-finished` callback of a transition into a property that becomes true once
+/// the animations of the transition are over, and a change callback on that property that runs the code
+fn lower_animation_finished(
+    elem: &ElementRc,
+    state_property: &Expression,
+    state_id: i32,
+    is_out: bool,
+    property_animations: &[(NamedReference, ElementRc)],
+    code: Expression,
+) {
+    let finished = transition_finished(state_property, state_id, is_out, property_animations);
+    let nr = declare_hidden_property(elem, "animation_finished", Type::Bool);
+    let mut elem_mut = elem.borrow_mut();
+    elem_mut.bindings.insert(nr.name.clone(), finished.into());
+    elem_mut.change_callbacks.push((
+        nr.clone(),
+        Expression::Condition {
+            condition: Box::new(Expression::PropertyReference(nr)),
+            // The empty code block makes sure that both branches have the void type
+            true_expr: Box::new(Expression::CodeBlock(vec![code, Expression::CodeBlock(vec![])])),
+            false_expr: Box::new(Expression::CodeBlock(vec![])),
+        },
+    ));
+}
+
+/// Returns an expression that is true when the state property is in the given transition and its
+/// animations are over
+fn transition_finished(
+    state_property: &Expression,
+    state_id: i32,
+    is_out: bool,
+    property_animations: &[(NamedReference, ElementRc)],
+) -> Expression {
+    let in_transition = Expression::BinaryExpression {
+        lhs: Box::new(Expression::ObjectAccess {
+            base: Box::new(state_property.clone()),
+            name: (if is_out { "previous_state" } else { "current_state" }).into(),
+        }),
+        rhs: Box::new(Expression::NumberLiteral(state_id as _, Unit::None)),
+        op: '=',
+    };
+    let elapsed = Expression::FunctionCall {
+        function: Box::new(Expression::BuiltinFunctionReference(
+            BuiltinFunction::AnimationElapsedTime,
+        )),
+        arguments: vec![state_property.clone()],
+    };
+    Expression::BinaryExpression {
+        lhs: Box::new(in_transition),
+        rhs: Box::new(Expression::BinaryExpression {
+            lhs: Box::new(elapsed),
+            rhs: Box::new(transition_duration(property_animations)),
+            op: '≥',
+        }),
+        op: '&',
+    }
+}
+
+/// Returns an expression computing the time it takes for all the animations of a transition to finish
+fn transition_duration(property_animations: &[(NamedReference, ElementRc)]) -> Expression {
+    property_animations
+        .iter()
+        .map(|(_, anim)| {
+            let anim = anim.borrow();
+            let binding = |name: &str| anim.bindings.get(name).map(|b| b.expression.clone());
+            let mut time = binding("duration").unwrap_or(Expression::NumberLiteral(0., Unit::Ms));
+            if let Some(loop_count) = binding("loop_count") {
+                time = Expression::BinaryExpression {
+                    lhs: Box::new(time),
+                    rhs: Box::new(Expression::BinaryExpression {
+                        lhs: Box::new(loop_count),
+                        rhs: Box::new(Expression::NumberLiteral(1., Unit::None)),
+                        op: '+',
+                    }),
+                    op: '*',
+                };
+            }
+            if let Some(delay) = binding("delay") {
+                time = Expression::BinaryExpression {
+                    lhs: Box::new(delay),
+                    rhs: Box::new(time),
+                    op: '+',
+                };
+            }
+            time
+        })
+        .fold(None, |longest, time| {
+            Some(match longest {
+                None => time,
+                Some(longest) => Expression::Condition {
+                    condition: Box::new(Expression::BinaryExpression {
+                        lhs: Box::new(longest.clone()),
+                        rhs: Box::new(time.clone()),
+                        op: '>',
+                    }),
+                    true_expr: Box::new(longest),
+                    false_expr: Box::new(time),
+                },
+            })
+        })
+        .unwrap_or(Expression::NumberLiteral(0., Unit::Ms))
+}
+
+/// Declare a new property in the element, with a name that does not clash with existing properties
+fn declare_hidden_property(elem: &ElementRc, name: &str, property_type: Type) -> NamedReference {
+    let mut property_name = name.to_owned();
+    while elem.borrow().lookup_property(property_name.as_ref()) != Type::Invalid {
+        property_name += "_";
+    }
+    elem.borrow_mut().property_declarations.insert(
+        property_name.clone(),
+        PropertyDeclaration { property_type, ..PropertyDeclaration::default() },
+    );
+    NamedReference { element: Rc::downgrade(elem), name: property_name }
+}
+
 /// Returns a suitable unique name for the "state" property
 fn compute_state_property_name(root_element: &ElementRc) -> String {
     let mut property_name = "state".to_owned();
@@ -31,7 +31,11 @@ pub fn move_declarations(component: &Rc<Component>, diag: &mut BuildDiagnostics)
 
     let mut decl = Declarations::take_from_element(&mut *component.root_element.borrow_mut());
     decl.property_declarations.values_mut().for_each(|d| {
-        if d.property_type.ok_for_public_api() {
+        if matches!(d.property_type, Type::Function { .. }) {
+            // functions are only used internally
+        } else if d.visibility == PropertyVisibility::Private {
+            // private properties are not part of the public API
+        } else if d.property_type.ok_for_public_api() {
             d.expose_in_public_api = true
         } else {
             diag.push_diagnostic(
@@ -93,6 +97,20 @@ pub fn move_declarations(component: &Rc<Component>, diag: &mut BuildDiagnostics)
 
     component.optimized_elements.borrow().iter().for_each(|e| move_bindings_and_animations(e));
 
+    // The optimized elements are removed, so their change callbacks and init code go to the root
+    let new_root_change_callbacks = component
+        .optimized_elements
+        .borrow()
+        .iter()
+        .flat_map(|e| core::mem::take(&mut e.borrow_mut().change_callbacks))
+        .collect::<Vec<_>>();
+    let new_root_init_code = component
+        .optimized_elements
+        .borrow()
+        .iter()
+        .flat_map(|e| core::mem::take(&mut e.borrow_mut().init_code))
+        .collect::<Vec<_>>();
+
     component
         .layouts
         .borrow_mut()
@@ -120,6 +138,8 @@ pub fn move_declarations(component: &Rc<Component>, diag: &mut BuildDiagnostics)
         r.property_declarations = decl.property_declarations;
         r.bindings.extend(new_root_bindings.into_iter());
         r.property_animations.extend(new_root_property_animations.into_iter());
+        r.change_callbacks.extend(new_root_change_callbacks.into_iter());
+        r.init_code.extend(new_root_init_code.into_iter());
     }
 
     // By now, the optimized item should be unused
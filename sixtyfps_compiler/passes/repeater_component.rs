@@ -46,6 +46,10 @@ fn create_repeater_components(component: &Rc<Component>) {
                 enclosing_component: Default::default(),
                 states: std::mem::take(&mut elem.states),
                 transitions: std::mem::take(&mut elem.transitions),
+                enter_exit_transitions: Default::default(), // lowered by the lower_states pass
+                change_callbacks: std::mem::take(&mut elem.change_callbacks),
+                init_code: std::mem::take(&mut elem.init_code),
+                animation_sequences: Default::default(), // lowered by the lower_animation_sequences pass
                 child_of_layout: elem.child_of_layout,
                 item_index: Default::default(), // Not determined yet
             })),
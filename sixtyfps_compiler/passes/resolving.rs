@@ -17,6 +17,7 @@
 use crate::diagnostics::{BuildDiagnostics, SpannedWithSourceFile};
 use crate::expression_tree::*;
 use crate::langtype::Type;
+use crate::lexer::unescape_string;
 use crate::object_tree::*;
 use crate::parser::{
     identifier_text, syntax_nodes, NodeOrTokenWithSourceFile, SyntaxKind, SyntaxNodeWithSourceFile,
@@ -63,6 +64,16 @@ fn resolve_expression(
             SyntaxKind::TwoWayBinding => {
                 Expression::from_two_way_binding(node.clone().into(), &mut lookup_ctx)
             }
+            SyntaxKind::Function => {
+                Expression::from_function_node(node.clone().into(), &mut lookup_ctx)
+            }
+            SyntaxKind::KeyFrames => {
+                Expression::from_keyframes_node(node.clone().into(), &mut lookup_ctx)
+            }
+            SyntaxKind::PropertyChangedCallback => Expression::from_codeblock_node(
+                syntax_nodes::PropertyChangedCallback::from(node.clone()).CodeBlock(),
+                &mut lookup_ctx,
+            ),
             _ => {
                 debug_assert!(diag.has_error());
                 Expression::Invalid
@@ -109,7 +120,46 @@ pub fn resolve_expressions(doc: &Document, diag: &mut BuildDiagnostics) {
             });
             new_scope.0.pop();
             new_scope
-        })
+        });
+        recurse_elem(&component.root_element, &(), &mut |elem, _| {
+            check_spring_damping(&elem.borrow(), diag);
+        });
+    }
+}
+
+/// Reports an error for the animations of the element that have a negative `damping`
+fn check_spring_damping(elem: &Element, diag: &mut BuildDiagnostics) {
+    fn is_negative(e: &Expression) -> bool {
+        match e {
+            Expression::NumberLiteral(value, _) => *value < 0.,
+            Expression::UnaryOp { sub, op: '-' } => {
+                matches!(**sub, Expression::NumberLiteral(value, _) if value > 0.)
+            }
+            Expression::Cast { from, .. } => is_negative(from),
+            _ => false,
+        }
+    }
+    let static_animations = elem.property_animations.values().flat_map(|a| match a {
+        PropertyAnimation::Static(anim) => vec![anim],
+        PropertyAnimation::Transition { animations, .. } => {
+            animations.iter().map(|a| &a.animation).collect()
+        }
+    });
+    let transition_animations = elem
+        .transitions
+        .iter()
+        .flat_map(|t| t.property_animations.iter())
+        .chain(elem.enter_exit_transitions.iter().flat_map(|t| t.property_animations.iter()))
+        .map(|(_, anim)| anim);
+    for anim in static_animations.chain(transition_animations) {
+        if let Some(damping) = anim.borrow().bindings.get("damping") {
+            if is_negative(&damping.expression) {
+                diag.push_error(
+                    "The damping of a spring animation cannot be negative".into(),
+                    damping,
+                );
+            }
+        }
     }
 }
 
@@ -188,6 +238,19 @@ fn recurse(base: &ElementRc, e: &ElementRc) -> Option<ElementRc> {
     recurse(&root, e)
 }
 
+/// Returns true if `name` is a function declared with `function` in the element or in the
+/// component it is based on (as opposed to the member functions of builtin elements)
+fn is_declared_function(elem: &ElementRc, name: &str) -> bool {
+    let elem = elem.borrow();
+    match elem.property_declarations.get(name) {
+        Some(decl) => matches!(decl.property_type, Type::Function { .. }),
+        None => match &elem.base_type {
+            Type::Component(c) => is_declared_function(&c.root_element, name),
+            _ => false,
+        },
+    }
+}
+
 /// If the type of the expression is a percentage, and the current property evaluated is
 /// `width` or `height`, attempt to multiply by the parent `width` or `height`
 fn attempt_percent_conversion(
@@ -254,12 +317,79 @@ pub fn from_binding_expression_node(
         e.maybe_convert_to(ctx.property_type.clone(), &node, &mut ctx.diag)
     }
 
+    /// The keyframes are lowered to an array of `{ position, value }` objects, sorted by position
+    fn from_keyframes_node(node: syntax_nodes::KeyFrames, ctx: &mut LookupCtx) -> Expression {
+        let value_type = match &ctx.property_type {
+            Type::Array(ty) => match &**ty {
+                Type::Object { fields, .. } => fields.get("value").cloned().unwrap_or_default(),
+                _ => Type::Invalid,
+            },
+            _ => Type::Invalid,
+        };
+        let element_ty = Type::Object {
+            fields: [("position", Type::Float32), ("value", Type::Float32)]
+                .iter()
+                .map(|(name, ty)| (name.to_string(), ty.clone()))
+                .collect(),
+            name: None,
+        };
+        let mut keyframes = vec![];
+        for keyframe in node.KeyFrame() {
+            let (position_n, value_n) = keyframe.Expression();
+            let position = match Self::from_expression_node(position_n.clone(), ctx) {
+                Expression::NumberLiteral(val, Unit::Percent) if val > 0. && val < 100. => {
+                    val / 100.
+                }
+                Expression::Invalid => continue,
+                _ => {
+                    ctx.diag.push_error(
+                        "Keyframe position must be a percentage between 0% and 100%".into(),
+                        &position_n,
+                    );
+                    continue;
+                }
+            };
+            let value = Self::from_expression_node(value_n.clone(), ctx).maybe_convert_to(
+                value_type.clone(),
+                &value_n,
+                &mut ctx.diag,
+            );
+            keyframes.push((position, value));
+        }
+        keyframes.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        Expression::Array {
+            values: keyframes
+                .into_iter()
+                .map(|(position, value)| Expression::Object {
+                    ty: element_ty.clone(),
+                    values: [
+                        ("position".to_owned(), Expression::NumberLiteral(position, Unit::None)),
+                        (
+                            "value".to_owned(),
+                            Expression::Cast { from: Box::new(value), to: Type::Float32 },
+                        ),
+                    ]
+                    .iter()
+                    .cloned()
+                    .collect(),
+                })
+                .collect(),
+            element_ty,
+        }
+    }
+
     fn from_codeblock_node(node: syntax_nodes::CodeBlock, ctx: &mut LookupCtx) -> Expression {
         debug_assert_eq!(node.kind(), SyntaxKind::CodeBlock);
         Expression::CodeBlock(
             node.children()
                 .filter(|n| n.kind() == SyntaxKind::Expression)
-                .map(|n| Self::from_expression_node(n.into(), ctx))
+                .map(|n| {
+                    let e = Self::from_expression_node(n.clone().into(), ctx);
+                    if matches!(e, Expression::FunctionReference(_)) {
+                        ctx.diag.push_error("Function must be called".into(), &n);
+                    }
+                    e
+                })
                 .collect(),
         )
     }
@@ -273,11 +403,70 @@ fn from_callback_connection(
         Self::from_codeblock_node(node.CodeBlock(), ctx)
     }
 
+    fn from_function_node(node: syntax_nodes::Function, ctx: &mut LookupCtx) -> Expression {
+        ctx.arguments = node
+            .ArgumentDeclaration()
+            .map(|x| identifier_text(&x.DeclaredIdentifier()).unwrap_or_default())
+            .collect();
+        let return_type = match &ctx.property_type {
+            Type::Function { return_type, .. } => (**return_type).clone(),
+            _ => Type::Invalid,
+        };
+        let body = node.CodeBlock();
+        Self::from_codeblock_node(body.clone(), ctx).maybe_convert_to(
+            return_type,
+            &body,
+            &mut ctx.diag,
+        )
+    }
+
+    fn from_index_expression_node(
+        node: syntax_nodes::IndexExpression,
+        ctx: &mut LookupCtx,
+    ) -> Expression {
+        let (array_n, index_n) = node.Expression();
+        let array = Self::from_expression_node(array_n.clone(), ctx);
+        let index = Self::from_expression_node(index_n.clone(), ctx).maybe_convert_to(
+            Type::Int32,
+            &index_n,
+            &mut ctx.diag,
+        );
+        match array.ty() {
+            Type::Array(_) => {
+                Expression::ArrayIndex { array: Box::new(array), index: Box::new(index) }
+            }
+            Type::Invalid => Expression::Invalid,
+            ty => {
+                ctx.diag.push_error(format!("{} is not an indexable type", ty), &array_n);
+                Expression::Invalid
+            }
+        }
+    }
+
+    fn from_member_access_node(
+        node: syntax_nodes::MemberAccess,
+        ctx: &mut LookupCtx,
+    ) -> Expression {
+        let base = Self::from_expression_node(node.Expression(), ctx);
+        if matches!(base.ty(), Type::Invalid) {
+            return Expression::Invalid;
+        }
+        maybe_lookup_object(base, node.child_token(SyntaxKind::Identifier).into_iter(), ctx)
+    }
+
     fn from_two_way_binding(node: syntax_nodes::TwoWayBinding, ctx: &mut LookupCtx) -> Expression {
         let e = Self::from_expression_node(node.Expression(), ctx);
         let ty = e.ty();
         match e {
             Expression::PropertyReference(n) => {
+                // Binding an output property only reads the other property
+                let is_output = ctx.property_name.map_or(false, |name| {
+                    ctx.component_scope.last().and_then(|e| e.borrow().property_visibility(name))
+                        == Some(PropertyVisibility::Output)
+                });
+                if !is_output {
+                    check_property_access(&n.element.upgrade().unwrap(), &n.name, true, &node, ctx);
+                }
                 if ty != ctx.property_type {
                     ctx.diag.push_error(
                         "The property does not have the same type as the bound property".into(),
@@ -346,7 +535,11 @@ fn from_expression_node(node: syntax_nodes::Expression, ctx: &mut LookupCtx) ->
             })
             .or_else(|| node.ObjectLiteral().map(|n| Self::from_object_literal_node(n, ctx)))
             .or_else(|| node.Array().map(|n| Self::from_array_node(n, ctx)))
+            .or_else(|| node.IndexExpression().map(|n| Self::from_index_expression_node(n, ctx)))
+            .or_else(|| node.MemberAccess().map(|n| Self::from_member_access_node(n, ctx)))
             .or_else(|| node.CodeBlock().map(|n| Self::from_codeblock_node(n, ctx)))
+            .or_else(|| node.AtTr().map(|n| Self::from_at_tr_node(n, ctx)))
+            .or_else(|| node.AtFormat().map(|n| Self::from_at_format_node(n, ctx)))
             .unwrap_or(Self::Invalid)
     }
 
@@ -403,6 +596,167 @@ fn from_bang_expression_node(node: SyntaxNodeWithSourceFile, ctx: &mut LookupCtx
         }
     }
 
+    fn from_at_tr_node(node: syntax_nodes::AtTr, ctx: &mut LookupCtx) -> Self {
+        let string_literal = |text: Option<String>, ctx: &mut LookupCtx| -> String {
+            text.and_then(|s| unescape_string(&s)).unwrap_or_else(|| {
+                ctx.diag.push_error("Cannot parse string literal".into(), &node);
+                String::new()
+            })
+        };
+        let message = string_literal(node.child_text(SyntaxKind::StringLiteral), ctx);
+        let context = node
+            .TrContext()
+            .map(|n| string_literal(n.child_text(SyntaxKind::StringLiteral), ctx))
+            .unwrap_or_default();
+        let (plural, count) = match node.TrPlural() {
+            Some(n) => (
+                string_literal(n.child_text(SyntaxKind::StringLiteral), ctx),
+                Self::from_expression_node(n.Expression(), ctx).maybe_convert_to(
+                    Type::Int32,
+                    &n.Expression(),
+                    &mut ctx.diag,
+                ),
+            ),
+            None => (String::new(), Expression::NumberLiteral(1., Unit::None)),
+        };
+
+        let values: Vec<_> = node
+            .Expression()
+            .map(|n| {
+                Self::from_expression_node(n.clone(), ctx).maybe_convert_to(
+                    Type::String,
+                    &n,
+                    &mut ctx.diag,
+                )
+            })
+            .collect();
+
+        let has_plural = node.TrPlural().is_some();
+        let forms = std::iter::once(&message).chain(if has_plural { Some(&plural) } else { None });
+        let mut needed = Some(0);
+        for format in forms {
+            match tr_placeholder_count(format, has_plural) {
+                Ok(n) => needed = needed.map(|needed| needed.max(n)),
+                Err(e) => {
+                    ctx.diag.push_error(e, &node);
+                    needed = None;
+                }
+            }
+        }
+        if let Some(needed) = needed.filter(|n| *n != values.len()) {
+            ctx.diag.push_error(
+                format!(
+                    "Format string contains {} placeholders, but {} values were given",
+                    needed,
+                    values.len()
+                ),
+                &node,
+            );
+        }
+
+        Expression::FunctionCall {
+            function: Box::new(Expression::BuiltinFunctionReference(BuiltinFunction::Translate)),
+            arguments: vec![
+                Expression::StringLiteral(message),
+                Expression::StringLiteral(context),
+                Expression::StringLiteral(plural),
+                count,
+            ]
+            .into_iter()
+            .chain(values)
+            .collect(),
+        }
+    }
+
+    fn from_at_format_node(node: syntax_nodes::AtFormat, ctx: &mut LookupCtx) -> Self {
+        let format =
+            match node.child_text(SyntaxKind::StringLiteral).and_then(|s| unescape_string(&s)) {
+                Some(format) => format,
+                None => {
+                    ctx.diag.push_error("Cannot parse string literal".into(), &node);
+                    return Self::Invalid;
+                }
+            };
+        let pieces = match parse_format_string(&format) {
+            Ok(pieces) => pieces,
+            Err(e) => {
+                ctx.diag.push_error(e, &node);
+                return Self::Invalid;
+            }
+        };
+        let values: Vec<_> =
+            node.Expression().map(|n| (Self::from_expression_node(n.clone(), ctx), n)).collect();
+
+        let mut used = vec![false; values.len()];
+        let mut result = None;
+        for piece in pieces {
+            let expr = match piece {
+                FormatPiece::Literal(s) => Expression::StringLiteral(s),
+                FormatPiece::Placeholder { index, spec } => {
+                    let (value, value_node) = match values.get(index) {
+                        Some(value) => value,
+                        None => {
+                            ctx.diag.push_error(
+                                format!(
+                                    "The format string refers to the value {}, but only {} values were given",
+                                    index,
+                                    values.len()
+                                ),
+                                &node,
+                            );
+                            return Self::Invalid;
+                        }
+                    };
+                    used[index] = true;
+                    match spec {
+                        None => {
+                            value.clone().maybe_convert_to(Type::String, value_node, &mut ctx.diag)
+                        }
+                        Some(FormatSpec { width, precision, zero_padding }) => {
+                            if !matches!(value.ty(), Type::Float32 | Type::Int32) {
+                                ctx.diag.push_error(
+                                    "Format specifications can only be used with numbers".into(),
+                                    value_node,
+                                );
+                                return Self::Invalid;
+                            }
+                            Expression::FunctionCall {
+                                function: Box::new(Expression::BuiltinFunctionReference(
+                                    BuiltinFunction::FormatNumber,
+                                )),
+                                arguments: vec![
+                                    value.clone().maybe_convert_to(
+                                        Type::Float32,
+                                        value_node,
+                                        &mut ctx.diag,
+                                    ),
+                                    Expression::NumberLiteral(width as _, Unit::None),
+                                    Expression::NumberLiteral(precision as _, Unit::None),
+                                    Expression::BoolLiteral(zero_padding),
+                                ],
+                            }
+                        }
+                    }
+                }
+            };
+            result = Some(match result {
+                None => expr,
+                Some(lhs) => Expression::BinaryExpression {
+                    lhs: Box::new(lhs),
+                    rhs: Box::new(expr),
+                    op: '+',
+                },
+            });
+        }
+        if let Some(unused) = used.iter().position(|used| !used) {
+            ctx.diag.push_error(
+                "This value is not used in the format string".into(),
+                &values[unused].1,
+            );
+        }
+        result.unwrap_or_else(|| Expression::StringLiteral(String::new()))
+    }
+
     /// Perform the lookup
     fn from_qualified_name_node(node: SyntaxNodeWithSourceFile, ctx: &mut LookupCtx) -> Self {
         debug_assert_eq!(node.kind(), SyntaxKind::QualifiedName);
@@ -462,12 +816,13 @@ fn from_qualified_name_node(node: SyntaxNodeWithSourceFile, ctx: &mut LookupCtx)
 
             let property = elem.borrow().lookup_property(&first_str);
             if property.is_property_type() {
+                check_property_access(elem, &first_str, false, &first, ctx);
                 let prop = Self::PropertyReference(NamedReference {
                     element: Rc::downgrade(&elem),
                     name: first_str,
                 });
                 return maybe_lookup_object(prop, it, ctx);
-            } else if matches!(property, Type::Callback{..}) {
+            } else if matches!(property, Type::Callback { .. }) {
                 if let Some(x) = it.next() {
                     ctx.diag.push_error("Cannot access fields of callback".into(), &x)
                 }
@@ -475,11 +830,41 @@ fn from_qualified_name_node(node: SyntaxNodeWithSourceFile, ctx: &mut LookupCtx)
                     element: Rc::downgrade(&elem),
                     name: first_str,
                 });
+            } else if is_declared_function(elem, &first_str) {
+                if let Some(x) = it.next() {
+                    ctx.diag.push_error("Cannot access fields of a function".into(), &x)
+                }
+                return Self::FunctionReference(NamedReference {
+                    element: Rc::downgrade(&elem),
+                    name: first_str,
+                });
             } else if property.is_object_type() {
                 todo!("Continue lookling up");
             }
         }
 
+        if let Type::Enumeration(enumeration) = ctx.type_register.lookup(&first_str) {
+            if let Some(value_token) = it.next() {
+                let value_str = crate::parser::normalize_identifier(value_token.text().as_str());
+                if let Some(x) = it.next() {
+                    ctx.diag.push_error("Cannot access fields of an enum value".into(), &x);
+                }
+                return match enumeration.clone().try_value_from_string(&value_str) {
+                    Some(value) => Expression::EnumerationValue(value),
+                    None => {
+                        ctx.diag.push_error(
+                            format!(
+                                "'{}' is not a value of the enum {}",
+                                value_str, enumeration.name
+                            ),
+                            &value_token,
+                        );
+                        Expression::Invalid
+                    }
+                };
+            }
+        }
+
         if it.next().is_some() {
             ctx.diag.push_error(format!("Cannot access id '{}'", first_str), &node);
             return Expression::Invalid;
@@ -506,12 +891,27 @@ fn from_qualified_name_node(node: SyntaxNodeWithSourceFile, ctx: &mut LookupCtx)
                     "ease_in" => Some(EasingCurve::CubicBezier(0.42, 0.0, 1.0, 1.0)),
                     "ease_in_out" => Some(EasingCurve::CubicBezier(0.42, 0.0, 0.58, 1.0)),
                     "ease_out" => Some(EasingCurve::CubicBezier(0.0, 0.0, 0.58, 1.0)),
+                    "ease_in_elastic" => Some(EasingCurve::EaseInElastic),
+                    "ease_out_elastic" => Some(EasingCurve::EaseOutElastic),
+                    "ease_in_out_elastic" => Some(EasingCurve::EaseInOutElastic),
+                    "ease_in_bounce" => Some(EasingCurve::EaseInBounce),
+                    "ease_out_bounce" => Some(EasingCurve::EaseOutBounce),
+                    "ease_in_out_bounce" => Some(EasingCurve::EaseInOutBounce),
+                    "ease_in_back" => Some(EasingCurve::EaseInBack),
+                    "ease_out_back" => Some(EasingCurve::EaseOutBack),
+                    "ease_in_out_back" => Some(EasingCurve::EaseInOutBack),
                     "cubic_bezier" => {
                         return Expression::BuiltinMacroReference(
                             BuiltinMacroFunction::CubicBezier,
                             first.into(),
                         )
                     }
+                    "steps" => {
+                        return Expression::BuiltinMacroReference(
+                            BuiltinMacroFunction::Steps,
+                            first.into(),
+                        )
+                    }
                     _ => None,
                 };
                 if let Some(curve) = value {
@@ -619,6 +1019,37 @@ fn from_function_call_node(
 
                     return expr;
                 }
+                BuiltinMacroFunction::Steps => {
+                    let expr = match (sub_expr.next(), sub_expr.next()) {
+                        (None, _) => {
+                            ctx.diag.push_error("Not enough arguments".into(), &f_node);
+                            Expression::Invalid
+                        }
+                        (Some(_), Some((_, n))) => {
+                            ctx.diag.push_error("Too many argument for steps".into(), &n);
+                            Expression::Invalid
+                        }
+                        (Some((Expression::NumberLiteral(val, Unit::None), n)), None) => {
+                            if val < 1. || val.fract() != 0. {
+                                ctx.diag.push_error(
+                                    "The number of steps must be a positive integer".into(),
+                                    &n,
+                                );
+                                Expression::Invalid
+                            } else {
+                                Expression::EasingCurve(EasingCurve::Steps(val as u32))
+                            }
+                        }
+                        (Some((_, n)), None) => {
+                            ctx.diag.push_error(
+                                "Argument to steps must be a number literal".into(),
+                                &n,
+                            );
+                            Expression::Invalid
+                        }
+                    };
+                    return expr;
+                }
             },
             Expression::MemberFunction { base, base_node, member } => {
                 arguments.push((*base, base_node));
@@ -654,6 +1085,14 @@ fn from_function_call_node(
             }
         };
 
+        if matches!(ctx.property_type, Type::Function { .. }) && has_side_effects(&function) {
+            ctx.diag.push_error(
+                "Functions must be pure and cannot call callbacks or functions with side effects"
+                    .into(),
+                &node,
+            );
+        }
+
         Expression::FunctionCall { function, arguments }
     }
 
@@ -670,6 +1109,10 @@ fn from_self_assignement_node(
             .or(node.child_token(SyntaxKind::DivEqual).and(Some('/')))
             .or(node.child_token(SyntaxKind::Equal).and(Some('=')))
             .unwrap_or('_');
+        if matches!(ctx.property_type, Type::Function { .. }) {
+            ctx.diag
+                .push_error("Functions must be pure and cannot assign properties".into(), &node);
+        }
         if !lhs.is_rw() && lhs.ty() != Type::Invalid {
             ctx.diag.push_error(
                 format!(
@@ -679,6 +1122,13 @@ fn from_self_assignement_node(
                 &node,
             );
         }
+        let mut assigned = &lhs;
+        while let Expression::ObjectAccess { base, .. } = assigned {
+            assigned = &**base;
+        }
+        if let Expression::PropertyReference(nr) = assigned {
+            check_property_access(&nr.element.upgrade().unwrap(), &nr.name, true, &node, ctx);
+        }
         let rhs = Self::from_expression_node(rhs_n.clone(), ctx).maybe_convert_to(
             lhs.ty(),
             &rhs_n,
@@ -958,6 +1408,37 @@ fn min_max_macro(
     base
 }
 
+/// Report an error if the property `name` of `elem` cannot be accessed from the component being
+/// resolved because of its visibility qualifier. `is_write` is true for assignments.
+fn check_property_access(
+    elem: &ElementRc,
+    name: &str,
+    is_write: bool,
+    node: &dyn SpannedWithSourceFile,
+    ctx: &mut LookupCtx,
+) {
+    let e = elem.borrow();
+    let visibility = match e.property_visibility(name) {
+        Some(visibility) => visibility,
+        None => return,
+    };
+    let is_local = e.property_declarations.contains_key(name)
+        && ctx.component_scope.first().map_or(false, |root| {
+            std::rc::Weak::ptr_eq(&root.borrow().enclosing_component, &e.enclosing_component)
+        });
+    let error = match (visibility, is_local, is_write) {
+        (PropertyVisibility::Private, false, false) => private_property_error(name),
+        (PropertyVisibility::Output, false, true) => {
+            format!("Cannot assign to output property '{}'", name)
+        }
+        (PropertyVisibility::Input, true, true) => {
+            format!("Cannot assign to input property '{}'", name)
+        }
+        _ => return,
+    };
+    ctx.diag.push_error(error, node);
+}
+
 fn continue_lookup_within_element(
     elem: &ElementRc,
     it: &mut impl Iterator<Item = crate::parser::SyntaxTokenWithSourceFile>,
@@ -976,12 +1457,13 @@ fn continue_lookup_within_element(
 
     let p = elem.borrow().lookup_property(&prop_name);
     if p.is_property_type() {
+        check_property_access(elem, &prop_name, false, &second, ctx);
         let prop = Expression::PropertyReference(NamedReference {
             element: Rc::downgrade(elem),
             name: prop_name,
         });
         maybe_lookup_object(prop, it, ctx)
-    } else if matches!(p, Type::Callback{..}) {
+    } else if matches!(p, Type::Callback { .. }) {
         if let Some(x) = it.next() {
             ctx.diag.push_error("Cannot access fields of callback".into(), &x)
         }
@@ -989,7 +1471,15 @@ fn continue_lookup_within_element(
             element: Rc::downgrade(elem),
             name: prop_name,
         })
-    } else if matches!(p, Type::Function{..}) {
+    } else if is_declared_function(elem, &prop_name) {
+        if let Some(x) = it.next() {
+            ctx.diag.push_error("Cannot access fields of a function".into(), &x)
+        }
+        Expression::FunctionReference(NamedReference {
+            element: Rc::downgrade(elem),
+            name: prop_name,
+        })
+    } else if matches!(p, Type::Function { .. }) {
         let member = elem.borrow().base_type.lookup_member_function(&prop_name);
         Expression::MemberFunction {
             base: Box::new(Expression::ElementReference(Rc::downgrade(elem))),
@@ -1082,6 +1572,21 @@ fn error_or_try_minus(
                         "to_float" => {
                             Expression::BuiltinFunctionReference(BuiltinFunction::StringToFloat)
                         }
+                        "length" => {
+                            Expression::BuiltinFunctionReference(BuiltinFunction::StringLength)
+                        }
+                        "to_upper" => {
+                            Expression::BuiltinFunctionReference(BuiltinFunction::StringToUpper)
+                        }
+                        "to_lower" => {
+                            Expression::BuiltinFunctionReference(BuiltinFunction::StringToLower)
+                        }
+                        "contains" => {
+                            Expression::BuiltinFunctionReference(BuiltinFunction::StringContains)
+                        }
+                        "substring" => {
+                            Expression::BuiltinFunctionReference(BuiltinFunction::StringSubstring)
+                        }
                         _ => {
                             ctx.diag.push_error("Cannot access fields of string".into(), &next);
                             return Expression::Invalid;
@@ -1089,6 +1594,34 @@ fn error_or_try_minus(
                     }),
                 };
             }
+            Type::Array(_) => {
+                if next_str == "length" {
+                    base = Expression::FunctionCall {
+                        function: Box::new(Expression::BuiltinFunctionReference(
+                            BuiltinFunction::ArrayLength,
+                        )),
+                        arguments: vec![std::mem::replace(&mut base, Expression::Invalid)],
+                    }
+                } else {
+                    ctx.diag.push_error("Cannot access fields of array".into(), &next);
+                    return Expression::Invalid;
+                }
+            }
+            Type::Float32 | Type::Int32 => {
+                return Expression::MemberFunction {
+                    base: Box::new(base),
+                    base_node: next.clone().into(), // Note that this is not the base_node, but the function's node
+                    member: Box::new(match next_str.as_str() {
+                        "to_fixed" => {
+                            Expression::BuiltinFunctionReference(BuiltinFunction::NumberToFixed)
+                        }
+                        _ => {
+                            ctx.diag.push_error("Cannot access fields of number".into(), &next);
+                            return Expression::Invalid;
+                        }
+                    }),
+                };
+            }
             _ => {
                 ctx.diag.push_error("Cannot access fields of property".into(), &next);
                 return Expression::Invalid;
@@ -1150,13 +1683,124 @@ fn test_parse_color_literal() {
     assert_eq!(parse_color_literal("#1234567890"), None);
 }
 
-fn unescape_string(string: &str) -> Option<String> {
-    if !string.starts_with('"') || !string.ends_with('"') {
-        return None;
+/// Returns true if calling this function may have side effects, which functions are not allowed
+/// to do. The member functions, such as `popup.show()`, are checked on the function they are
+/// lowered to.
+fn has_side_effects(function: &Expression) -> bool {
+    match function {
+        Expression::CallbackReference(_) => true,
+        Expression::BuiltinFunctionReference(function) => {
+            matches!(function, BuiltinFunction::SetFocusItem | BuiltinFunction::ShowPopupWindow)
+        }
+        Expression::MemberFunction { member, .. } => has_side_effects(member),
+        _ => false,
+    }
+}
+
+/// Check that the placeholders of a `@tr` format string are valid, and return the number of
+/// values they refer to.
+/// `{}` refers to the next value, `{0}`, `{1}`, ... refer to the value at that position,
+/// `{n}` refers to the count of the plural form, and `{{` and `}}` are escaped braces.
+fn tr_placeholder_count(format: &str, has_plural: bool) -> Result<usize, String> {
+    let mut next_value = 0;
+    let mut needed = 0;
+    for piece in sixtyfps_format_string::parse(format).map_err(|e| e.to_string())? {
+        match piece {
+            sixtyfps_format_string::Piece::Literal(_) => {}
+            sixtyfps_format_string::Piece::Placeholder("") => {
+                next_value += 1;
+                needed = needed.max(next_value);
+            }
+            sixtyfps_format_string::Piece::Placeholder("n") => {
+                if !has_plural {
+                    return Err("`{n}` can only be used with a plural form".into());
+                }
+            }
+            sixtyfps_format_string::Piece::Placeholder(placeholder) => {
+                let index = placeholder
+                    .parse::<usize>()
+                    .map_err(|_| format!("Invalid placeholder `{{{}}}`", placeholder))?;
+                needed = needed.max(index + 1);
+            }
+        }
+    }
+    Ok(needed)
+}
+
+/// The width, precision and padding of a placeholder in a `@format` string, such as `{0:05.2}`
+struct FormatSpec {
+    width: usize,
+    /// -1 for the default precision
+    precision: i32,
+    zero_padding: bool,
+}
+
+enum FormatPiece {
+    Literal(String),
+    /// `{}` or `{index}`, followed by an optional `:spec`
+    Placeholder {
+        index: usize,
+        spec: Option<FormatSpec>,
+    },
+}
+
+/// Split a `@format` string into literal strings and placeholders.
+/// `{}` refers to the next value, `{0}`, `{1}`, ... refer to the value at that position,
+/// and `{{` and `}}` are escaped braces. The placeholder can have a specification after
+/// a colon: `[0][width][.precision]`
+fn parse_format_string(format: &str) -> Result<Vec<FormatPiece>, String> {
+    fn parse_spec(spec: &str) -> Option<FormatSpec> {
+        let (width, precision) = match spec.find('.') {
+            Some(pos) => (&spec[..pos], Some(&spec[pos + 1..])),
+            None => (spec, None),
+        };
+        Some(FormatSpec {
+            zero_padding: width.len() > 1 && width.starts_with('0'),
+            width: if width.is_empty() { 0 } else { width.parse().ok()? },
+            precision: match precision {
+                Some(precision) => precision.parse::<u16>().ok()? as i32,
+                None => -1,
+            },
+        })
+    }
+
+    let mut pieces = vec![];
+    let mut literal = String::new();
+    let mut next_index = 0;
+    for piece in sixtyfps_format_string::parse(format).map_err(|e| e.to_string())? {
+        let placeholder = match piece {
+            sixtyfps_format_string::Piece::Literal(text) => {
+                literal += text;
+                continue;
+            }
+            sixtyfps_format_string::Piece::Placeholder(placeholder) => placeholder,
+        };
+        let (index, spec) = match placeholder.find(':') {
+            Some(pos) => (&placeholder[..pos], Some(&placeholder[pos + 1..])),
+            None => (placeholder, None),
+        };
+        let index = if index.is_empty() {
+            next_index += 1;
+            next_index - 1
+        } else {
+            index.parse().map_err(|_| format!("Invalid placeholder `{{{}}}`", placeholder))?
+        };
+        let spec = match spec {
+            Some(spec) => Some(
+                parse_spec(spec)
+                    .ok_or_else(|| format!("Invalid format specification `{}`", spec))?,
+            ),
+            None => None,
+        };
+        if !literal.is_empty() {
+            pieces.push(FormatPiece::Literal(std::mem::take(&mut literal)));
+        }
+        pieces.push(FormatPiece::Placeholder { index, spec });
+    }
+    if !literal.is_empty() {
+        pieces.push(FormatPiece::Literal(literal));
     }
-    let string = &string[1..(string.len() - 1)];
-    // TODO: remove slashes
-    Some(string.into())
+    Ok(pieces)
 }
 
 fn parse_number_literal(s: String) -> Result<Expression, String> {
@@ -287,6 +287,7 @@ pub async fn load_file(
             &dependency_registry,
         );
         crate::passes::resolving::resolve_expressions(&doc, build_diagnostics);
+        crate::passes::lower_animation_sequences::lower_animation_sequences(&doc);
 
         // Add diagnostics regardless whether they're empty or not. This is used by the syntax_tests to
         // also verify that imported files have no errors.
@@ -95,6 +95,7 @@ pub fn builtin() -> Rc<RefCell<Self>> {
                     name: name.to_owned(),
                     values: values.iter().cloned().map(String::from).collect(),
                     default_value: 0,
+                    node: None,
                 })),
                 name.to_owned(),
             );
@@ -107,6 +108,63 @@ pub fn builtin() -> Rc<RefCell<Self>> {
             &["stretch", "center", "start", "end", "space_between", "space_around"],
         );
         declare_enum("ImageFit", &["fill", "contain"]);
+        declare_enum("PointerEventButton", &["none", "left", "right", "middle"]);
+        declare_enum("PointerEventKind", &["cancel", "down", "up"]);
+        declare_enum("SortOrder", &["unsorted", "ascending", "descending"]);
+        declare_enum(
+            "MouseCursor",
+            &[
+                "inherit",
+                "arrow",
+                "none",
+                "help",
+                "pointer",
+                "progress",
+                "wait",
+                "crosshair",
+                "text",
+                "alias",
+                "copy",
+                "all_scroll",
+                "no_drop",
+                "not_allowed",
+                "grab",
+                "grabbing",
+                "col_resize",
+                "row_resize",
+                "n_resize",
+                "e_resize",
+                "s_resize",
+                "w_resize",
+                "ne_resize",
+                "nw_resize",
+                "se_resize",
+                "sw_resize",
+                "ew_resize",
+                "ns_resize",
+                "nesw_resize",
+                "nwse_resize",
+            ],
+        );
+        declare_enum(
+            "AccessibleRole",
+            &[
+                "none",
+                "window",
+                "button",
+                "checkbox",
+                "combobox",
+                "group",
+                "image",
+                "list",
+                "list_item",
+                "slider",
+                "spinbox",
+                "tab",
+                "text",
+                "text_input",
+            ],
+        );
 
         register.supported_property_animation_types.insert(Type::Float32.to_string());
         register.supported_property_animation_types.insert(Type::Int32.to_string());
@@ -123,19 +181,21 @@ pub fn builtin() -> Rc<RefCell<Self>> {
             .for_each(|ty| ty.collect_contextual_types(&mut context_restricted_types));
         register.context_restricted_types = context_restricted_types;
 
-        match &mut register.types.get_mut("TextInput").unwrap() {
-            Type::Builtin(ref mut b) => {
-                Rc::get_mut(b)
-                    .unwrap()
-                    .properties
-                    .insert("focus".into(), BuiltinFunction::SetFocusItem.ty());
-                Rc::get_mut(b).unwrap().member_functions.insert(
-                    "focus".into(),
-                    Expression::BuiltinFunctionReference(BuiltinFunction::SetFocusItem),
-                );
-            }
-            _ => unreachable!(),
-        };
+        for focusable in &["TextInput", "FocusScope"] {
+            match &mut register.types.get_mut(*focusable).unwrap() {
+                Type::Builtin(ref mut b) => {
+                    Rc::get_mut(b)
+                        .unwrap()
+                        .properties
+                        .insert("focus".into(), BuiltinFunction::SetFocusItem.ty());
+                    Rc::get_mut(b).unwrap().member_functions.insert(
+                        "focus".into(),
+                        Expression::BuiltinFunctionReference(BuiltinFunction::SetFocusItem),
+                    );
+                }
+                _ => unreachable!(),
+            };
+        }
         match &mut register.types.get_mut("PopupWindow").unwrap() {
             Type::Builtin(ref mut b) => {
                 Rc::get_mut(b)
@@ -0,0 +1,228 @@
+/* LICENSE BEGIN
+    This file is part of the SixtyFPS Project -- https://sixtyfps.io
+    Copyright (c) 2020 Olivier Goffart <olivier.goffart@sixtyfps.io>
+    Copyright (c) 2020 Simon Hausmann <simon.hausmann@sixtyfps.io>
+
+    SPDX-License-Identifier: GPL-3.0-only
+    This file is also available under commercial licensing terms.
+    Please contact info@sixtyfps.io for more information.
+LICENSE END */
+/*!
+    Support for assistive technologies.
+
+    The accessibility tree is built from the `accessible_role`, `accessible_label` and
+    `accessible_description` properties of the items. It is handed over to an
+    [`AccessibilityAdapter`] which bridges it to the platform API. On Linux, the adapter that
+    exposes the tree with AT-SPI is installed by default when the `atspi` feature is enabled.
+*/
+
+#![warn(missing_docs)]
+use crate::component::ComponentRc;
+use crate::eventloop::ComponentWindow;
+use crate::graphics::{Point, Rect};
+use crate::item_tree::{ItemVisitor, ItemVisitorVTable, TraversalOrder, VisitChildrenResult};
+use crate::items::{
+    AccessibleRole, BorderRectangle, ClippedImage, FocusScope, Image, ItemRef, Rectangle, Text,
+    TextInput, TouchArea, Window,
+};
+use crate::SharedString;
+use core::pin::Pin;
+use std::cell::RefCell;
+use std::rc::Rc;
+use vtable::{VRc, VRefMut};
+
+#[cfg(all(target_os = "linux", feature = "atspi"))]
+mod atspi;
+
+/// An element of the accessibility tree
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccessibleNode {
+    /// The role of the element
+    pub role: AccessibleRole,
+    /// The name under which the element is announced
+    pub label: SharedString,
+    /// An additional description of the element
+    pub description: SharedString,
+    /// The geometry of the element, in the coordinates of the window
+    pub geometry: Rect,
+    /// The accessible elements that are within this element
+    pub children: Vec<AccessibleNode>,
+}
+
+/// Implemented by the bridges between the accessibility tree and the platform
+/// accessibility API, and installed with [`set_accessibility_adapter`].
+pub trait AccessibilityAdapter {
+    /// Called with the accessibility tree of the window when it is shown, and again
+    /// after any of the accessible properties changed.
+    fn update_tree(&self, window: &ComponentWindow, tree: &AccessibleNode);
+
+    /// Called when the window is hidden. The window will not be updated anymore, unless
+    /// it is shown again.
+    fn remove_window(&self, _window: &ComponentWindow) {}
+}
+
+#[cfg(all(target_os = "linux", feature = "atspi"))]
+fn default_adapter() -> Option<Rc<dyn AccessibilityAdapter>> {
+    Some(Rc::new(atspi::AtSpiAdapter::default()))
+}
+
+#[cfg(not(all(target_os = "linux", feature = "atspi")))]
+fn default_adapter() -> Option<Rc<dyn AccessibilityAdapter>> {
+    None
+}
+
+thread_local! {
+    static ADAPTER: RefCell<Option<Rc<dyn AccessibilityAdapter>>> = RefCell::new(default_adapter());
+}
+
+/// Installs the adapter that receives the accessibility tree of the windows, replacing the
+/// default adapter of the platform, or removes it when passing None.
+/// The adapter must be installed before the windows are shown.
+pub fn set_accessibility_adapter(adapter: Option<Rc<dyn AccessibilityAdapter>>) {
+    ADAPTER.with(|a| *a.borrow_mut() = adapter);
+}
+
+pub(crate) fn accessibility_adapter() -> Option<Rc<dyn AccessibilityAdapter>> {
+    ADAPTER.with(|a| a.borrow().clone())
+}
+
+/// Returns the role, label and description of the item, if it is an item that has them.
+fn accessible_properties(
+    item: Pin<ItemRef>,
+) -> Option<(AccessibleRole, SharedString, SharedString)> {
+    macro_rules! accessible_items {
+        ($($ty:ty),*) => {$(
+            if let Some(item) = ItemRef::downcast_pin::<$ty>(item) {
+                return Some((
+                    <$ty>::FIELD_OFFSETS.accessible_role.apply_pin(item).get(),
+                    <$ty>::FIELD_OFFSETS.accessible_label.apply_pin(item).get(),
+                    <$ty>::FIELD_OFFSETS.accessible_description.apply_pin(item).get(),
+                ));
+            }
+        )*};
+    }
+    accessible_items!(
+        Rectangle,
+        BorderRectangle,
+        Image,
+        ClippedImage,
+        Text,
+        TextInput,
+        TouchArea,
+        FocusScope
+    );
+    None
+}
+
+/// Returns the accessible node of an item, without its children, or None if the item is
+/// not part of the accessibility tree.
+///
+/// A non-empty Text without role is exposed with the `text` role, unless it is within an element
+/// that has a role (for example the text of a button), in which case it is part of that element.
+fn accessible_node(
+    item: Pin<ItemRef>,
+    geometry: Rect,
+    within_accessible_element: bool,
+) -> Option<AccessibleNode> {
+    let (mut role, mut label, description) = accessible_properties(item)?;
+    if role == AccessibleRole::none {
+        if within_accessible_element {
+            return None;
+        }
+        let text = Text::FIELD_OFFSETS.text.apply_pin(ItemRef::downcast_pin::<Text>(item)?).get();
+        if text.is_empty() {
+            return None;
+        }
+        role = AccessibleRole::text;
+        if label.is_empty() {
+            label = text;
+        }
+    }
+    Some(AccessibleNode { role, label, description, geometry, children: vec![] })
+}
+
+/// Appends to `nodes` the accessible nodes for the children of the item at `index`, whose
+/// position in the window is `offset`.
+fn collect_nodes(
+    component: &ComponentRc,
+    index: isize,
+    offset: Point,
+    within_accessible_element: bool,
+    nodes: &mut Vec<AccessibleNode>,
+) {
+    let mut visitor =
+        |component: &ComponentRc, index: usize, item: Pin<ItemRef>| -> VisitChildrenResult {
+            let geometry = item.as_ref().geometry().translate(offset.to_vector());
+            match accessible_node(item, geometry, within_accessible_element) {
+                Some(mut node) => {
+                    let is_container = matches!(
+                        node.role,
+                        AccessibleRole::window | AccessibleRole::group | AccessibleRole::list
+                    );
+                    collect_nodes(
+                        component,
+                        index as isize,
+                        geometry.origin,
+                        within_accessible_element || !is_container,
+                        &mut node.children,
+                    );
+                    nodes.push(node);
+                }
+                None => collect_nodes(
+                    component,
+                    index as isize,
+                    geometry.origin,
+                    within_accessible_element,
+                    nodes,
+                ),
+            }
+            VisitChildrenResult::CONTINUE
+        };
+    vtable::new_vref!(let mut visitor : VRefMut<ItemVisitorVTable> for ItemVisitor = &mut visitor);
+    VRc::borrow_pin(component).as_ref().visit_children_item(
+        index,
+        TraversalOrder::BackToFront,
+        visitor,
+    );
+}
+
+/// Builds the accessibility tree of a component shown in a window.
+/// The root of the tree has the `window` role, and the window title as label.
+pub fn accessibility_tree(component: &ComponentRc) -> AccessibleNode {
+    let root_item = VRc::borrow_pin(component).as_ref().get_item_ref(0);
+    let label = ItemRef::downcast_pin::<Window>(root_item)
+        .map(|window| Window::FIELD_OFFSETS.title.apply_pin(window).get())
+        .unwrap_or_default();
+    let mut children = vec![];
+    collect_nodes(component, -1, Point::default(), false, &mut children);
+    AccessibleNode {
+        role: AccessibleRole::window,
+        label,
+        description: Default::default(),
+        geometry: root_item.as_ref().geometry(),
+        children,
+    }
+}
+
+/// Returns a textual representation of the accessibility tree of the component, with one
+/// element per line, indented by its depth. Each line contains the role, the quoted label if
+/// any, and the description in parentheses if any.
+pub fn dump_accessibility_tree(component: &ComponentRc) -> String {
+    fn dump(node: &AccessibleNode, depth: usize, result: &mut String) {
+        use std::fmt::Write;
+        write!(result, "{:indent$}{}", "", node.role, indent = depth * 2).unwrap();
+        if !node.label.is_empty() {
+            write!(result, " {:?}", node.label.as_str()).unwrap();
+        }
+        if !node.description.is_empty() {
+            write!(result, " ({})", node.description).unwrap();
+        }
+        result.push('\n');
+        for child in &node.children {
+            dump(child, depth + 1, result);
+        }
+    }
+    let mut result = String::new();
+    dump(&accessibility_tree(component), 0, &mut result);
+    result
+}
@@ -0,0 +1,715 @@
+/* LICENSE BEGIN
+    This file is part of the SixtyFPS Project -- https://sixtyfps.io
+    Copyright (c) 2020 Olivier Goffart <olivier.goffart@sixtyfps.io>
+    Copyright (c) 2020 Simon Hausmann <simon.hausmann@sixtyfps.io>
+
+    SPDX-License-Identifier: GPL-3.0-only
+    This file is also available under commercial licensing terms.
+    Please contact info@sixtyfps.io for more information.
+LICENSE END */
+/*!
+    The AT-SPI bridge, which exposes the accessibility tree of the windows to the assistive
+    technologies on Linux, such as the Orca screen reader.
+
+    The connection to the accessibility bus is made by a thread that is started when the first
+    tree is received. That thread answers the requests of the assistive technologies from a copy
+    of the trees, which the adapter updates from the main thread. The adapter also emits the
+    events describing the changes of the trees.
+*/
+
+use super::{AccessibilityAdapter, AccessibleNode};
+use crate::eventloop::ComponentWindow;
+use crate::items::AccessibleRole;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::sync::{Arc, Mutex};
+use zbus::{Connection, Message, MessageType};
+use zvariant::{OwnedObjectPath, Structure, Value};
+
+/// The object path of the application, as expected by the AT-SPI registry
+const ROOT_PATH: &str = "/org/a11y/atspi/accessible/root";
+/// The object path used by AT-SPI to refer to no object
+const NULL_PATH: &str = "/org/a11y/atspi/null";
+/// The object path of a window is this prefix followed by the number of the window. The path of
+/// the other elements is the path of their parent followed by their index in the parent.
+const WINDOW_PATH_PREFIX: &str = "/org/sixtyfps/accessible";
+
+const ACCESSIBLE_INTERFACE: &str = "org.a11y.atspi.Accessible";
+const APPLICATION_INTERFACE: &str = "org.a11y.atspi.Application";
+const COMPONENT_INTERFACE: &str = "org.a11y.atspi.Component";
+const PROPERTIES_INTERFACE: &str = "org.freedesktop.DBus.Properties";
+const EVENT_OBJECT_INTERFACE: &str = "org.a11y.atspi.Event.Object";
+
+/// The coordinate types of the Component interface
+const COORD_TYPE_SCREEN: u32 = 0;
+const COORD_TYPE_WINDOW: u32 = 1;
+
+/// The states reported for all elements: enabled, sensitive, showing and visible
+const STATES: [u32; 2] = [1 << 8 | 1 << 24 | 1 << 25 | 1 << 30, 0];
+
+/// Returns the AT-SPI role, and its name, for the role of an element
+fn atspi_role(role: AccessibleRole) -> (u32, &'static str) {
+    match role {
+        AccessibleRole::none => (67, "unknown"),
+        AccessibleRole::window => (23, "frame"),
+        AccessibleRole::button => (43, "push button"),
+        AccessibleRole::checkbox => (7, "check box"),
+        AccessibleRole::combobox => (11, "combo box"),
+        AccessibleRole::group => (39, "panel"),
+        AccessibleRole::image => (27, "image"),
+        AccessibleRole::list => (31, "list"),
+        AccessibleRole::list_item => (32, "list item"),
+        AccessibleRole::slider => (51, "slider"),
+        AccessibleRole::spinbox => (52, "spin button"),
+        AccessibleRole::tab => (37, "page tab"),
+        AccessibleRole::text => (29, "label"),
+        AccessibleRole::text_input => (61, "text"),
+    }
+}
+
+/// An element of the accessibility tree, made of types that can be sent to the D-Bus thread
+#[derive(Clone, Debug, PartialEq)]
+struct Node {
+    role: AccessibleRole,
+    name: String,
+    description: String,
+    /// x, y, width and height, in the coordinates of the window
+    extents: (i32, i32, i32, i32),
+    children: Vec<Node>,
+}
+
+impl From<&AccessibleNode> for Node {
+    fn from(node: &AccessibleNode) -> Self {
+        Self {
+            role: node.role,
+            name: node.label.to_string(),
+            description: node.description.to_string(),
+            extents: (
+                node.geometry.origin.x as i32,
+                node.geometry.origin.y as i32,
+                node.geometry.size.width as i32,
+                node.geometry.size.height as i32,
+            ),
+            children: node.children.iter().map(Node::from).collect(),
+        }
+    }
+}
+
+/// The accessibility tree of a window
+struct WindowTree {
+    /// Identifies the window, see [`window_key`]
+    key: usize,
+    /// The number in the object path of the window
+    number: u32,
+    /// The position of the window on the screen
+    position: (i32, i32),
+    tree: Node,
+}
+
+/// The state shared by the adapter and the D-Bus thread
+#[derive(Default)]
+struct State {
+    windows: Vec<WindowTree>,
+    next_window_number: u32,
+    /// The id given to the application by the registry
+    application_id: i32,
+}
+
+/// Identifies a window by the address of its GenericWindow
+fn window_key(window: &ComponentWindow) -> usize {
+    &*window.0 as *const dyn crate::eventloop::GenericWindow as *const u8 as usize
+}
+
+fn window_path(number: u32) -> String {
+    format!("{}/{}", WINDOW_PATH_PREFIX, number)
+}
+
+/// A reference to an object, as sent on D-Bus: the bus name and the object path
+type ObjectRef = (String, OwnedObjectPath);
+
+fn object_ref(bus_name: &str, path: &str) -> ObjectRef {
+    // The paths are built by this module, and are always valid
+    (bus_name.into(), OwnedObjectPath::try_from(path).unwrap())
+}
+
+fn object_ref_value(bus_name: &str, path: &str) -> Value<'static> {
+    let (bus_name, path) = object_ref(bus_name, path);
+    Structure::new().add_field(bus_name).add_field(path.into_inner()).into()
+}
+
+/// An object of the application, found from its object path
+enum Object<'a> {
+    Application,
+    Element {
+        /// The index of the window in `State::windows`
+        window_index: usize,
+        window: &'a WindowTree,
+        /// The indices of the element and its ancestors in their parent, from the window
+        indices: Vec<usize>,
+        node: &'a Node,
+    },
+}
+
+impl<'a> Object<'a> {
+    fn find(state: &'a State, path: &str) -> Option<Self> {
+        if path == ROOT_PATH {
+            return Some(Object::Application);
+        }
+        let mut parts = path.strip_prefix(WINDOW_PATH_PREFIX)?.strip_prefix('/')?.split('/');
+        let number = parts.next()?.parse::<u32>().ok()?;
+        let (window_index, window) =
+            state.windows.iter().enumerate().find(|(_, window)| window.number == number)?;
+        let mut node = &window.tree;
+        let mut indices = vec![];
+        for part in parts {
+            let index = part.parse::<usize>().ok()?;
+            node = node.children.get(index)?;
+            indices.push(index);
+        }
+        Some(Object::Element { window_index, window, indices, node })
+    }
+
+    fn path(&self) -> String {
+        match self {
+            Object::Application => ROOT_PATH.into(),
+            Object::Element { window, indices, .. } => {
+                let mut path = window_path(window.number);
+                for index in indices {
+                    path += &format!("/{}", index);
+                }
+                path
+            }
+        }
+    }
+
+    fn children(&self, state: &State) -> Vec<String> {
+        match self {
+            Object::Application => {
+                state.windows.iter().map(|window| window_path(window.number)).collect()
+            }
+            Object::Element { node, .. } => {
+                let path = self.path();
+                (0..node.children.len()).map(|index| format!("{}/{}", path, index)).collect()
+            }
+        }
+    }
+
+    fn parent_path(&self) -> String {
+        match self {
+            Object::Application => NULL_PATH.into(),
+            Object::Element { indices, .. } if indices.is_empty() => ROOT_PATH.into(),
+            Object::Element { .. } => {
+                let path = self.path();
+                path[..path.rfind('/').unwrap()].into()
+            }
+        }
+    }
+
+    fn index_in_parent(&self) -> i32 {
+        match self {
+            Object::Application => -1,
+            Object::Element { window_index, indices, .. } => {
+                *indices.last().unwrap_or(window_index) as i32
+            }
+        }
+    }
+
+    fn name(&self) -> String {
+        match self {
+            Object::Application => std::env::current_exe()
+                .ok()
+                .and_then(|exe| Some(exe.file_stem()?.to_string_lossy().into_owned()))
+                .unwrap_or_default(),
+            Object::Element { node, .. } => node.name.clone(),
+        }
+    }
+
+    fn role(&self) -> (u32, &'static str) {
+        match self {
+            Object::Application => (75, "application"),
+            Object::Element { node, .. } => atspi_role(node.role),
+        }
+    }
+
+    fn interfaces(&self) -> Vec<&'static str> {
+        match self {
+            Object::Application => vec![ACCESSIBLE_INTERFACE, APPLICATION_INTERFACE],
+            Object::Element { .. } => vec![ACCESSIBLE_INTERFACE, COMPONENT_INTERFACE],
+        }
+    }
+
+    /// The extents of the element in the given coordinate type (screen, window or parent)
+    fn extents(&self, coord_type: u32) -> (i32, i32, i32, i32) {
+        match self {
+            Object::Application => (0, 0, 0, 0),
+            Object::Element { window, indices, node, .. } => {
+                let (x, y, width, height) = node.extents;
+                let (origin_x, origin_y) = match coord_type {
+                    COORD_TYPE_SCREEN => window.position,
+                    COORD_TYPE_WINDOW => (0, 0),
+                    _ => {
+                        let mut parent = &window.tree;
+                        if let Some((_, ancestors)) = indices.split_last() {
+                            for index in ancestors {
+                                parent = &parent.children[*index];
+                            }
+                            (-parent.extents.0, -parent.extents.1)
+                        } else {
+                            (-x, -y)
+                        }
+                    }
+                };
+                (x + origin_x, y + origin_y, width, height)
+            }
+        }
+    }
+
+    /// The values of the properties of the given interface
+    fn properties(
+        &self,
+        state: &State,
+        bus_name: &str,
+        interface: &str,
+    ) -> Vec<(&'static str, Value<'static>)> {
+        match (self, interface) {
+            (_, ACCESSIBLE_INTERFACE) => vec![
+                ("Name", Value::from(self.name())),
+                (
+                    "Description",
+                    Value::from(match self {
+                        Object::Application => String::new(),
+                        Object::Element { node, .. } => node.description.clone(),
+                    }),
+                ),
+                ("Parent", object_ref_value(bus_name, &self.parent_path())),
+                ("ChildCount", Value::from(self.children(state).len() as i32)),
+                ("Locale", Value::from(String::new())),
+                ("AccessibleId", Value::from(String::new())),
+            ],
+            (Object::Application, APPLICATION_INTERFACE) => vec![
+                ("ToolkitName", Value::from(String::from("SixtyFPS"))),
+                ("Version", Value::from(String::from(env!("CARGO_PKG_VERSION")))),
+                ("AtspiVersion", Value::from(String::from("2.1"))),
+                ("Id", Value::from(state.application_id)),
+            ],
+            _ => vec![],
+        }
+    }
+
+    /// Returns the path of the innermost element at the point, in the given coordinate type
+    fn element_at(&self, x: i32, y: i32, coord_type: u32) -> Option<String> {
+        let (ex, ey, width, height) = self.extents(coord_type);
+        if x < ex || y < ey || x >= ex + width || y >= ey + height {
+            return None;
+        }
+        if let Object::Element { window_index, window, indices, node } = self {
+            // The children are in front of their parent, and the last child is the topmost
+            for index in (0..node.children.len()).rev() {
+                let mut indices = indices.clone();
+                indices.push(index);
+                let child = Object::Element {
+                    window_index: *window_index,
+                    window: *window,
+                    indices,
+                    node: &node.children[index],
+                };
+                if let Some(path) = child.element_at(x, y, coord_type) {
+                    return Some(path);
+                }
+            }
+        }
+        Some(self.path())
+    }
+}
+
+/// Connects to the accessibility bus, and registers the application to the AT-SPI registry
+fn connect() -> zbus::Result<Connection> {
+    let session = Connection::new_session()?;
+    let reply = session.call_method(
+        Some("org.a11y.Bus"),
+        "/org/a11y/bus",
+        Some("org.a11y.Bus"),
+        "GetAddress",
+        &(),
+    )?;
+    let address: String = reply.body()?;
+    let connection = Connection::new_for_address(&address, true)?;
+    let bus_name = connection.unique_name().unwrap_or_default().to_owned();
+    connection.call_method(
+        Some("org.a11y.atspi.Registry"),
+        ROOT_PATH,
+        Some("org.a11y.atspi.Socket"),
+        "Embed",
+        &(object_ref(&bus_name, ROOT_PATH),),
+    )?;
+    Ok(connection)
+}
+
+/// Answers a method call of an assistive technology
+fn handle_message(
+    connection: &Connection,
+    state: &Mutex<State>,
+    message: &Message,
+) -> zbus::Result<()> {
+    let header = message.header()?;
+    if header.message_type()? != MessageType::MethodCall {
+        return Ok(());
+    }
+    let (path, interface, member) = match (header.path()?, header.interface()?, header.member()?) {
+        (Some(path), Some(interface), Some(member)) => {
+            (path.as_str().to_owned(), interface.to_owned(), member.to_owned())
+        }
+        _ => return Ok(()),
+    };
+    let bus_name = connection.unique_name().unwrap_or_default();
+
+    let mut state = state.lock().unwrap();
+    if interface == PROPERTIES_INTERFACE && member == "Set" {
+        // The registry sets the id of the application, which is the only writable property
+        let (property_interface, property, value): (String, String, Value) = message.body()?;
+        if let (APPLICATION_INTERFACE, "Id", Value::I32(id)) =
+            (property_interface.as_str(), property.as_str(), value)
+        {
+            state.application_id = id;
+        }
+        connection.reply(message, &())?;
+        return Ok(());
+    }
+    let state = &*state;
+
+    let object = match Object::find(state, &path) {
+        Some(object) => object,
+        None => {
+            connection.reply_error(
+                message,
+                "org.freedesktop.DBus.Error.UnknownObject",
+                &format!("Unknown object {}", path),
+            )?;
+            return Ok(());
+        }
+    };
+    let object_refs = |paths: Vec<String>| -> Vec<ObjectRef> {
+        paths.iter().map(|path| object_ref(bus_name, path)).collect()
+    };
+
+    match (interface.as_str(), member.as_str()) {
+        (PROPERTIES_INTERFACE, "Get") => {
+            let (property_interface, property): (String, String) = message.body()?;
+            match object
+                .properties(state, bus_name, &property_interface)
+                .into_iter()
+                .find(|(name, _)| *name == property)
+            {
+                Some((_, value)) => connection.reply(message, &value)?,
+                None => connection.reply_error(
+                    message,
+                    "org.freedesktop.DBus.Error.UnknownProperty",
+                    &format!("Unknown property {}", property),
+                )?,
+            }
+        }
+        (PROPERTIES_INTERFACE, "GetAll") => {
+            let property_interface: String = message.body()?;
+            let properties = object
+                .properties(state, bus_name, &property_interface)
+                .into_iter()
+                .collect::<HashMap<_, _>>();
+            connection.reply(message, &properties)?
+        }
+        (ACCESSIBLE_INTERFACE, "GetChildren") => {
+            connection.reply(message, &object_refs(object.children(state)))?
+        }
+        (ACCESSIBLE_INTERFACE, "GetChildAtIndex") => {
+            let index: i32 = message.body()?;
+            let child = object.children(state).into_iter().nth(index as usize);
+            let child = child.unwrap_or_else(|| NULL_PATH.into());
+            connection.reply(message, &object_ref(bus_name, &child))?
+        }
+        (ACCESSIBLE_INTERFACE, "GetIndexInParent") => {
+            connection.reply(message, &object.index_in_parent())?
+        }
+        (ACCESSIBLE_INTERFACE, "GetRole") => connection.reply(message, &object.role().0)?,
+        (ACCESSIBLE_INTERFACE, "GetRoleName") | (ACCESSIBLE_INTERFACE, "GetLocalizedRoleName") => {
+            connection.reply(message, &object.role().1)?
+        }
+        (ACCESSIBLE_INTERFACE, "GetState") => connection.reply(message, &STATES.to_vec())?,
+        (ACCESSIBLE_INTERFACE, "GetAttributes") => {
+            let mut attributes = HashMap::new();
+            attributes.insert("toolkit", "SixtyFPS");
+            connection.reply(message, &attributes)?
+        }
+        (ACCESSIBLE_INTERFACE, "GetApplication") => {
+            connection.reply(message, &object_ref(bus_name, ROOT_PATH))?
+        }
+        (ACCESSIBLE_INTERFACE, "GetInterfaces") => {
+            connection.reply(message, &object.interfaces())?
+        }
+        (ACCESSIBLE_INTERFACE, "GetRelationSet") => {
+            connection.reply(message, &Vec::<(u32, Vec<ObjectRef>)>::new())?
+        }
+        (COMPONENT_INTERFACE, "GetExtents") => {
+            let coord_type: u32 = message.body()?;
+            connection.reply(message, &object.extents(coord_type))?
+        }
+        (COMPONENT_INTERFACE, "GetPosition") => {
+            let coord_type: u32 = message.body()?;
+            let (x, y, _, _) = object.extents(coord_type);
+            connection.reply(message, &(x, y))?
+        }
+        (COMPONENT_INTERFACE, "GetSize") => {
+            let (_, _, width, height) = object.extents(COORD_TYPE_WINDOW);
+            connection.reply(message, &(width, height))?
+        }
+        (COMPONENT_INTERFACE, "Contains") => {
+            let (x, y, coord_type): (i32, i32, u32) = message.body()?;
+            let (ex, ey, width, height) = object.extents(coord_type);
+            let contains = x >= ex && y >= ey && x < ex + width && y < ey + height;
+            connection.reply(message, &contains)?
+        }
+        (COMPONENT_INTERFACE, "GetAccessibleAtPoint") => {
+            let (x, y, coord_type): (i32, i32, u32) = message.body()?;
+            let path = object.element_at(x, y, coord_type).unwrap_or_else(|| NULL_PATH.into());
+            connection.reply(message, &object_ref(bus_name, &path))?
+        }
+        (COMPONENT_INTERFACE, "GetLayer") => {
+            // WINDOW for the windows, WIDGET for the other elements
+            let layer: u32 = if object.index_in_parent() < 0 || object.parent_path() == ROOT_PATH {
+                7
+            } else {
+                3
+            };
+            connection.reply(message, &layer)?
+        }
+        (COMPONENT_INTERFACE, "GetMDIZOrder") => connection.reply(message, &0i16)?,
+        (COMPONENT_INTERFACE, "GetAlpha") => connection.reply(message, &1.0f64)?,
+        (COMPONENT_INTERFACE, "GrabFocus") => connection.reply(message, &false)?,
+        _ => connection.reply_error(
+            message,
+            "org.freedesktop.DBus.Error.UnknownMethod",
+            &format!("Unknown method {}.{}", interface, member),
+        )?,
+    };
+    Ok(())
+}
+
+/// The data of an event
+#[derive(Debug, PartialEq)]
+enum EventData {
+    /// The path of the child that was added or removed
+    Child(String),
+    /// The new name or description
+    Text(String),
+    /// The new AT-SPI role
+    Role(u32),
+}
+
+/// An event of the `org.a11y.atspi.Event.Object` interface
+#[derive(Debug, PartialEq)]
+struct Event {
+    /// The object path of the element that changed
+    path: String,
+    /// `ChildrenChanged` or `PropertyChange`
+    member: &'static str,
+    /// `add` or `remove` for `ChildrenChanged`, the name of the property for `PropertyChange`
+    kind: &'static str,
+    /// The index of the child for `ChildrenChanged`
+    detail: i32,
+    data: EventData,
+}
+
+impl Event {
+    fn children_changed(path: &str, kind: &'static str, index: usize, child_path: String) -> Self {
+        Self {
+            path: path.into(),
+            member: "ChildrenChanged",
+            kind,
+            detail: index as i32,
+            data: EventData::Child(child_path),
+        }
+    }
+
+    fn property_change(path: &str, kind: &'static str, data: EventData) -> Self {
+        Self { path: path.into(), member: "PropertyChange", kind, detail: 0, data }
+    }
+}
+
+/// Appends to `events` the events describing the changes from the `old` tree to the `new` tree,
+/// whose root has the object `path`.
+fn tree_changes(path: &str, old: &Node, new: &Node, events: &mut Vec<Event>) {
+    if old.name != new.name {
+        events.push(Event::property_change(
+            path,
+            "accessible-name",
+            EventData::Text(new.name.clone()),
+        ));
+    }
+    if old.description != new.description {
+        events.push(Event::property_change(
+            path,
+            "accessible-description",
+            EventData::Text(new.description.clone()),
+        ));
+    }
+    if old.role != new.role {
+        events.push(Event::property_change(
+            path,
+            "accessible-role",
+            EventData::Role(atspi_role(new.role).0),
+        ));
+    }
+    let child_path = |index: usize| format!("{}/{}", path, index);
+    let common = old.children.len().min(new.children.len());
+    for index in 0..common {
+        tree_changes(&child_path(index), &old.children[index], &new.children[index], events);
+    }
+    for index in (common..old.children.len()).rev() {
+        events.push(Event::children_changed(path, "remove", index, child_path(index)));
+    }
+    for index in common..new.children.len() {
+        events.push(Event::children_changed(path, "add", index, child_path(index)));
+    }
+}
+
+/// The AT-SPI adapter, see the module documentation
+#[derive(Default)]
+pub(super) struct AtSpiAdapter {
+    state: Arc<Mutex<State>>,
+    /// The connection to the accessibility bus, once the thread is connected
+    connection: Arc<Mutex<Option<Connection>>>,
+    thread_started: std::cell::Cell<bool>,
+}
+
+impl AtSpiAdapter {
+    /// Connects to the accessibility bus from a thread, which then answers the requests.
+    /// When there is no accessibility bus, there is no assistive technology to talk to, and
+    /// the thread just exits.
+    fn start_thread(&self) {
+        let state = self.state.clone();
+        let shared_connection = self.connection.clone();
+        let _ = std::thread::Builder::new().name("sixtyfps-atspi".into()).spawn(move || {
+            let connection = match connect() {
+                Ok(connection) => connection,
+                Err(_) => return,
+            };
+            *shared_connection.lock().unwrap() = Some(connection.clone());
+            while let Ok(message) = connection.receive_message() {
+                let _ = handle_message(&connection, &state, &message);
+            }
+            *shared_connection.lock().unwrap() = None;
+        });
+    }
+
+    fn emit_events(&self, events: &[Event]) {
+        let connection = self.connection.lock().unwrap();
+        let connection = match &*connection {
+            Some(connection) => connection,
+            None => return,
+        };
+        let bus_name = connection.unique_name().unwrap_or_default();
+        for event in events {
+            let data = match &event.data {
+                EventData::Child(path) => object_ref_value(bus_name, path),
+                EventData::Text(text) => Value::from(text.clone()),
+                EventData::Role(role) => Value::from(*role),
+            };
+            let _ = connection.emit_signal(
+                None,
+                event.path.as_str(),
+                EVENT_OBJECT_INTERFACE,
+                event.member,
+                &(event.kind, event.detail, 0i32, data, HashMap::<&str, Value>::new()),
+            );
+        }
+    }
+}
+
+impl AccessibilityAdapter for AtSpiAdapter {
+    fn update_tree(&self, window: &ComponentWindow, tree: &AccessibleNode) {
+        if !self.thread_started.replace(true) {
+            self.start_thread();
+        }
+        let position = std::cell::Cell::new((0, 0));
+        window.0.with_platform_window(&|platform_window| {
+            if let Ok(pos) = platform_window.inner_position() {
+                position.set((pos.x, pos.y));
+            }
+        });
+        let key = window_key(window);
+        let tree = Node::from(tree);
+        let mut events = vec![];
+        {
+            let mut state = self.state.lock().unwrap();
+            let index = state.windows.len();
+            if let Some(window) = state.windows.iter_mut().find(|window| window.key == key) {
+                window.position = position.get();
+                tree_changes(&window_path(window.number), &window.tree, &tree, &mut events);
+                window.tree = tree;
+            } else {
+                let number = state.next_window_number;
+                state.next_window_number += 1;
+                events.push(Event::children_changed(ROOT_PATH, "add", index, window_path(number)));
+                state.windows.push(WindowTree { key, number, position: position.get(), tree });
+            }
+        }
+        self.emit_events(&events);
+    }
+
+    fn remove_window(&self, window: &ComponentWindow) {
+        let key = window_key(window);
+        let removed = {
+            let mut state = self.state.lock().unwrap();
+            state
+                .windows
+                .iter()
+                .position(|window| window.key == key)
+                .map(|index| (index, state.windows.remove(index).number))
+        };
+        if let Some((index, number)) = removed {
+            self.emit_events(&[Event::children_changed(
+                ROOT_PATH,
+                "remove",
+                index,
+                window_path(number),
+            )]);
+        }
+    }
+}
+
+#[test]
+fn test_tree_changes() {
+    let node = |role, name: &str, children| Node {
+        role,
+        name: name.into(),
+        description: String::new(),
+        extents: (0, 0, 10, 10),
+        children,
+    };
+    let old = node(
+        AccessibleRole::window,
+        "Window",
+        vec![
+            node(AccessibleRole::button, "OK", vec![]),
+            node(AccessibleRole::text, "A", vec![]),
+            node(AccessibleRole::text, "B", vec![]),
+        ],
+    );
+    let mut events = vec![];
+    tree_changes("/w", &old, &old, &mut events);
+    assert_eq!(events, vec![]);
+
+    let new = node(
+        AccessibleRole::window,
+        "Window",
+        vec![node(AccessibleRole::button, "Cancel", vec![node(AccessibleRole::image, "", vec![])])],
+    );
+    tree_changes("/w", &old, &new, &mut events);
+    assert_eq!(
+        events,
+        vec![
+            Event::property_change("/w/0", "accessible-name", EventData::Text("Cancel".into())),
+            Event::children_changed("/w/0", "add", 0, "/w/0/0".into()),
+            Event::children_changed("/w", "remove", 2, "/w/2".into()),
+            Event::children_changed("/w", "remove", 1, "/w/1".into()),
+        ]
+    );
+}
@@ -19,6 +19,26 @@ pub enum EasingCurve {
     Linear,
     /// A Cubic bezier curve, with its 4 parameter
     CubicBezier([f32; 4]),
+    /// Exponentially growing oscillation at the start of the animation
+    EaseInElastic,
+    /// Exponentially decaying oscillation at the end of the animation
+    EaseOutElastic,
+    /// Elastic oscillation at both the start and the end of the animation
+    EaseInOutElastic,
+    /// Bouncing at the start of the animation
+    EaseInBounce,
+    /// Bouncing at the end of the animation, like a ball falling on the floor
+    EaseOutBounce,
+    /// Bouncing at both the start and the end of the animation
+    EaseInOutBounce,
+    /// Slightly moves backward before starting the animation
+    EaseInBack,
+    /// Overshoots the target before settling on it
+    EaseOutBack,
+    /// Moves backward at the start and overshoots at the end
+    EaseInOutBack,
+    /// Jumps between the given number of discrete steps
+    Steps(u32),
     //Custom(Box<dyn Fn(f32) -> f32>),
 }
 
@@ -28,6 +48,16 @@ fn default() -> Self {
     }
 }
 
+/// An intermediate value of a keyframe animation
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct KeyFrame {
+    /// The position of this keyframe in the animation, between 0 and 1
+    pub position: f32,
+    /// The value of the property at this position
+    pub value: f32,
+}
+
 /// Represent an instant, in miliseconds since the AnimationDriver's initial_instant
 #[repr(transparent)]
 #[derive(Copy, Clone, Debug, Default, PartialEq, Ord, PartialOrd, Eq)]
@@ -82,6 +112,11 @@ pub struct AnimationDriver {
     global_instant: core::pin::Pin<Box<crate::Property<Instant>>>,
     /// Time at which the AnimationDriver was created. global_instant is relative to that
     initial_instant: instant::Instant,
+    /// Factor by which the time elapsed in animations is multiplied. Zero disables the animations.
+    speed: Cell<f32>,
+    /// The tick at which the speed last changed, and the animation time at that tick, in
+    /// milliseconds. The animation time accumulates the time elapsed at the previous speeds.
+    speed_change: Cell<(Instant, f64)>,
 }
 
 impl Default for AnimationDriver {
@@ -90,6 +125,11 @@ fn default() -> Self {
             active_animations: Cell::default(),
             global_instant: Box::pin(crate::Property::new(Instant::default())),
             initial_instant: instant::Instant::now(),
+            speed: Cell::new(match std::env::var("SIXTYFPS_SLOW_ANIMATIONS") {
+                Err(_) => 1.,
+                Ok(val) => 1. / val.parse::<f32>().ok().filter(|f| *f > 0.).unwrap_or(2.),
+            }),
+            speed_change: Cell::default(),
         }
     }
 }
@@ -119,6 +159,37 @@ pub fn set_has_active_animations(&self) {
     pub fn current_tick(&self) -> Instant {
         self.global_instant.as_ref().get()
     }
+
+    /// The real time corresponding to the current animation tick, without registering a dependency.
+    /// Once the time is mocked in tests, the timers use this time instead of the real time.
+    pub(crate) fn current_instant(&self) -> instant::Instant {
+        self.initial_instant
+            + core::time::Duration::from_millis(self.global_instant.as_ref().get_untracked().0)
+    }
+
+    /// The factor by which the time elapsed in animations is multiplied
+    pub fn speed(&self) -> f32 {
+        self.speed.get()
+    }
+
+    /// Change the speed of all the animations. See [`set_animation_speed`]
+    pub fn set_speed(&self, speed: f32) {
+        let tick = self.global_instant.as_ref().get_untracked();
+        self.speed_change.set((tick, self.animation_time_at(tick)));
+        self.speed.set(speed.max(0.));
+    }
+
+    /// The instant to be used for the progress of the animations: the time elapsed
+    /// since the start, taking the changes of the animation speed into account.
+    /// Using this function register the current binding as a dependency.
+    pub fn animation_tick(&self) -> Instant {
+        Instant(self.animation_time_at(self.current_tick()) as u64)
+    }
+
+    fn animation_time_at(&self, tick: Instant) -> f64 {
+        let (change_tick, change_time) = self.speed_change.get();
+        change_time + tick.0.saturating_sub(change_tick.0) as f64 * self.speed.get() as f64
+    }
 }
 
 thread_local!(pub(crate) static CURRENT_ANIMATION_DRIVER : AnimationDriver = AnimationDriver::default());
@@ -129,6 +200,36 @@ pub fn current_tick() -> Instant {
     CURRENT_ANIMATION_DRIVER.with(|driver| driver.current_tick())
 }
 
+/// Change the speed of all the animations: 1 is the normal speed, 0.5 runs the animations
+/// twice as slowly, and 0 disables them so that the properties jump to their final value.
+/// The running animations continue from their current value at the new speed.
+///
+/// This is useful for slow-motion debugging or to honor reduced-motion settings.
+/// The `SIXTYFPS_SLOW_ANIMATIONS` environment variable sets the initial speed to its inverse.
+pub fn set_animation_speed(speed: f32) {
+    CURRENT_ANIMATION_DRIVER.with(|driver| driver.set_speed(speed))
+}
+
+/// The instant to be used for the progress of the animations, see [`AnimationDriver::animation_tick`]
+pub fn animation_tick() -> Instant {
+    CURRENT_ANIMATION_DRIVER.with(|driver| driver.animation_tick())
+}
+
+/// The time elapsed in the animations since `start`, an instant returned by [`animation_tick`].
+/// Using this function register the current binding as a dependency.
+///
+/// Returns `None` if the animations are disabled, in which case they are considered finished.
+pub fn animation_elapsed_time(start: Instant) -> Option<core::time::Duration> {
+    CURRENT_ANIMATION_DRIVER.with(|driver| {
+        let elapsed = driver.animation_tick().0.saturating_sub(start.0);
+        if driver.speed() > 0. {
+            Some(core::time::Duration::from_millis(elapsed))
+        } else {
+            None
+        }
+    })
+}
+
 /// map a value betwen 0 and 1 to another value between 0 and 1 according to the curve
 pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
     match curve {
@@ -146,9 +247,151 @@ pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
             let curve = curve.assume_monotonic();
             curve.y(curve.solve_t_for_x(value, 0.0..1.0, 0.01))
         }
+        EasingCurve::EaseInElastic => ease_in_elastic(value),
+        EasingCurve::EaseOutElastic => 1. - ease_in_elastic(1. - value),
+        EasingCurve::EaseInOutElastic => {
+            if value < 0.5 {
+                ease_in_elastic(value * 2.) / 2.
+            } else {
+                1. - ease_in_elastic(2. - value * 2.) / 2.
+            }
+        }
+        EasingCurve::EaseInBounce => 1. - ease_out_bounce(1. - value),
+        EasingCurve::EaseOutBounce => ease_out_bounce(value),
+        EasingCurve::EaseInOutBounce => {
+            if value < 0.5 {
+                (1. - ease_out_bounce(1. - value * 2.)) / 2.
+            } else {
+                (1. + ease_out_bounce(value * 2. - 1.)) / 2.
+            }
+        }
+        EasingCurve::EaseInBack => ease_in_back(value),
+        EasingCurve::EaseOutBack => 1. - ease_in_back(1. - value),
+        EasingCurve::EaseInOutBack => {
+            if value < 0.5 {
+                ease_in_back(value * 2.) / 2.
+            } else {
+                1. - ease_in_back(2. - value * 2.) / 2.
+            }
+        }
+        EasingCurve::Steps(steps) => {
+            if *steps == 0 || value >= 1. {
+                return value;
+            }
+            let steps = *steps as f32;
+            (value * steps).floor() / steps
+        }
+    }
+}
+
+fn ease_in_elastic(value: f32) -> f32 {
+    if value <= 0. || value >= 1. {
+        return value;
+    }
+    const C4: f32 = 2. * std::f32::consts::PI / 3.;
+    -(2f32.powf(10. * value - 10.)) * ((value * 10. - 10.75) * C4).sin()
+}
+
+fn ease_out_bounce(value: f32) -> f32 {
+    const N1: f32 = 7.5625;
+    const D1: f32 = 2.75;
+    if value < 1. / D1 {
+        N1 * value * value
+    } else if value < 2. / D1 {
+        let value = value - 1.5 / D1;
+        N1 * value * value + 0.75
+    } else if value < 2.5 / D1 {
+        let value = value - 2.25 / D1;
+        N1 * value * value + 0.9375
+    } else {
+        let value = value - 2.625 / D1;
+        N1 * value * value + 0.984375
+    }
+}
+
+fn ease_in_back(value: f32) -> f32 {
+    const C1: f32 = 1.70158;
+    const C3: f32 = C1 + 1.;
+    C3 * value * value * value - C1 * value * value
+}
+
+/// Parameters of a physically based spring animation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Spring {
+    /// The stiffness of the spring
+    pub stiffness: f32,
+    /// The damping coefficient. 0 for the default: the critical damping, with which the spring
+    /// comes to rest the fastest without oscillating
+    pub damping: f32,
+    /// The mass attached to the spring
+    pub mass: f32,
+}
+
+impl Spring {
+    /// Returns the displacement from the rest position and the velocity of a spring that was
+    /// released `time` seconds ago with the given initial displacement and velocity.
+    pub fn state(&self, displacement: f32, velocity: f32, time: f32) -> (f32, f32) {
+        let mass = if self.mass > 0. { self.mass } else { 1. };
+        let omega = (self.stiffness / mass).sqrt();
+        let critical_damping = 2. * (self.stiffness * mass).sqrt();
+        let damping = if self.damping > 0. { self.damping } else { critical_damping };
+        let zeta = damping / critical_damping;
+        if zeta < 1. {
+            // Under-damped: oscillates around the rest position
+            let omega_d = omega * (1. - zeta * zeta).sqrt();
+            let a = displacement;
+            let b = (velocity + zeta * omega * displacement) / omega_d;
+            let decay = (-zeta * omega * time).exp();
+            let (sin, cos) = (omega_d * time).sin_cos();
+            let x = decay * (a * cos + b * sin);
+            let v = decay
+                * ((b * omega_d - a * zeta * omega) * cos - (a * omega_d + b * zeta * omega) * sin);
+            (x, v)
+        } else if zeta == 1. {
+            // Critically damped
+            let b = velocity + omega * displacement;
+            let decay = (-omega * time).exp();
+            let x = decay * (displacement + b * time);
+            let v = decay * (b - omega * (displacement + b * time));
+            (x, v)
+        } else {
+            // Over-damped
+            let root = omega * (zeta * zeta - 1.).sqrt();
+            let r1 = -zeta * omega + root;
+            let r2 = -zeta * omega - root;
+            let c1 = (velocity - r2 * displacement) / (r1 - r2);
+            let c2 = displacement - c1;
+            let (e1, e2) = ((r1 * time).exp(), (r2 * time).exp());
+            (c1 * e1 + c2 * e2, c1 * r1 * e1 + c2 * r2 * e2)
+        }
     }
 }
 
+#[test]
+fn easing_curves_end_points() {
+    let curves = [
+        EasingCurve::Linear,
+        EasingCurve::EaseInElastic,
+        EasingCurve::EaseOutElastic,
+        EasingCurve::EaseInOutElastic,
+        EasingCurve::EaseInBounce,
+        EasingCurve::EaseOutBounce,
+        EasingCurve::EaseInOutBounce,
+        EasingCurve::EaseInBack,
+        EasingCurve::EaseOutBack,
+        EasingCurve::EaseInOutBack,
+        EasingCurve::Steps(4),
+    ];
+    for curve in curves.iter() {
+        assert!(easing_curve(curve, 0.).abs() < 0.0001, "{:?}", curve);
+        assert!((easing_curve(curve, 1.) - 1.).abs() < 0.0001, "{:?}", curve);
+    }
+    assert_eq!(easing_curve(&EasingCurve::Steps(4), 0.3), 0.25);
+    assert_eq!(easing_curve(&EasingCurve::Steps(4), 0.99), 0.75);
+    assert!(easing_curve(&EasingCurve::EaseOutBack, 0.7) > 1.);
+    assert!(easing_curve(&EasingCurve::EaseInBack, 0.2) < 0.);
+}
+
 /*
 #[test]
 fn easing_test() {
@@ -184,13 +427,25 @@ fn test_curve(name: &str, curve: &EasingCurve) {
 pub(crate) fn update_animations() {
     CURRENT_ANIMATION_DRIVER.with(|driver| {
         let duration = instant::Instant::now() - driver.initial_instant;
-        let duration = match std::env::var("SIXTYFPS_SLOW_ANIMATIONS") {
-            Err(_) => duration,
-            Ok(val) => {
-                let factor = val.parse().unwrap_or(2);
-                duration / factor
-            }
-        };
         driver.update_animations(Instant(duration.as_millis() as u64))
     });
 }
+
+pub(crate) mod ffi {
+    #![allow(unsafe_code)]
+
+    use super::*;
+
+    /// Change the speed of all the animations. See [`set_animation_speed`]
+    #[no_mangle]
+    pub extern "C" fn sixtyfps_set_animation_speed(speed: f32) {
+        set_animation_speed(speed)
+    }
+
+    /// The time elapsed since the `start` instant, in milliseconds, taking the animation speed into account.
+    /// Returns i64::MAX if the animations are disabled.
+    #[no_mangle]
+    pub extern "C" fn sixtyfps_animation_elapsed_time(start: u64) -> i64 {
+        animation_elapsed_time(Instant(start)).map_or(i64::MAX, |d| d.as_millis() as i64)
+    }
+}
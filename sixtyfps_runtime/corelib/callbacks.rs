@@ -22,10 +22,12 @@
 ///
 /// The Arg represents the argument. It should always be a tuple
 ///
+/// The handler writes the return value in its second argument, so that handlers set
+/// from C++ can return a value through the FFI.
 #[repr(C)]
 pub struct Callback<Arg: ?Sized, Ret = ()> {
     /// FIXME: Box<dyn> is a fat object and we probaly want to put an erased type in there
-    handler: Cell<Option<Box<dyn Fn(&Arg) -> Ret>>>,
+    handler: Cell<Option<Box<dyn Fn(&Arg, &mut Ret)>>>,
 }
 
 impl<Arg: ?Sized, Ret> Default for Callback<Arg, Ret> {
@@ -34,24 +36,30 @@ fn default() -> Self {
     }
 }
 
-impl<Arg: ?Sized, Ret: Default> Callback<Arg, Ret> {
-    /// Emit the callback with the given argument.
-    pub fn emit(&self, a: &Arg) -> Ret {
+impl<Arg: ?Sized, Ret> Callback<Arg, Ret> {
+    /// Call the handler, if any, which stores its return value in `r`
+    fn call(&self, a: &Arg, r: &mut Ret) {
         if let Some(h) = self.handler.take() {
-            let r = h(a);
+            h(a, r);
             assert!(self.handler.take().is_none(), "Callback Handler set while emitted");
             self.handler.set(Some(h));
-            r
-        } else {
-            Default::default()
         }
     }
+}
+
+impl<Arg: ?Sized + 'static, Ret: Default + 'static> Callback<Arg, Ret> {
+    /// Emit the callback with the given argument.
+    pub fn emit(&self, a: &Arg) -> Ret {
+        let mut r = Ret::default();
+        self.call(a, &mut r);
+        r
+    }
 
     /// Set an handler to be called when the callback is emited
     ///
     /// There can only be one single handler per callback.
     pub fn set_handler(&self, f: impl Fn(&Arg) -> Ret + 'static) {
-        self.handler.set(Some(Box::new(f)));
+        self.handler.set(Some(Box::new(move |a: &Arg, r: &mut Ret| *r = f(a))));
     }
 }
 
@@ -94,23 +102,25 @@ pub(crate) mod ffi {
         core::ptr::write(out as *mut Callback<()>, Default::default());
     }
 
-    /// Emit the callback
+    /// Emit the callback. The handler writes the return value in `ret`, which must point
+    /// to a valid value of the return type.
     #[no_mangle]
     pub unsafe extern "C" fn sixtyfps_callback_emit(
         sig: *const CallbackOpaque,
         arg: *const c_void,
+        ret: *mut c_void,
     ) {
         let sig = &*(sig as *const Callback<c_void>);
-        sig.emit(&*arg);
+        sig.call(&*arg, &mut *ret);
     }
 
     /// Set callback handler.
     ///
-    /// The binding has signature fn(user_data)
+    /// The binding has signature fn(user_data, arg, ret) and must write the return value in ret
     #[no_mangle]
     pub unsafe extern "C" fn sixtyfps_callback_set_handler(
         sig: *const CallbackOpaque,
-        binding: extern "C" fn(user_data: *mut c_void, arg: *const c_void),
+        binding: extern "C" fn(user_data: *mut c_void, arg: *const c_void, ret: *mut c_void),
         user_data: *mut c_void,
         drop_user_data: Option<extern "C" fn(*mut c_void)>,
     ) {
@@ -130,10 +140,10 @@ fn drop(&mut self) {
         }
         let ud = UserData { user_data, drop_user_data };
 
-        let real_binding = move |arg: &()| {
-            binding(ud.user_data, arg as *const c_void);
+        let real_binding = move |arg: &(), ret: &mut ()| {
+            binding(ud.user_data, arg as *const c_void, ret as *mut c_void);
         };
-        sig.set_handler(real_binding);
+        sig.handler.set(Some(Box::new(real_binding)));
     }
 
     /// Destroy callback
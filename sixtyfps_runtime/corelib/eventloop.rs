@@ -21,7 +21,8 @@
 use std::rc::{Rc, Weak};
 use std::{convert::TryInto, pin::Pin};
 
-use crate::input::{KeyEvent, MouseEventType};
+use crate::input::{FileDropEvent, KeyEvent, MouseEventType, TouchPhase};
+use crate::items::PointerEventButton;
 #[cfg(not(target_arch = "wasm32"))]
 use winit::platform::run_return::EventLoopExtRunReturn;
 
@@ -43,11 +44,36 @@ pub trait GenericWindow {
     /// Arguments:
     /// * `pos`: The position of the mouse event in window physical coordinates.
     /// * `what`: The type of mouse event.
+    /// * `button`: The button pressed or released, or the button currently held for move events.
     /// * `component`: The SixtyFPS compiled component that provides the tree of items.
     fn process_mouse_input(
         self: Rc<Self>,
         pos: winit::dpi::PhysicalPosition<f64>,
         what: MouseEventType,
+        button: PointerEventButton,
+    );
+    /// Receive a touch event and pass it to the PinchArea under the fingers when several
+    /// touch points are active. The first touch point is also handled as the left mouse button.
+    ///
+    /// Arguments:
+    /// * `id`: The identifier of the touch point.
+    /// * `pos`: The position of the touch point in window physical coordinates.
+    /// * `phase`: Whether the touch point started, moved or ended.
+    fn process_touch_input(
+        self: Rc<Self>,
+        id: u64,
+        pos: winit::dpi::PhysicalPosition<f64>,
+        phase: TouchPhase,
+    );
+    /// Receive an event for a file dragged from the desktop and pass it to the DropArea under it.
+    ///
+    /// Arguments:
+    /// * `pos`: The position of the mouse in window physical coordinates.
+    /// * `event`: Whether the file is hovering the window, was dropped, or left the window.
+    fn process_file_drop(
+        self: Rc<Self>,
+        pos: winit::dpi::PhysicalPosition<f64>,
+        event: FileDropEvent,
     );
     /// Receive a key event and pass it to the items of the component to
     /// change their state.
@@ -171,6 +197,15 @@ pub(crate) fn current_keyboard_modifiers(&self) -> crate::input::KeyboardModifie
         self.0.clone().current_keyboard_modifiers()
     }
 
+    pub(crate) fn process_touch_input(
+        &self,
+        id: u64,
+        pos: winit::dpi::PhysicalPosition<f64>,
+        phase: TouchPhase,
+    ) {
+        self.0.clone().process_touch_input(id, pos, phase)
+    }
+
     pub(crate) fn process_key_input(&self, event: &KeyEvent) {
         self.0.clone().process_key_input(event)
     }
@@ -243,6 +278,7 @@ pub fn run(mut self) {
 
         let mut cursor_pos = winit::dpi::PhysicalPosition::new(0., 0.);
         let mut pressed = false;
+        let mut pressed_button = PointerEventButton::none;
         let mut run_fn = move |event: Event<CustomEvent>,
                                _: &EventLoopWindowTarget<CustomEvent>,
                                control_flow: &mut ControlFlow| {
@@ -300,7 +336,7 @@ pub fn run(mut self) {
 
                 winit::event::Event::WindowEvent {
                     ref window_id,
-                    event: winit::event::WindowEvent::MouseInput { state, .. },
+                    event: winit::event::WindowEvent::MouseInput { state, button, .. },
                     ..
                 } => {
                     crate::animations::update_animations();
@@ -308,17 +344,25 @@ pub fn run(mut self) {
                         if let Some(Some(window)) =
                             windows.borrow().get(&window_id).map(|weakref| weakref.upgrade())
                         {
+                            let button = match button {
+                                winit::event::MouseButton::Left => PointerEventButton::left,
+                                winit::event::MouseButton::Right => PointerEventButton::right,
+                                winit::event::MouseButton::Middle => PointerEventButton::middle,
+                                winit::event::MouseButton::Other(_) => PointerEventButton::none,
+                            };
                             let what = match state {
                                 winit::event::ElementState::Pressed => {
                                     pressed = true;
+                                    pressed_button = button;
                                     MouseEventType::MousePressed
                                 }
                                 winit::event::ElementState::Released => {
                                     pressed = false;
+                                    pressed_button = PointerEventButton::none;
                                     MouseEventType::MouseReleased
                                 }
                             };
-                            window.clone().process_mouse_input(cursor_pos, what);
+                            window.clone().process_mouse_input(cursor_pos, what, button);
                             // FIXME: remove this, it should be based on actual changes rather than this
                             window.request_redraw();
                         }
@@ -334,20 +378,39 @@ pub fn run(mut self) {
                         if let Some(Some(window)) =
                             windows.borrow().get(&window_id).map(|weakref| weakref.upgrade())
                         {
-                            let cursor_pos = touch.location;
-                            let what = match touch.phase {
-                                winit::event::TouchPhase::Started => {
-                                    pressed = true;
-                                    MouseEventType::MousePressed
-                                }
-                                winit::event::TouchPhase::Ended
-                                | winit::event::TouchPhase::Cancelled => {
-                                    pressed = false;
-                                    MouseEventType::MouseReleased
-                                }
-                                winit::event::TouchPhase::Moved => MouseEventType::MouseMoved,
-                            };
-                            window.clone().process_mouse_input(cursor_pos, what);
+                            window.clone().process_touch_input(
+                                touch.id,
+                                touch.location,
+                                touch.phase.into(),
+                            );
+                            // FIXME: remove this, it should be based on actual changes rather than this
+                            window.request_redraw();
+                        }
+                    });
+                }
+                winit::event::Event::WindowEvent { ref window_id, ref event }
+                    if matches!(
+                        event,
+                        winit::event::WindowEvent::HoveredFile(..)
+                            | winit::event::WindowEvent::DroppedFile(..)
+                            | winit::event::WindowEvent::HoveredFileCancelled
+                    ) =>
+                {
+                    let file_drop_event = match event {
+                        winit::event::WindowEvent::HoveredFile(path) => {
+                            FileDropEvent::Hovered(path.clone())
+                        }
+                        winit::event::WindowEvent::DroppedFile(path) => {
+                            FileDropEvent::Dropped(path.clone())
+                        }
+                        _ => FileDropEvent::Cancelled,
+                    };
+                    crate::animations::update_animations();
+                    ALL_WINDOWS.with(|windows| {
+                        if let Some(Some(window)) =
+                            windows.borrow().get(&window_id).map(|weakref| weakref.upgrade())
+                        {
+                            window.clone().process_file_drop(cursor_pos, file_drop_event);
                             // FIXME: remove this, it should be based on actual changes rather than this
                             window.request_redraw();
                         }
@@ -364,9 +427,11 @@ pub fn run(mut self) {
                         if let Some(Some(window)) =
                             windows.borrow().get(&window_id).map(|weakref| weakref.upgrade())
                         {
-                            window
-                                .clone()
-                                .process_mouse_input(cursor_pos, MouseEventType::MouseMoved);
+                            window.clone().process_mouse_input(
+                                cursor_pos,
+                                MouseEventType::MouseMoved,
+                                pressed_button,
+                            );
                             // FIXME: remove this, it should be based on actual changes rather than this
                             window.request_redraw();
                         }
@@ -386,9 +451,12 @@ pub fn run(mut self) {
                                 windows.borrow().get(&window_id).map(|weakref| weakref.upgrade())
                             {
                                 pressed = false;
-                                window
-                                    .clone()
-                                    .process_mouse_input(cursor_pos, MouseEventType::MouseExit);
+                                window.clone().process_mouse_input(
+                                    cursor_pos,
+                                    MouseEventType::MouseExit,
+                                    pressed_button,
+                                );
+                                pressed_button = PointerEventButton::none;
                                 // FIXME: remove this, it should be based on actual changes rather than this
                                 window.request_redraw();
                             }
@@ -487,6 +555,7 @@ pub fn run(mut self) {
                 })
             }
 
+            crate::animations::update_animations();
             if crate::timers::TimerList::maybe_activate_timers() {
                 ALL_WINDOWS.with(|windows| {
                     windows.borrow().values().for_each(|window| {
@@ -497,6 +566,16 @@ pub fn run(mut self) {
                 })
             }
 
+            if crate::properties::ChangeTracker::run_change_handlers() {
+                ALL_WINDOWS.with(|windows| {
+                    windows.borrow().values().for_each(|window| {
+                        if let Some(window) = window.upgrade() {
+                            window.request_redraw();
+                        }
+                    })
+                })
+            }
+
             if *control_flow == winit::event_loop::ControlFlow::Wait {
                 if let Some(next_timer) = crate::timers::TimerList::next_timeout() {
                     *control_flow = winit::event_loop::ControlFlow::WaitUntil(next_timer);
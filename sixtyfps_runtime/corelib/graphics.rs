@@ -20,13 +20,14 @@
     created by the backend in a type-erased manner.
 */
 extern crate alloc;
-use crate::input::{KeyEvent, KeyboardModifiers, MouseEvent, MouseEventType};
-use crate::items::{ItemRc, ItemRef, ItemWeak};
+use crate::input::{
+    FileDropEvent, KeyCode, KeyEvent, KeyEventResult, KeyboardModifiers, MouseEvent,
+    MouseEventType, TouchEvent, TouchPhase,
+};
+use crate::items::{ItemRc, ItemRef, ItemWeak, PointerEventButton};
 use crate::properties::{InterpolatedPropertyValue, Property, PropertyTracker};
 #[cfg(feature = "rtti")]
-use crate::rtti::{BuiltinItem, FieldInfo, PropertyInfo, ValueType};
-#[cfg(feature = "rtti")]
-use crate::Callback;
+use crate::rtti::{BuiltinItem, CallbackInfo, FieldInfo, PropertyInfo, ValueType};
 use crate::{
     component::{ComponentRc, ComponentWeak},
     slice::Slice,
@@ -213,6 +214,27 @@ fn interpolate(self, target_value: Self, t: f32) -> Self {
             alpha: self.alpha.interpolate(target_value.alpha, t),
         }
     }
+
+    fn span_ratio(self, target_value: Self, from: Self, to: Self) -> f32 {
+        // Project the span on the span from `from` to `to`
+        let span = |a: Self, b: Self| {
+            [
+                b.red as f32 - a.red as f32,
+                b.green as f32 - a.green as f32,
+                b.blue as f32 - a.blue as f32,
+                b.alpha as f32 - a.alpha as f32,
+            ]
+        };
+        let dot =
+            |a: [f32; 4], b: [f32; 4]| a.iter().zip(b.iter()).map(|(a, b)| a * b).sum::<f32>();
+        let unit = span(from, to);
+        let unit_square = dot(unit, unit);
+        if unit_square > 0. {
+            dot(span(self, target_value), unit) / unit_square
+        } else {
+            0.
+        }
+    }
 }
 
 impl std::fmt::Display for Color {
@@ -530,12 +552,125 @@ pub struct GraphicsWindow<Backend: GraphicsBackend + 'static> {
     meta_property_listener: Pin<Rc<PropertyTracker>>,
     focus_item: std::cell::RefCell<ItemWeak>,
     mouse_input_state: std::cell::Cell<crate::input::MouseInputState>,
+    touch_input_state: std::cell::Cell<crate::input::TouchInputState>,
+    file_drop_state: std::cell::Cell<crate::input::FileDropState>,
+    /// Sends the new tree to the accessibility adapter when the accessible properties change
+    accessibility_tracker: crate::properties::ChangeTracker,
+    /// Set when the last key press activated a Shortcut, until the next key event
+    key_press_consumed_by_shortcut: Cell<bool>,
+    /// The cursor shape that was last applied on the platform window
+    mouse_cursor: std::cell::Cell<crate::items::MouseCursor>,
+    /// Applies the cursor shape again when the `mouse_cursor` of the items under the mouse change
+    mouse_cursor_tracker: crate::properties::ChangeTracker,
     /// Current popup's component and position
     /// FIXME: the popup should actually be another window, not just some overlay
     active_popup: std::cell::RefCell<Option<(ComponentRc, Point)>>,
 }
 
 impl<Backend: GraphicsBackend + 'static> GraphicsWindow<Backend> {
+    /// Applies the cursor shape on the platform window, if it has changed.
+    fn set_mouse_cursor(&self, cursor: crate::items::MouseCursor) {
+        use crate::eventloop::GenericWindow;
+        use crate::items::MouseCursor;
+        use winit::window::CursorIcon;
+        if !matches!(&*self.map_state.borrow(), GraphicsWindowBackendState::Mapped(..)) {
+            return;
+        }
+        if self.mouse_cursor.replace(cursor) == cursor {
+            return;
+        }
+        let icon = match cursor {
+            MouseCursor::inherit | MouseCursor::arrow | MouseCursor::none => CursorIcon::Default,
+            MouseCursor::help => CursorIcon::Help,
+            MouseCursor::pointer => CursorIcon::Hand,
+            MouseCursor::progress => CursorIcon::Progress,
+            MouseCursor::wait => CursorIcon::Wait,
+            MouseCursor::crosshair => CursorIcon::Crosshair,
+            MouseCursor::text => CursorIcon::Text,
+            MouseCursor::alias => CursorIcon::Alias,
+            MouseCursor::copy => CursorIcon::Copy,
+            MouseCursor::all_scroll => CursorIcon::AllScroll,
+            MouseCursor::no_drop => CursorIcon::NoDrop,
+            MouseCursor::not_allowed => CursorIcon::NotAllowed,
+            MouseCursor::grab => CursorIcon::Grab,
+            MouseCursor::grabbing => CursorIcon::Grabbing,
+            MouseCursor::col_resize => CursorIcon::ColResize,
+            MouseCursor::row_resize => CursorIcon::RowResize,
+            MouseCursor::n_resize => CursorIcon::NResize,
+            MouseCursor::e_resize => CursorIcon::EResize,
+            MouseCursor::s_resize => CursorIcon::SResize,
+            MouseCursor::w_resize => CursorIcon::WResize,
+            MouseCursor::ne_resize => CursorIcon::NeResize,
+            MouseCursor::nw_resize => CursorIcon::NwResize,
+            MouseCursor::se_resize => CursorIcon::SeResize,
+            MouseCursor::sw_resize => CursorIcon::SwResize,
+            MouseCursor::ew_resize => CursorIcon::EwResize,
+            MouseCursor::ns_resize => CursorIcon::NsResize,
+            MouseCursor::nesw_resize => CursorIcon::NeswResize,
+            MouseCursor::nwse_resize => CursorIcon::NwseResize,
+        };
+        self.with_platform_window(&|platform_window| {
+            platform_window.set_cursor_visible(cursor != MouseCursor::none);
+            platform_window.set_cursor_icon(icon);
+        });
+    }
+
+    /// Returns the cursor shape requested by the items under the mouse, or the arrow.
+    fn mouse_cursor_from_items(&self) -> crate::items::MouseCursor {
+        let mouse_input_state = self.mouse_input_state.take();
+        let cursor = mouse_input_state.mouse_cursor();
+        self.mouse_input_state.set(mouse_input_state);
+        cursor.unwrap_or(crate::items::MouseCursor::arrow)
+    }
+
+    /// Applies the cursor shape of the items under the mouse, and applies it again whenever
+    /// one of their `mouse_cursor` properties change, until the next mouse event.
+    fn track_mouse_cursor(self: &Rc<Self>, mouse_in_window: bool) {
+        let window = Rc::downgrade(self);
+        let cursor = move || {
+            window
+                .upgrade()
+                .filter(|_| mouse_in_window)
+                .map_or(crate::items::MouseCursor::arrow, |window| window.mouse_cursor_from_items())
+        };
+        self.set_mouse_cursor(cursor());
+        let window = Rc::downgrade(self);
+        self.mouse_cursor_tracker.init(cursor.clone(), move || {
+            if let Some(window) = window.upgrade() {
+                window.set_mouse_cursor(cursor());
+            }
+        });
+    }
+
+    /// Sends the accessibility tree to the accessibility adapter, and sends it again whenever
+    /// one of the accessible properties change, until the window is unmapped.
+    fn track_accessibility_tree(self: &Rc<Self>) {
+        let adapter = match crate::accessibility::accessibility_adapter() {
+            Some(adapter) => adapter,
+            None => return,
+        };
+        let component = self.component.borrow().clone();
+        let last_tree = Rc::new(RefCell::new(None));
+        let tree = {
+            let last_tree = last_tree.clone();
+            move || {
+                let tree = component
+                    .upgrade()
+                    .map(|component| crate::accessibility::accessibility_tree(&component));
+                *last_tree.borrow_mut() = tree.clone();
+                tree
+            }
+        };
+        let window = Rc::downgrade(self);
+        let update_tree = move || {
+            if let (Some(window), Some(tree)) = (window.upgrade(), &*last_tree.borrow()) {
+                adapter.update_tree(&crate::eventloop::ComponentWindow::new(window), tree);
+            }
+        };
+        self.accessibility_tracker.init(tree, update_tree.clone());
+        update_tree();
+    }
+
     /// Creates a new reference-counted instance.
     ///
     /// Arguments:
@@ -556,6 +691,12 @@ pub fn new(
             meta_property_listener: Rc::pin(Default::default()),
             focus_item: Default::default(),
             mouse_input_state: Default::default(),
+            touch_input_state: Default::default(),
+            file_drop_state: Default::default(),
+            accessibility_tracker: Default::default(),
+            key_press_consumed_by_shortcut: Default::default(),
+            mouse_cursor: Default::default(),
+            mouse_cursor_tracker: Default::default(),
             active_popup: Default::default(),
         })
     }
@@ -753,6 +894,7 @@ fn process_mouse_input(
         self: Rc<Self>,
         pos: winit::dpi::PhysicalPosition<f64>,
         what: MouseEventType,
+        button: PointerEventButton,
     ) {
         let mut pos = euclid::point2(pos.x as _, pos.y as _);
         let active_popup = (*self.active_popup.borrow()).clone();
@@ -772,12 +914,14 @@ fn process_mouse_input(
             self.component.borrow().upgrade().unwrap()
         };
 
-        self.mouse_input_state.set(crate::input::process_mouse_input(
+        let mouse_input_state = crate::input::process_mouse_input(
             component,
-            MouseEvent { pos, what },
+            MouseEvent { pos, what, button },
             &crate::eventloop::ComponentWindow::new(self.clone()),
             self.mouse_input_state.take(),
-        ));
+        );
+        self.mouse_input_state.set(mouse_input_state);
+        self.track_mouse_cursor(what != MouseEventType::MouseExit);
 
         if active_popup.is_some() {
             //FIXME: currently the ComboBox is the only thing that uses the popup, and it should close automatically
@@ -788,10 +932,90 @@ fn process_mouse_input(
         }
     }
 
+    fn process_touch_input(
+        self: Rc<Self>,
+        id: u64,
+        pos: winit::dpi::PhysicalPosition<f64>,
+        phase: TouchPhase,
+    ) {
+        let state = self.touch_input_state.take();
+        let primary_before = state.primary_touch();
+        let gesture_before = state.gesture_active();
+        let state = crate::input::process_touch_input(
+            self.component.borrow().upgrade().unwrap(),
+            TouchEvent { id, pos: euclid::point2(pos.x as _, pos.y as _), phase },
+            state,
+        );
+        let is_primary = state.primary_touch().or(primary_before) == Some(id);
+        let gesture_now = state.gesture_active();
+        self.touch_input_state.set(state);
+
+        if !gesture_before && gesture_now {
+            // The press of the first finger is not a click
+            self.process_mouse_input(pos, MouseEventType::MouseExit, PointerEventButton::left);
+        } else if is_primary && !gesture_before && !gesture_now {
+            // Synthesize the mouse events from the first finger
+            let what = match phase {
+                TouchPhase::Started => MouseEventType::MousePressed,
+                TouchPhase::Moved => MouseEventType::MouseMoved,
+                TouchPhase::Ended => MouseEventType::MouseReleased,
+                TouchPhase::Cancelled => MouseEventType::MouseExit,
+            };
+            self.process_mouse_input(pos, what, PointerEventButton::left);
+        }
+    }
+
+    fn process_file_drop(
+        self: Rc<Self>,
+        pos: winit::dpi::PhysicalPosition<f64>,
+        event: FileDropEvent,
+    ) {
+        let state = crate::input::process_file_drop(
+            self.component.borrow().upgrade().unwrap(),
+            euclid::point2(pos.x as _, pos.y as _),
+            event,
+            self.file_drop_state.take(),
+        );
+        self.file_drop_state.set(state);
+    }
+
     fn process_key_input(self: Rc<Self>, event: &KeyEvent) {
-        if let Some(focus_item) = self.as_ref().focus_item.borrow().upgrade() {
+        let component = self.component.borrow().upgrade();
+        match event {
+            KeyEvent::KeyPressed { code, modifiers } => {
+                self.key_press_consumed_by_shortcut.set(false);
+                if let Some(component) = &component {
+                    if crate::input::process_shortcuts(component, *code, *modifiers) {
+                        // The text produced by that key press must not be delivered either
+                        self.key_press_consumed_by_shortcut.set(true);
+                        return;
+                    }
+                }
+            }
+            KeyEvent::CharacterInput { .. } if self.key_press_consumed_by_shortcut.get() => return,
+            _ => {}
+        }
+
+        let focus_item = self.as_ref().focus_item.borrow().upgrade();
+        let result = if let Some(focus_item) = &focus_item {
             let window = &crate::eventloop::ComponentWindow::new(self.clone());
-            focus_item.borrow().as_ref().key_event(event, &window);
+            focus_item.borrow().as_ref().key_event(event, &window)
+        } else {
+            KeyEventResult::EventIgnored
+        };
+        if !matches!(result, KeyEventResult::EventIgnored) {
+            return;
+        }
+
+        // Move the focus along the focus chain with Tab and Shift+Tab
+        if let (KeyEvent::KeyPressed { code: KeyCode::Tab, modifiers }, Some(component)) =
+            (event, &component)
+        {
+            if let Some(next) =
+                crate::input::next_focus_item(component, focus_item.as_ref(), modifiers.shift())
+            {
+                crate::eventloop::GenericWindow::set_focus_item(self.clone(), &next);
+            }
         }
     }
 
@@ -891,10 +1115,16 @@ fn map_window(self: Rc<Self>, event_loop: &crate::eventloop::EventLoop) {
             window_id
         };
 
+        // The new platform window has the default cursor: apply the one of the items under the mouse
+        self.mouse_cursor.set(Default::default());
+        self.set_mouse_cursor(self.mouse_cursor_from_items());
+
         crate::eventloop::register_window(
             id,
             self.clone() as Rc<dyn crate::eventloop::GenericWindow>,
         );
+
+        self.track_accessibility_tree();
     }
 
     fn request_redraw(&self) {
@@ -907,6 +1137,10 @@ fn request_redraw(&self) {
     }
 
     fn unmap_window(self: Rc<Self>) {
+        self.accessibility_tracker.clear();
+        if let Some(adapter) = crate::accessibility::accessibility_adapter() {
+            adapter.remove_window(&crate::eventloop::ComponentWindow::new(self.clone()));
+        }
         self.map_state.replace(GraphicsWindowBackendState::Unmapped);
         if let Some(existing_blinker) = self.cursor_blinker.borrow().upgrade() {
             existing_blinker.stop();
@@ -14,7 +14,10 @@
 use crate::component::ComponentRc;
 use crate::graphics::Point;
 use crate::item_tree::ItemVisitorResult;
-use crate::items::{ItemRc, ItemRef, ItemWeak};
+use crate::items::{
+    DragArea, DropArea, DropEvent, ItemRc, ItemRef, ItemWeak, MouseCursor, PinchArea,
+    PointerEventButton,
+};
 use euclid::default::Vector2D;
 use sixtyfps_corelib_macros::*;
 use std::convert::TryFrom;
@@ -41,6 +44,9 @@ pub struct MouseEvent {
     pub pos: Point,
     /// The action performed (pressed/released/moced)
     pub what: MouseEventType,
+    /// The button that was pressed or released. For move events, this is the button
+    /// currently held, if any.
+    pub button: PointerEventButton,
 }
 
 /// This value is returned by the input handler of a component
@@ -69,6 +75,9 @@ pub enum InputEventResult {
     GrabMouse,
     /// One must send an MouseExit when the mouse leave this item
     ObserveHover,
+    /// Same as GrabMouse, but a drag operation is started from this item, which must be a DragArea.
+    /// The DropArea under the mouse will be notified until the mouse is released.
+    StartDrag,
 }
 
 impl Default for InputEventResult {
@@ -289,6 +298,8 @@ fn try_from(value: char) -> Result<Self, Self::Error> {
             '7' => Self::Key7,
             '8' => Self::Key8,
             '9' => Self::Key9,
+            '0' => Self::Key0,
+            '\t' => Self::Tab,
             _ => return Err(()),
         })
     }
@@ -392,6 +403,49 @@ fn from(modifier: KeyboardModifier) -> Self {
     }
 }
 
+/// Parses a key sequence such as `"Ctrl+S"`, `"Ctrl+Shift+F5"` or `"Escape"` into the key code
+/// and the exact set of modifiers that must be pressed.
+///
+/// The syntax of the key sequences is documented in the `sixtyfps_key_sequence` crate, which is
+/// also used by the compiler to validate them.
+/// Returns None if the sequence is not valid.
+pub fn parse_key_sequence(keys: &str) -> Option<(KeyCode, KeyboardModifiers)> {
+    let (key, modifiers) = sixtyfps_key_sequence::parse(keys).ok()?;
+    let mut keyboard_modifiers = KeyboardModifiers::default();
+    for (pressed, modifier) in [
+        (modifiers.control, CONTROL_MODIFIER),
+        (modifiers.shift, SHIFT_MODIFIER),
+        (modifiers.alt, ALT_MODIFIER),
+        (modifiers.meta, LOGO_MODIFIER),
+    ]
+    .iter()
+    {
+        if *pressed {
+            keyboard_modifiers.0 |= modifier.0;
+        }
+    }
+    Some((KeyCode::from_name(key)?, keyboard_modifiers))
+}
+
+#[test]
+fn test_parse_key_sequence() {
+    let ctrl_shift = KeyboardModifiers(CONTROL_MODIFIER.0 | SHIFT_MODIFIER.0);
+    assert_eq!(parse_key_sequence("Ctrl+Shift+S"), Some((KeyCode::S, ctrl_shift)));
+    assert_eq!(parse_key_sequence("ctrl + SHIFT + s"), Some((KeyCode::S, ctrl_shift)));
+    assert_eq!(parse_key_sequence("pageup"), Some((KeyCode::PageUp, KeyboardModifiers::default())));
+    assert_eq!(parse_key_sequence("Alt+f5"), Some((KeyCode::F5, ALT_MODIFIER.into())));
+    assert_eq!(parse_key_sequence("Meta+Esc"), Some((KeyCode::Escape, LOGO_MODIFIER.into())));
+    assert_eq!(parse_key_sequence("Ctrl+Shfit+S"), None);
+    assert_eq!(parse_key_sequence("Ctrl+Foo"), None);
+    assert_eq!(parse_key_sequence("Ctrl+"), None);
+}
+
+#[test]
+fn test_key_sequence_names() {
+    // The compiler validates the key sequences with the same names as the KeyCode
+    assert_eq!(KeyCode::NAMES, sixtyfps_key_sequence::KEY_NAMES);
+}
+
 /// Represents a key event sent by the windowing system.
 #[derive(Debug, Clone, PartialEq)]
 #[repr(C)]
@@ -451,6 +505,130 @@ pub enum KeyEventResult {
     EventIgnored,
 }
 
+/// Emits the `activated` callback of the first enabled Shortcut of the `component` whose keys
+/// are `code` with exactly the `modifiers`. A Shortcut is also disabled when one of its ancestor
+/// items, such as a FocusScope, is disabled.
+/// Returns true if a shortcut was activated, in which case the key press must not be delivered further.
+pub fn process_shortcuts(
+    component: &ComponentRc,
+    code: KeyCode,
+    modifiers: KeyboardModifiers,
+) -> bool {
+    let mut shortcut = None;
+    // The FrontToBack order skips the elements of the exit transitions. The items are post-visited
+    // in the reverse order of the item tree, so the last match is the first one in the item tree.
+    // The state passed to the children is whether all their ancestors are enabled.
+    crate::item_tree::visit_items_with_post_visit(
+        component,
+        crate::item_tree::TraversalOrder::FrontToBack,
+        |_, item, item_index, ancestors_enabled: &bool| {
+            let enabled = *ancestors_enabled && is_item_enabled(item);
+            (ItemVisitorResult::Continue(enabled), (item_index, *ancestors_enabled))
+        },
+        |comp_rc: &ComponentRc,
+         item: core::pin::Pin<ItemRef>,
+         (item_index, ancestors_enabled): (usize, bool)| {
+            if !ancestors_enabled {
+                return;
+            }
+            if let Some(s) = ItemRef::downcast_pin::<crate::items::Shortcut>(item) {
+                if s.matches(code, modifiers) {
+                    shortcut = Some(ItemRc::new(comp_rc.clone(), item_index));
+                }
+            }
+        },
+        true,
+    );
+    // Emit the callback after the traversal, as the handler may change the item tree
+    if let Some(shortcut) = shortcut {
+        if let Some(s) = ItemRef::downcast_pin::<crate::items::Shortcut>(shortcut.borrow()) {
+            s.activate();
+            return true;
+        }
+    }
+    false
+}
+
+/// Returns false if the item has an `enabled` property that is false
+fn is_item_enabled(item: core::pin::Pin<ItemRef>) -> bool {
+    use crate::items::{DragArea, DropArea, FocusScope, PinchArea, TextInput};
+    macro_rules! check_enabled {
+        ($($ty:ident),*) => {
+            $(if let Some(item) = ItemRef::downcast_pin::<$ty>(item) {
+                return $ty::FIELD_OFFSETS.enabled.apply_pin(item).get();
+            })*
+        };
+    }
+    check_enabled!(FocusScope, TextInput, PinchArea, DragArea, DropArea);
+    true
+}
+
+/// Returns the `tab_index` of the item if it is an enabled item that can have the keyboard focus
+fn focusable_tab_index(item: core::pin::Pin<ItemRef>) -> Option<i32> {
+    use crate::items::{FocusScope, TextInput};
+    if let Some(text_input) = ItemRef::downcast_pin::<TextInput>(item) {
+        if TextInput::FIELD_OFFSETS.enabled.apply_pin(text_input).get() {
+            return Some(TextInput::FIELD_OFFSETS.tab_index.apply_pin(text_input).get());
+        }
+    } else if let Some(focus_scope) = ItemRef::downcast_pin::<FocusScope>(item) {
+        if FocusScope::FIELD_OFFSETS.enabled.apply_pin(focus_scope).get() {
+            return Some(FocusScope::FIELD_OFFSETS.tab_index.apply_pin(focus_scope).get());
+        }
+    }
+    None
+}
+
+/// Returns the items that are reached by pressing the Tab key, in order.
+///
+/// These are the enabled items that can have the focus, in the order of the item tree, including
+/// the items of the repeated and conditional sub-components, but not the ones of the elements whose
+/// exit transition is running. The items with a positive `tab_index` come first, sorted by
+/// increasing `tab_index`, and the items with a negative `tab_index` are skipped.
+pub fn focus_chain(component: &ComponentRc) -> Vec<ItemRc> {
+    let mut chain = Vec::new();
+    // The FrontToBack order skips the elements of the exit transitions. The items are post-visited
+    // in the reverse order of the item tree, so the chain is reversed afterwards.
+    crate::item_tree::visit_items_with_post_visit(
+        component,
+        crate::item_tree::TraversalOrder::FrontToBack,
+        |_, _, item_index, _| (ItemVisitorResult::Continue(()), item_index),
+        |comp_rc: &ComponentRc, item: core::pin::Pin<ItemRef>, item_index: usize| {
+            if let Some(tab_index) = focusable_tab_index(item) {
+                if tab_index >= 0 {
+                    chain.push((tab_index, ItemRc::new(comp_rc.clone(), item_index)));
+                }
+            }
+        },
+        (),
+    );
+    chain.reverse();
+    // sort_by_key is stable, so the items with the same tab_index stay in the item tree order
+    chain.sort_by_key(|(tab_index, _)| if *tab_index > 0 { *tab_index } else { i32::MAX });
+    chain.into_iter().map(|(_, item)| item).collect()
+}
+
+/// Returns the item which should get the focus after `current` when pressing Tab,
+/// or before `current` when pressing Shift+Tab if `backward` is true.
+pub fn next_focus_item(
+    component: &ComponentRc,
+    current: Option<&ItemRc>,
+    backward: bool,
+) -> Option<ItemRc> {
+    let mut chain = focus_chain(component);
+    let len = chain.len();
+    if len == 0 {
+        return None;
+    }
+    let position = current.and_then(|current| chain.iter().position(|item| item == current));
+    let next = match (position, backward) {
+        (Some(position), false) => (position + 1) % len,
+        (Some(position), true) => (position + len - 1) % len,
+        (None, false) => 0,
+        (None, true) => len - 1,
+    };
+    Some(chain.swap_remove(next))
+}
+
 /// This event is sent to a component and items when they receive or loose
 /// the keyboard focus.
 #[derive(Debug, Clone, PartialEq)]
@@ -466,6 +644,109 @@ pub enum FocusEvent {
     WindowLostFocus,
 }
 
+/// A drag and drop operation in progress
+struct DragState {
+    data: DropEvent,
+    /// The DropArea currently under the mouse
+    target: Option<ItemRc>,
+}
+
+impl DragState {
+    /// Notify the DropArea under `pos` (in window coordinates) that the drag is over it,
+    /// and the previous one that the drag has left it.
+    fn update_target(&mut self, component: &ComponentRc, pos: Point) {
+        let new_target = item_at::<DropArea>(component, pos, |drop_area| {
+            DropArea::FIELD_OFFSETS.enabled.apply_pin(drop_area).get()
+        });
+        let same = match (&self.target, &new_target) {
+            (Some(a), Some(b)) => a == b,
+            (None, None) => true,
+            _ => false,
+        };
+        if !same {
+            self.leave();
+            if let Some(target) = &new_target {
+                if let Some(drop_area) = ItemRef::downcast_pin::<DropArea>(target.borrow()) {
+                    drop_area.drag_enter(&self.data);
+                }
+            }
+            self.target = new_target;
+        }
+    }
+
+    /// The drag was cancelled or left the current target
+    fn leave(&mut self) {
+        if let Some(target) = self.target.take() {
+            if let Some(drop_area) = ItemRef::downcast_pin::<DropArea>(target.borrow()) {
+                drop_area.drag_exit();
+            }
+        }
+    }
+
+    /// The mouse was released: drop on the target
+    fn drop_on_target(mut self) {
+        if let Some(target) = self.target.take() {
+            if let Some(drop_area) = ItemRef::downcast_pin::<DropArea>(target.borrow()) {
+                drop_area.drop(&self.data);
+            }
+        }
+    }
+}
+
+/// A file dragged from the desktop onto the window
+#[derive(Debug, Clone, PartialEq)]
+pub enum FileDropEvent {
+    /// The file is being dragged over the window
+    Hovered(std::path::PathBuf),
+    /// The file was dropped on the window
+    Dropped(std::path::PathBuf),
+    /// The file was dragged out of the window, or the drag was cancelled
+    Cancelled,
+}
+
+/// The state which a window should hold for the files dragged from the desktop
+#[derive(Default)]
+pub struct FileDropState {
+    drag: Option<DragState>,
+}
+
+/// Process the `event` of a file dragged from the desktop at `pos` over the `component`.
+/// The file is seen as a drag with the `DROPPED_FILE_MIME_TYPE` by the DropArea.
+pub fn process_file_drop(
+    component: ComponentRc,
+    pos: Point,
+    event: FileDropEvent,
+    mut state: FileDropState,
+) -> FileDropState {
+    let drag_for = |path: &std::path::Path| DragState {
+        data: DropEvent {
+            data: path.to_string_lossy().as_ref().into(),
+            mime_type: crate::items::DROPPED_FILE_MIME_TYPE.into(),
+        },
+        target: None,
+    };
+    match event {
+        FileDropEvent::Hovered(path) => {
+            let drag = state.drag.get_or_insert_with(|| drag_for(&path));
+            drag.update_target(&component, pos);
+            state
+        }
+        FileDropEvent::Dropped(path) => {
+            let mut drag = state.drag.take().unwrap_or_else(|| drag_for(&path));
+            drag.data = drag_for(&path).data;
+            drag.update_target(&component, pos);
+            drag.drop_on_target();
+            FileDropState::default()
+        }
+        FileDropEvent::Cancelled => {
+            if let Some(mut drag) = state.drag.take() {
+                drag.leave();
+            }
+            FileDropState::default()
+        }
+    }
+}
+
 /// The state which a window should hold for the mouse input
 #[derive(Default)]
 pub struct MouseInputState {
@@ -473,6 +754,109 @@ pub struct MouseInputState {
     item_stack: Vec<ItemWeak>,
     /// true if the top item of the stack has the mouse grab
     grabbed: bool,
+    /// The drag operation started by the grabber, if any
+    drag: Option<DragState>,
+}
+
+impl MouseInputState {
+    /// Returns the shape the mouse cursor should have, depending on the items under the mouse,
+    /// or None if none of them has a preference.
+    pub fn mouse_cursor(&self) -> Option<MouseCursor> {
+        let items = self.item_stack.iter().filter_map(|it| it.upgrade()).collect::<Vec<_>>();
+        mouse_cursor_for_items(items.iter().map(|item| item.borrow()))
+    }
+}
+
+/// Returns the shape of the mouse cursor over the stack of `items`, ordered from the outermost
+/// to the innermost item. The innermost item that has an opinion about the cursor wins.
+fn mouse_cursor_for_items<'a>(
+    items: impl DoubleEndedIterator<Item = core::pin::Pin<ItemRef<'a>>>,
+) -> Option<MouseCursor> {
+    for item in items.rev() {
+        if let Some(touch_area) = ItemRef::downcast_pin::<crate::items::TouchArea>(item) {
+            let cursor =
+                crate::items::TouchArea::FIELD_OFFSETS.mouse_cursor.apply_pin(touch_area).get();
+            if cursor != MouseCursor::inherit {
+                return Some(cursor);
+            }
+        } else if let Some(text_input) = ItemRef::downcast_pin::<crate::items::TextInput>(item) {
+            if crate::items::TextInput::FIELD_OFFSETS.enabled.apply_pin(text_input).get() {
+                return Some(MouseCursor::text);
+            }
+        }
+    }
+    None
+}
+
+#[test]
+fn test_mouse_cursor_for_items() {
+    use crate::items::{Rectangle, TextInput, TouchArea};
+    let outer = Box::pin(TouchArea::default());
+    let rect = Box::pin(Rectangle::default());
+    let inner = Box::pin(TouchArea::default());
+    let text_input = Box::pin(TextInput::default());
+    let stack = |items: &[core::pin::Pin<ItemRef>]| mouse_cursor_for_items(items.iter().cloned());
+
+    assert_eq!(stack(&[]), None);
+    let touch_areas = [
+        ItemRef::new_pin(outer.as_ref()),
+        ItemRef::new_pin(rect.as_ref()),
+        ItemRef::new_pin(inner.as_ref()),
+    ];
+    assert_eq!(stack(&touch_areas), None);
+    outer.mouse_cursor.set(MouseCursor::pointer);
+    assert_eq!(stack(&touch_areas), Some(MouseCursor::pointer));
+    // The innermost TouchArea wins
+    inner.mouse_cursor.set(MouseCursor::wait);
+    assert_eq!(stack(&touch_areas), Some(MouseCursor::wait));
+    inner.mouse_cursor.set(MouseCursor::arrow);
+    assert_eq!(stack(&touch_areas), Some(MouseCursor::arrow));
+    // `inherit` lets the outer items decide
+    inner.mouse_cursor.set(MouseCursor::inherit);
+    assert_eq!(stack(&touch_areas), Some(MouseCursor::pointer));
+
+    let with_text_input = [ItemRef::new_pin(outer.as_ref()), ItemRef::new_pin(text_input.as_ref())];
+    assert_eq!(stack(&with_text_input), Some(MouseCursor::pointer));
+    text_input.enabled.set(true);
+    assert_eq!(stack(&with_text_input), Some(MouseCursor::text));
+    let over_text_input = [ItemRef::new_pin(text_input.as_ref()), ItemRef::new_pin(inner.as_ref())];
+    assert_eq!(stack(&over_text_input), Some(MouseCursor::text));
+    inner.mouse_cursor.set(MouseCursor::crosshair);
+    assert_eq!(stack(&over_text_input), Some(MouseCursor::crosshair));
+    // A TouchArea can force the arrow over a TextInput
+    inner.mouse_cursor.set(MouseCursor::arrow);
+    assert_eq!(stack(&over_text_input), Some(MouseCursor::arrow));
+}
+
+/// Find the topmost item of type `T` under `pos` (in window coordinates) for which `filter` returns true
+fn item_at<T: vtable::HasStaticVTable<crate::items::ItemVTable>>(
+    component: &ComponentRc,
+    pos: Point,
+    filter: impl Fn(core::pin::Pin<&T>) -> bool,
+) -> Option<ItemRc> {
+    let mut result = None;
+    crate::item_tree::visit_items(
+        component,
+        crate::item_tree::TraversalOrder::FrontToBack,
+        |comp_rc: &ComponentRc,
+         item: core::pin::Pin<ItemRef>,
+         item_index: usize,
+         offset: &Vector2D<f32>|
+         -> ItemVisitorResult<Vector2D<f32>> {
+            let geom = item.as_ref().geometry().translate(*offset);
+            if geom.contains(pos) {
+                if let Some(item) = ItemRef::downcast_pin::<T>(item) {
+                    if filter(item) {
+                        result = Some(ItemRc::new(comp_rc.clone(), item_index));
+                        return ItemVisitorResult::Abort;
+                    }
+                }
+            }
+            ItemVisitorResult::Continue(geom.origin.to_vector())
+        },
+        Vector2D::new(0., 0.),
+    );
+    result
 }
 
 /// Process the `mouse_event` on the `component`, the `mouse_grabber_stack` is the prebious stack
@@ -482,7 +866,7 @@ pub fn process_mouse_input(
     component: ComponentRc,
     mouse_event: MouseEvent,
     window: &crate::eventloop::ComponentWindow,
-    mouse_input_state: MouseInputState,
+    mut mouse_input_state: MouseInputState,
 ) -> MouseInputState {
     'grab: loop {
         if !mouse_input_state.grabbed || mouse_input_state.item_stack.is_empty() {
@@ -495,10 +879,30 @@ pub fn process_mouse_input(
             event.pos -= g.origin.to_vector();
         }
         let grabber = mouse_input_state.item_stack.last().unwrap().upgrade().unwrap();
-        return match grabber.borrow().as_ref().input_event(event, window, &grabber) {
-            InputEventResult::GrabMouse => mouse_input_state,
-            _ => Default::default(),
+        match grabber.borrow().as_ref().input_event(event, window, &grabber) {
+            InputEventResult::GrabMouse => {}
+            InputEventResult::StartDrag => {
+                if let Some(drag_area) = ItemRef::downcast_pin::<DragArea>(grabber.borrow()) {
+                    mouse_input_state.drag =
+                        Some(DragState { data: drag_area.drop_event(), target: None });
+                }
+            }
+            _ => {
+                if let Some(mut drag) = mouse_input_state.drag.take() {
+                    if mouse_event.what == MouseEventType::MouseReleased {
+                        drag.update_target(&component, mouse_event.pos);
+                        drag.drop_on_target();
+                    } else {
+                        drag.leave();
+                    }
+                }
+                return Default::default();
+            }
         };
+        if let Some(drag) = &mut mouse_input_state.drag {
+            drag.update_target(&component, mouse_event.pos);
+        }
+        return mouse_input_state;
     }
 
     // Send the Exit event.
@@ -509,7 +913,7 @@ pub fn process_mouse_input(
         let g = item.borrow().as_ref().geometry();
         pos -= g.origin.to_vector();
         item.borrow().as_ref().input_event(
-            MouseEvent { pos, what: MouseEventType::MouseExit },
+            MouseEvent { pos, what: MouseEventType::MouseExit, button: mouse_event.button },
             window,
             &item,
         );
@@ -541,7 +945,7 @@ pub fn process_mouse_input(
                         return ItemVisitorResult::Abort;
                     }
                     InputEventResult::EventIgnored => (),
-                    InputEventResult::GrabMouse => {
+                    InputEventResult::GrabMouse | InputEventResult::StartDrag => {
                         result.item_stack = mouse_grabber_stack.clone();
                         result.item_stack.push(item_rc.downgrade());
                         result.grabbed = true;
@@ -563,3 +967,145 @@ pub fn process_mouse_input(
     );
     result
 }
+
+/// The phase of a touch point, in a TouchEvent
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TouchPhase {
+    /// A finger touched the screen
+    Started,
+    /// A finger moved on the screen
+    Moved,
+    /// A finger was lifted from the screen
+    Ended,
+    /// The system cancelled the touch point
+    Cancelled,
+}
+
+impl From<winit::event::TouchPhase> for TouchPhase {
+    fn from(phase: winit::event::TouchPhase) -> Self {
+        match phase {
+            winit::event::TouchPhase::Started => Self::Started,
+            winit::event::TouchPhase::Moved => Self::Moved,
+            winit::event::TouchPhase::Ended => Self::Ended,
+            winit::event::TouchPhase::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
+/// Structure representing the event of a single touch point
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct TouchEvent {
+    /// Identifier of the touch point, which stays the same while the finger is on the screen
+    pub id: u64,
+    /// The position of the touch point, in window coordinates
+    pub pos: Point,
+    /// Whether the finger touched, moved or left the screen
+    pub phase: TouchPhase,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TouchPoint {
+    id: u64,
+    /// Position of the touch point when the current gesture started
+    start: Point,
+    pos: Point,
+}
+
+/// The state which a window should hold for the touch input
+#[derive(Default)]
+pub struct TouchInputState {
+    /// The touch points currently on the screen, in the order they were started
+    points: Vec<TouchPoint>,
+    /// The touch point that is synthesized as mouse events
+    primary: Option<u64>,
+    /// The PinchArea receiving the current gesture, if any
+    gesture_item: Option<ItemWeak>,
+}
+
+impl TouchInputState {
+    /// Returns the id of the touch point that is handled as the mouse
+    pub fn primary_touch(&self) -> Option<u64> {
+        self.primary
+    }
+
+    /// Returns true if a gesture with several touch points is in progress
+    pub fn gesture_active(&self) -> bool {
+        self.gesture_item.is_some()
+    }
+
+    fn gesture_item(&self) -> Option<ItemRc> {
+        self.gesture_item.as_ref().and_then(|i| i.upgrade())
+    }
+}
+
+/// Process the `touch_event` on the `component`, given the previous `touch_input_state`.
+/// When two fingers are on the screen, the PinchArea under them receives the gesture.
+/// Returns the new state.
+///
+/// Note that this does not send mouse events, this is the responsibility of the caller
+/// to synthesize them from the primary touch point.
+pub fn process_touch_input(
+    component: ComponentRc,
+    touch_event: TouchEvent,
+    mut state: TouchInputState,
+) -> TouchInputState {
+    match touch_event.phase {
+        TouchPhase::Started => {
+            if state.points.is_empty() {
+                state.primary = Some(touch_event.id);
+            }
+            state.points.retain(|p| p.id != touch_event.id);
+            state.points.push(TouchPoint {
+                id: touch_event.id,
+                start: touch_event.pos,
+                pos: touch_event.pos,
+            });
+            if state.points.len() == 2 && state.gesture_item.is_none() {
+                let center = state.points[0].pos.lerp(state.points[1].pos, 0.5);
+                if let Some(item) = item_at::<PinchArea>(&component, center, |pinch_area| {
+                    PinchArea::FIELD_OFFSETS.enabled.apply_pin(pinch_area).get()
+                }) {
+                    for p in state.points.iter_mut() {
+                        p.start = p.pos;
+                    }
+                    if let Some(pinch_area) = ItemRef::downcast_pin::<PinchArea>(item.borrow()) {
+                        pinch_area.gesture_started();
+                    }
+                    state.gesture_item = Some(item.downgrade());
+                }
+            }
+        }
+        TouchPhase::Moved => {
+            if let Some(p) = state.points.iter_mut().find(|p| p.id == touch_event.id) {
+                p.pos = touch_event.pos;
+            }
+            if let (Some(item), [p0, p1, ..]) = (state.gesture_item(), state.points.as_slice()) {
+                let start = p1.start - p0.start;
+                let current = p1.pos - p0.pos;
+                let scale =
+                    if start.length() > 0. { current.length() / start.length() } else { 1. };
+                let rotation = current.angle_from_x_axis() - start.angle_from_x_axis();
+                let translation = p0.pos.lerp(p1.pos, 0.5) - p0.start.lerp(p1.start, 0.5);
+                if let Some(pinch_area) = ItemRef::downcast_pin::<PinchArea>(item.borrow()) {
+                    pinch_area.gesture_updated(scale, rotation.signed().to_degrees(), translation);
+                }
+            }
+        }
+        TouchPhase::Ended | TouchPhase::Cancelled => {
+            state.points.retain(|p| p.id != touch_event.id);
+            if state.primary == Some(touch_event.id) {
+                state.primary = None;
+            }
+            if state.points.len() < 2 {
+                if let Some(item) = state.gesture_item.take().and_then(|i| i.upgrade()) {
+                    if let Some(pinch_area) = ItemRef::downcast_pin::<PinchArea>(item.borrow()) {
+                        pinch_area.gesture_finished(touch_event.phase == TouchPhase::Cancelled);
+                    }
+                }
+            }
+        }
+    }
+    state
+}
@@ -27,7 +27,8 @@
 use super::eventloop::ComponentWindow;
 use super::graphics::{Color, HighLevelRenderingPrimitive, PathData, Rect};
 use super::input::{
-    FocusEvent, InputEventResult, KeyEvent, KeyEventResult, MouseEvent, MouseEventType,
+    FocusEvent, InputEventResult, KeyEvent, KeyEventResult, KeyboardModifiers, MouseEvent,
+    MouseEventType,
 };
 use super::item_rendering::CachedRenderingData;
 use super::layout::LayoutInfo;
@@ -44,6 +45,8 @@
 pub use text::*;
 mod image;
 pub use self::image::*;
+mod timer;
+pub use timer::*;
 
 /// Items are the nodes in the render tree.
 #[vtable]
@@ -130,6 +133,12 @@ pub fn downgrade(&self) -> ItemWeak {
     }
 }
 
+impl PartialEq for ItemRc {
+    fn eq(&self, other: &Self) -> bool {
+        VRc::ptr_eq(&self.component, &other.component) && self.index == other.index
+    }
+}
+
 /// A Weak reference to an item that can be constructed from an ItemRc.
 #[derive(Default, Clone)]
 pub struct ItemWeak {
@@ -153,6 +162,9 @@ pub struct Rectangle {
     pub y: Property<f32>,
     pub width: Property<f32>,
     pub height: Property<f32>,
+    pub accessible_role: Property<AccessibleRole>,
+    pub accessible_label: Property<SharedString>,
+    pub accessible_description: Property<SharedString>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -235,6 +247,9 @@ pub struct BorderRectangle {
     pub border_width: Property<f32>,
     pub border_radius: Property<f32>,
     pub border_color: Property<Color>,
+    pub accessible_role: Property<AccessibleRole>,
+    pub accessible_label: Property<SharedString>,
+    pub accessible_description: Property<SharedString>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -316,6 +331,148 @@ impl ItemConsts for BorderRectangle {
     pub static ClippedImageVTable for ClippedImage
 }
 
+/// The button which was pressed or released in a pointer event
+#[derive(Copy, Clone, Debug, PartialEq, strum_macros::EnumString, strum_macros::Display)]
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub enum PointerEventButton {
+    none,
+    left,
+    right,
+    middle,
+}
+
+impl Default for PointerEventButton {
+    fn default() -> Self {
+        Self::none
+    }
+}
+
+/// The kind of a pointer event
+#[derive(Copy, Clone, Debug, PartialEq, strum_macros::EnumString, strum_macros::Display)]
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub enum PointerEventKind {
+    cancel,
+    down,
+    up,
+}
+
+impl Default for PointerEventKind {
+    fn default() -> Self {
+        Self::cancel
+    }
+}
+
+/// The argument of the `pointer_event` callback of the `TouchArea`
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+#[repr(C)]
+pub struct PointerEvent {
+    pub button: PointerEventButton,
+    pub kind: PointerEventKind,
+}
+
+/// The shape of the mouse cursor while it is over an element
+#[derive(Copy, Clone, Debug, PartialEq, strum_macros::EnumString, strum_macros::Display)]
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub enum MouseCursor {
+    /// No preference: the cursor of the elements below, or the arrow
+    inherit,
+    arrow,
+    none,
+    help,
+    pointer,
+    progress,
+    wait,
+    crosshair,
+    text,
+    alias,
+    copy,
+    all_scroll,
+    no_drop,
+    not_allowed,
+    grab,
+    grabbing,
+    col_resize,
+    row_resize,
+    n_resize,
+    e_resize,
+    s_resize,
+    w_resize,
+    ne_resize,
+    nw_resize,
+    se_resize,
+    sw_resize,
+    ew_resize,
+    ns_resize,
+    nesw_resize,
+    nwse_resize,
+}
+
+impl Default for MouseCursor {
+    fn default() -> Self {
+        Self::inherit
+    }
+}
+
+/// The role of an element, as exposed to the assistive technologies.
+/// Elements with the `none` role are not part of the accessibility tree, but their children are.
+#[derive(Copy, Clone, Debug, PartialEq, strum_macros::EnumString, strum_macros::Display)]
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub enum AccessibleRole {
+    none,
+    window,
+    button,
+    checkbox,
+    combobox,
+    group,
+    image,
+    list,
+    list_item,
+    slider,
+    spinbox,
+    tab,
+    text,
+    text_input,
+}
+
+impl Default for AccessibleRole {
+    fn default() -> Self {
+        Self::none
+    }
+}
+
+/// The sort order shown in the header of a column of a StandardTableView
+#[derive(Copy, Clone, Debug, PartialEq, strum_macros::EnumString, strum_macros::Display)]
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub enum SortOrder {
+    unsorted,
+    ascending,
+    descending,
+}
+
+impl Default for SortOrder {
+    fn default() -> Self {
+        Self::unsorted
+    }
+}
+
+/// The argument type of the callbacks that do not have argument.
+/// (Renamed to `void` for C++)
+pub type VoidArg = ();
+
+/// The argument type of the `pointer_event` callback.
+/// (Renamed to `PointerEvent` for C++, which has the same layout as this one-element tuple)
+pub type PointerEventArg = (PointerEvent,);
+
+/// Maximum time between two clicks for them to be considered as a double click
+pub const DOUBLE_CLICK_INTERVAL: core::time::Duration = core::time::Duration::from_millis(500);
+/// Time during which the left button needs to be held for the press to be a long press
+pub const LONG_PRESS_DURATION: core::time::Duration = core::time::Duration::from_millis(800);
+
 /// The implementation of the `TouchArea` element
 #[repr(C)]
 #[derive(FieldOffsets, Default, BuiltinItem)]
@@ -336,11 +493,52 @@ pub struct TouchArea {
     /// FIXME: should maybe be as parameter to the mouse event instead. Or at least just one property
     pub mouse_x: Property<f32>,
     pub mouse_y: Property<f32>,
-    pub clicked: Callback<()>,
+    pub mouse_cursor: Property<MouseCursor>,
+    pub clicked: Callback<VoidArg>,
+    pub double_clicked: Callback<VoidArg>,
+    pub long_pressed: Callback<VoidArg>,
+    pub pointer_event: Callback<PointerEventArg>,
+    /// The button that is currently held, or `none`
+    pressed_button: Property<PointerEventButton>,
+    /// The animation tick at which the current press started
+    press_time: Property<crate::animations::Instant>,
+    /// Set when the current press was already reported as a long press
+    long_press_emitted: Property<bool>,
+    /// The animation tick of the last click, if `last_click_valid` is set
+    last_click_time: Property<crate::animations::Instant>,
+    last_click_valid: Property<bool>,
+    pub accessible_role: Property<AccessibleRole>,
+    pub accessible_label: Property<SharedString>,
+    pub accessible_description: Property<SharedString>,
     /// FIXME: remove this
     pub cached_rendering_data: CachedRenderingData,
 }
 
+impl TouchArea {
+    fn emit_pointer_event(self: Pin<&Self>, button: PointerEventButton, kind: PointerEventKind) {
+        Self::FIELD_OFFSETS.pointer_event.apply_pin(self).emit(&(PointerEvent { button, kind },));
+    }
+
+    /// Emits `long_pressed` if the left button has been held long enough.
+    /// The `press_time` argument is the time of the press that triggered the check, so
+    /// that a check scheduled for a previous press is ignored.
+    fn check_long_press(
+        self: Pin<&Self>,
+        press_time: crate::animations::Instant,
+        now: crate::animations::Instant,
+    ) {
+        if Self::FIELD_OFFSETS.pressed.apply_pin(self).get()
+            && Self::FIELD_OFFSETS.pressed_button.apply_pin(self).get() == PointerEventButton::left
+            && !Self::FIELD_OFFSETS.long_press_emitted.apply_pin(self).get()
+            && Self::FIELD_OFFSETS.press_time.apply_pin(self).get() == press_time
+            && now - press_time >= LONG_PRESS_DURATION
+        {
+            Self::FIELD_OFFSETS.long_press_emitted.apply_pin(self).set(true);
+            Self::FIELD_OFFSETS.long_pressed.apply_pin(self).emit(&());
+        }
+    }
+}
+
 impl Item for TouchArea {
     fn init(self: Pin<&Self>, _window: &ComponentWindow) {}
 
@@ -371,35 +569,99 @@ fn input_event(
         self: Pin<&Self>,
         event: MouseEvent,
         _window: &ComponentWindow,
-        _self_rc: &ItemRc,
+        self_rc: &ItemRc,
     ) -> InputEventResult {
         Self::FIELD_OFFSETS.mouse_x.apply_pin(self).set(event.pos.x);
         Self::FIELD_OFFSETS.mouse_y.apply_pin(self).set(event.pos.y);
         Self::FIELD_OFFSETS.has_hover.apply_pin(self).set(event.what != MouseEventType::MouseExit);
 
-        let result = if matches!(event.what, MouseEventType::MouseReleased) {
-            Self::FIELD_OFFSETS.clicked.apply_pin(self).emit(&());
-            InputEventResult::EventAccepted
-        } else {
-            InputEventResult::GrabMouse
-        };
+        let now = crate::animations::current_tick();
+        let pressed_button = Self::FIELD_OFFSETS.pressed_button.apply_pin(self).get();
 
-        Self::FIELD_OFFSETS.pressed.apply_pin(self).set(match event.what {
+        match event.what {
             MouseEventType::MousePressed => {
+                if Self::FIELD_OFFSETS.pressed.apply_pin(self).get() {
+                    // Another button is pressed while we are already pressed: just report it
+                    self.emit_pointer_event(event.button, PointerEventKind::down);
+                    return InputEventResult::GrabMouse;
+                }
                 Self::FIELD_OFFSETS.pressed_x.apply_pin(self).set(event.pos.x);
                 Self::FIELD_OFFSETS.pressed_y.apply_pin(self).set(event.pos.y);
-                true
+                Self::FIELD_OFFSETS.pressed_button.apply_pin(self).set(event.button);
+                Self::FIELD_OFFSETS.press_time.apply_pin(self).set(now);
+                Self::FIELD_OFFSETS.long_press_emitted.apply_pin(self).set(false);
+                Self::FIELD_OFFSETS.pressed.apply_pin(self).set(true);
+                self.emit_pointer_event(event.button, PointerEventKind::down);
+                if event.button == PointerEventButton::left {
+                    // The animation tick is not advancing while the mouse stays still, so use a
+                    // timer to detect the long press in that case. Once the time is mocked in
+                    // tests, the timer follows the mocked time like the animation tick does.
+                    let weak = self_rc.downgrade();
+                    crate::timers::Timer::single_shot(LONG_PRESS_DURATION, move || {
+                        if let Some(item_rc) = weak.upgrade() {
+                            let item = item_rc.borrow();
+                            if let Some(touch_area) = ItemRef::downcast_pin::<TouchArea>(item) {
+                                touch_area.check_long_press(now, now + LONG_PRESS_DURATION);
+                            }
+                        }
+                    });
+                }
+                InputEventResult::GrabMouse
+            }
+            MouseEventType::MouseReleased => {
+                if !Self::FIELD_OFFSETS.pressed.apply_pin(self).get() {
+                    return InputEventResult::EventIgnored;
+                }
+                if event.button != pressed_button {
+                    self.emit_pointer_event(event.button, PointerEventKind::up);
+                    return InputEventResult::GrabMouse;
+                }
+                self.check_long_press(Self::FIELD_OFFSETS.press_time.apply_pin(self).get(), now);
+                Self::FIELD_OFFSETS.pressed.apply_pin(self).set(false);
+                Self::FIELD_OFFSETS.pressed_button.apply_pin(self).set(PointerEventButton::none);
+                self.emit_pointer_event(event.button, PointerEventKind::up);
+                if event.button == PointerEventButton::left
+                    && !Self::FIELD_OFFSETS.long_press_emitted.apply_pin(self).get()
+                {
+                    Self::FIELD_OFFSETS.clicked.apply_pin(self).emit(&());
+                    let last_click_time = Self::FIELD_OFFSETS.last_click_time.apply_pin(self);
+                    let last_click_valid = Self::FIELD_OFFSETS.last_click_valid.apply_pin(self);
+                    if last_click_valid.get()
+                        && now - last_click_time.get() <= DOUBLE_CLICK_INTERVAL
+                    {
+                        // A third click should not be a double click again
+                        last_click_valid.set(false);
+                        Self::FIELD_OFFSETS.double_clicked.apply_pin(self).emit(&());
+                    } else {
+                        last_click_time.set(now);
+                        last_click_valid.set(true);
+                    }
+                }
+                InputEventResult::EventAccepted
+            }
+            MouseEventType::MouseExit => {
+                if Self::FIELD_OFFSETS.pressed.apply_pin(self).get() {
+                    Self::FIELD_OFFSETS.pressed.apply_pin(self).set(false);
+                    Self::FIELD_OFFSETS
+                        .pressed_button
+                        .apply_pin(self)
+                        .set(PointerEventButton::none);
+                    self.emit_pointer_event(pressed_button, PointerEventKind::cancel);
+                }
+                InputEventResult::GrabMouse
             }
-            MouseEventType::MouseExit | MouseEventType::MouseReleased => false,
             MouseEventType::MouseMoved => {
-                return if Self::FIELD_OFFSETS.pressed.apply_pin(self).get() {
+                if Self::FIELD_OFFSETS.pressed.apply_pin(self).get() {
+                    self.check_long_press(
+                        Self::FIELD_OFFSETS.press_time.apply_pin(self).get(),
+                        now,
+                    );
                     InputEventResult::GrabMouse
                 } else {
                     InputEventResult::ObserveHover
                 }
             }
-        });
-        result
+        }
     }
 
     fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &ComponentWindow) -> KeyEventResult {
@@ -422,6 +684,630 @@ impl ItemConsts for TouchArea {
     pub static TouchAreaVTable for TouchArea
 }
 
+/// The implementation of the `PinchArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, BuiltinItem)]
+#[pin]
+pub struct PinchArea {
+    pub x: Property<f32>,
+    pub y: Property<f32>,
+    pub width: Property<f32>,
+    pub height: Property<f32>,
+    pub enabled: Property<bool>,
+    /// FIXME: We should anotate this as an "output" property.
+    pub active: Property<bool>,
+    /// The scale factor since the beginning of the gesture
+    pub scale: Property<f32>,
+    /// The rotation since the beginning of the gesture, in degrees
+    pub rotation: Property<f32>,
+    /// The translation of the center between the fingers since the beginning of the gesture
+    pub translation_x: Property<f32>,
+    pub translation_y: Property<f32>,
+    pub started: Callback<VoidArg>,
+    pub updated: Callback<VoidArg>,
+    pub finished: Callback<VoidArg>,
+    pub cancelled: Callback<VoidArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl PinchArea {
+    pub(crate) fn gesture_started(self: Pin<&Self>) {
+        Self::FIELD_OFFSETS.scale.apply_pin(self).set(1.);
+        Self::FIELD_OFFSETS.rotation.apply_pin(self).set(0.);
+        Self::FIELD_OFFSETS.translation_x.apply_pin(self).set(0.);
+        Self::FIELD_OFFSETS.translation_y.apply_pin(self).set(0.);
+        Self::FIELD_OFFSETS.active.apply_pin(self).set(true);
+        Self::FIELD_OFFSETS.started.apply_pin(self).emit(&());
+    }
+
+    pub(crate) fn gesture_updated(
+        self: Pin<&Self>,
+        scale: f32,
+        rotation: f32,
+        translation: euclid::default::Vector2D<f32>,
+    ) {
+        Self::FIELD_OFFSETS.scale.apply_pin(self).set(scale);
+        Self::FIELD_OFFSETS.rotation.apply_pin(self).set(rotation);
+        Self::FIELD_OFFSETS.translation_x.apply_pin(self).set(translation.x);
+        Self::FIELD_OFFSETS.translation_y.apply_pin(self).set(translation.y);
+        Self::FIELD_OFFSETS.updated.apply_pin(self).emit(&());
+    }
+
+    pub(crate) fn gesture_finished(self: Pin<&Self>, cancelled: bool) {
+        Self::FIELD_OFFSETS.active.apply_pin(self).set(false);
+        if cancelled {
+            Self::FIELD_OFFSETS.cancelled.apply_pin(self).emit(&());
+        } else {
+            Self::FIELD_OFFSETS.finished.apply_pin(self).emit(&());
+        }
+    }
+}
+
+impl Item for PinchArea {
+    fn init(self: Pin<&Self>, _window: &ComponentWindow) {}
+
+    fn geometry(self: Pin<&Self>) -> Rect {
+        euclid::rect(
+            Self::FIELD_OFFSETS.x.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.y.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.width.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.height.apply_pin(self).get(),
+        )
+    }
+    fn rendering_primitive(
+        self: Pin<&Self>,
+        _window: &ComponentWindow,
+    ) -> HighLevelRenderingPrimitive {
+        HighLevelRenderingPrimitive::NoContents
+    }
+
+    fn rendering_variables(self: Pin<&Self>, _window: &ComponentWindow) -> RenderingVariables {
+        RenderingVariables::default()
+    }
+
+    fn layouting_info(self: Pin<&Self>, _window: &ComponentWindow) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window: &ComponentWindow,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        // The gestures are handled by crate::input::process_touch_input, the mouse
+        // events go through to the children
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &ComponentWindow) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &ComponentWindow) {}
+}
+
+impl ItemConsts for PinchArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        PinchArea,
+        CachedRenderingData,
+    > = PinchArea::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+ItemVTable_static! {
+    /// The VTable for `PinchArea`
+    #[no_mangle]
+    pub static PinchAreaVTable for PinchArea
+}
+
+/// The payload of a drag and drop operation, passed to the callbacks of the `DropArea`
+#[derive(Clone, Debug, PartialEq, Default)]
+#[repr(C)]
+pub struct DropEvent {
+    /// The data of the drag
+    pub data: SharedString,
+    /// The mime type of the data
+    pub mime_type: SharedString,
+}
+
+/// The argument type of the `DropArea` callbacks.
+/// (Renamed to `DropEvent` for C++, which has the same layout as this one-element tuple)
+pub type DropEventArg = (DropEvent,);
+
+/// The mime type of the DropEvent for files dropped from the desktop. The data is the path of the file
+pub const DROPPED_FILE_MIME_TYPE: &str = "application/x-sixtyfps-dropped-file";
+
+/// Distance, in pixels, the mouse needs to move while pressed before a drag starts
+pub const DRAG_THRESHOLD: f32 = 8.;
+
+/// The implementation of the `DragArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, BuiltinItem)]
+#[pin]
+pub struct DragArea {
+    pub x: Property<f32>,
+    pub y: Property<f32>,
+    pub width: Property<f32>,
+    pub height: Property<f32>,
+    pub enabled: Property<bool>,
+    pub mime_type: Property<SharedString>,
+    pub data: Property<SharedString>,
+    /// FIXME: We should anotate this as an "output" property.
+    pub dragging: Property<bool>,
+    pub drag_started: Callback<VoidArg>,
+    pressed: Property<bool>,
+    pressed_x: Property<f32>,
+    pressed_y: Property<f32>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl DragArea {
+    pub(crate) fn drop_event(self: Pin<&Self>) -> DropEvent {
+        DropEvent {
+            data: Self::FIELD_OFFSETS.data.apply_pin(self).get(),
+            mime_type: Self::FIELD_OFFSETS.mime_type.apply_pin(self).get(),
+        }
+    }
+}
+
+impl Item for DragArea {
+    fn init(self: Pin<&Self>, _window: &ComponentWindow) {}
+
+    fn geometry(self: Pin<&Self>) -> Rect {
+        euclid::rect(
+            Self::FIELD_OFFSETS.x.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.y.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.width.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.height.apply_pin(self).get(),
+        )
+    }
+    fn rendering_primitive(
+        self: Pin<&Self>,
+        _window: &ComponentWindow,
+    ) -> HighLevelRenderingPrimitive {
+        HighLevelRenderingPrimitive::NoContents
+    }
+
+    fn rendering_variables(self: Pin<&Self>, _window: &ComponentWindow) -> RenderingVariables {
+        RenderingVariables::default()
+    }
+
+    fn layouting_info(self: Pin<&Self>, _window: &ComponentWindow) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window: &ComponentWindow,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        if !Self::FIELD_OFFSETS.enabled.apply_pin(self).get() {
+            return InputEventResult::EventIgnored;
+        }
+        let pressed = Self::FIELD_OFFSETS.pressed.apply_pin(self);
+        match event.what {
+            MouseEventType::MousePressed if event.button == PointerEventButton::left => {
+                pressed.set(true);
+                Self::FIELD_OFFSETS.pressed_x.apply_pin(self).set(event.pos.x);
+                Self::FIELD_OFFSETS.pressed_y.apply_pin(self).set(event.pos.y);
+                InputEventResult::GrabMouse
+            }
+            MouseEventType::MouseMoved if pressed.get() => {
+                let dragging = Self::FIELD_OFFSETS.dragging.apply_pin(self);
+                if dragging.get() {
+                    return InputEventResult::GrabMouse;
+                }
+                let pressed_pos = euclid::point2(
+                    Self::FIELD_OFFSETS.pressed_x.apply_pin(self).get(),
+                    Self::FIELD_OFFSETS.pressed_y.apply_pin(self).get(),
+                );
+                if (event.pos - pressed_pos).length() < DRAG_THRESHOLD {
+                    return InputEventResult::GrabMouse;
+                }
+                dragging.set(true);
+                Self::FIELD_OFFSETS.drag_started.apply_pin(self).emit(&());
+                InputEventResult::StartDrag
+            }
+            MouseEventType::MouseReleased | MouseEventType::MouseExit if pressed.get() => {
+                pressed.set(false);
+                Self::FIELD_OFFSETS.dragging.apply_pin(self).set(false);
+                InputEventResult::EventAccepted
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &ComponentWindow) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &ComponentWindow) {}
+}
+
+impl ItemConsts for DragArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        DragArea,
+        CachedRenderingData,
+    > = DragArea::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+ItemVTable_static! {
+    /// The VTable for `DragArea`
+    #[no_mangle]
+    pub static DragAreaVTable for DragArea
+}
+
+/// The implementation of the `DropArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, BuiltinItem)]
+#[pin]
+pub struct DropArea {
+    pub x: Property<f32>,
+    pub y: Property<f32>,
+    pub width: Property<f32>,
+    pub height: Property<f32>,
+    pub enabled: Property<bool>,
+    /// FIXME: We should anotate this as an "output" property.
+    pub contains_drag: Property<bool>,
+    pub drag_entered: Callback<DropEventArg>,
+    pub drag_exited: Callback<VoidArg>,
+    pub dropped: Callback<DropEventArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl DropArea {
+    pub(crate) fn drag_enter(self: Pin<&Self>, event: &DropEvent) {
+        Self::FIELD_OFFSETS.contains_drag.apply_pin(self).set(true);
+        Self::FIELD_OFFSETS.drag_entered.apply_pin(self).emit(&(event.clone(),));
+    }
+
+    pub(crate) fn drag_exit(self: Pin<&Self>) {
+        Self::FIELD_OFFSETS.contains_drag.apply_pin(self).set(false);
+        Self::FIELD_OFFSETS.drag_exited.apply_pin(self).emit(&());
+    }
+
+    pub(crate) fn drop(self: Pin<&Self>, event: &DropEvent) {
+        Self::FIELD_OFFSETS.contains_drag.apply_pin(self).set(false);
+        Self::FIELD_OFFSETS.dropped.apply_pin(self).emit(&(event.clone(),));
+    }
+}
+
+impl Item for DropArea {
+    fn init(self: Pin<&Self>, _window: &ComponentWindow) {}
+
+    fn geometry(self: Pin<&Self>) -> Rect {
+        euclid::rect(
+            Self::FIELD_OFFSETS.x.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.y.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.width.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.height.apply_pin(self).get(),
+        )
+    }
+    fn rendering_primitive(
+        self: Pin<&Self>,
+        _window: &ComponentWindow,
+    ) -> HighLevelRenderingPrimitive {
+        HighLevelRenderingPrimitive::NoContents
+    }
+
+    fn rendering_variables(self: Pin<&Self>, _window: &ComponentWindow) -> RenderingVariables {
+        RenderingVariables::default()
+    }
+
+    fn layouting_info(self: Pin<&Self>, _window: &ComponentWindow) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window: &ComponentWindow,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        // The drag is tracked by crate::input::process_mouse_input
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &ComponentWindow) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &ComponentWindow) {}
+}
+
+impl ItemConsts for DropArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        DropArea,
+        CachedRenderingData,
+    > = DropArea::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+ItemVTable_static! {
+    /// The VTable for `DropArea`
+    #[no_mangle]
+    pub static DropAreaVTable for DropArea
+}
+
+/// The state of the keyboard modifiers, as seen by the `.60` code
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+#[repr(C)]
+pub struct KeyboardModifierState {
+    /// The alt key is pressed
+    pub alt: bool,
+    /// The control key is pressed
+    pub control: bool,
+    /// The command key on macOS, or the windows key on Windows, is pressed
+    pub logo: bool,
+    /// The shift key is pressed
+    pub shift: bool,
+}
+
+impl From<KeyboardModifiers> for KeyboardModifierState {
+    fn from(modifiers: KeyboardModifiers) -> Self {
+        Self {
+            alt: modifiers.alt(),
+            control: modifiers.control(),
+            logo: modifiers.logo(),
+            shift: modifiers.shift(),
+        }
+    }
+}
+
+/// The key event passed to the callbacks of the `FocusScope`
+/// (This is called `KeyEvent` in the `.60` language)
+#[derive(Clone, Debug, PartialEq, Default)]
+#[repr(C)]
+pub struct KeyboardEvent {
+    /// The name of the key, such as "A", "Escape" or "Up". Empty for text input
+    pub key: SharedString,
+    /// The keyboard modifiers active at the time of the event
+    pub modifiers: KeyboardModifierState,
+    /// The text produced by the key, if any
+    pub text: SharedString,
+}
+
+impl From<&KeyEvent> for KeyboardEvent {
+    fn from(event: &KeyEvent) -> Self {
+        match event {
+            KeyEvent::KeyPressed { code, modifiers }
+            | KeyEvent::KeyReleased { code, modifiers } => Self {
+                key: format!("{:?}", code).as_str().into(),
+                modifiers: (*modifiers).into(),
+                text: Default::default(),
+            },
+            KeyEvent::CharacterInput { unicode_scalar, modifiers } => Self {
+                key: Default::default(),
+                modifiers: (*modifiers).into(),
+                text: core::char::from_u32(*unicode_scalar)
+                    .map(|ch| SharedString::from(ch.to_string().as_str()))
+                    .unwrap_or_default(),
+            },
+        }
+    }
+}
+
+/// The argument type of the `FocusScope` callbacks.
+/// (Renamed to `KeyboardEvent` for C++, which has the same layout as this one-element tuple)
+pub type KeyboardEventArg = (KeyboardEvent,);
+
+/// The implementation of the `FocusScope` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, BuiltinItem)]
+#[pin]
+pub struct FocusScope {
+    pub x: Property<f32>,
+    pub y: Property<f32>,
+    pub width: Property<f32>,
+    pub height: Property<f32>,
+    pub enabled: Property<bool>,
+    /// FIXME: We should anotate this as an "output" property.
+    pub has_focus: Property<bool>,
+    pub tab_index: Property<i32>,
+    pub key_pressed: Callback<KeyboardEventArg, bool>,
+    pub key_released: Callback<KeyboardEventArg, bool>,
+    pub accessible_role: Property<AccessibleRole>,
+    pub accessible_label: Property<SharedString>,
+    pub accessible_description: Property<SharedString>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for FocusScope {
+    fn init(self: Pin<&Self>, _window: &ComponentWindow) {}
+
+    fn geometry(self: Pin<&Self>) -> Rect {
+        euclid::rect(
+            Self::FIELD_OFFSETS.x.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.y.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.width.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.height.apply_pin(self).get(),
+        )
+    }
+    fn rendering_primitive(
+        self: Pin<&Self>,
+        _window: &ComponentWindow,
+    ) -> HighLevelRenderingPrimitive {
+        HighLevelRenderingPrimitive::NoContents
+    }
+
+    fn rendering_variables(self: Pin<&Self>, _window: &ComponentWindow) -> RenderingVariables {
+        RenderingVariables::default()
+    }
+
+    fn layouting_info(self: Pin<&Self>, _window: &ComponentWindow) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window: &ComponentWindow,
+        self_rc: &ItemRc,
+    ) -> InputEventResult {
+        if !Self::FIELD_OFFSETS.enabled.apply_pin(self).get() {
+            return InputEventResult::EventIgnored;
+        }
+        if event.what == MouseEventType::MousePressed
+            && !Self::FIELD_OFFSETS.has_focus.apply_pin(self).get()
+        {
+            window.set_focus_item(self_rc);
+        }
+        // Let the press go through to the items below, such as the widget this scope belongs to
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(self: Pin<&Self>, event: &KeyEvent, _window: &ComponentWindow) -> KeyEventResult {
+        if !Self::FIELD_OFFSETS.enabled.apply_pin(self).get() {
+            return KeyEventResult::EventIgnored;
+        }
+        let callback = match event {
+            KeyEvent::KeyReleased { .. } => Self::FIELD_OFFSETS.key_released.apply_pin(self),
+            KeyEvent::CharacterInput { unicode_scalar, .. }
+                if core::char::from_u32(*unicode_scalar).map_or(true, |ch| ch.is_control()) =>
+            {
+                // The control characters were already seen as key presses
+                return KeyEventResult::EventIgnored;
+            }
+            KeyEvent::KeyPressed { .. } | KeyEvent::CharacterInput { .. } => {
+                Self::FIELD_OFFSETS.key_pressed.apply_pin(self)
+            }
+        };
+        if callback.emit(&(event.into(),)) {
+            KeyEventResult::EventAccepted
+        } else {
+            KeyEventResult::EventIgnored
+        }
+    }
+
+    fn focus_event(self: Pin<&Self>, event: &FocusEvent, _window: &ComponentWindow) {
+        let has_focus = match event {
+            FocusEvent::FocusIn | FocusEvent::WindowReceivedFocus => true,
+            FocusEvent::FocusOut | FocusEvent::WindowLostFocus => false,
+        };
+        Self::FIELD_OFFSETS.has_focus.apply_pin(self).set(has_focus);
+    }
+}
+
+impl ItemConsts for FocusScope {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        FocusScope,
+        CachedRenderingData,
+    > = FocusScope::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+ItemVTable_static! {
+    /// The VTable for `FocusScope`
+    #[no_mangle]
+    pub static FocusScopeVTable for FocusScope
+}
+
+/// The implementation of the `Shortcut` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, BuiltinItem)]
+#[pin]
+pub struct Shortcut {
+    pub x: Property<f32>,
+    pub y: Property<f32>,
+    pub width: Property<f32>,
+    pub height: Property<f32>,
+    pub keys: Property<SharedString>,
+    pub enabled: Property<bool>,
+    pub activated: Callback<VoidArg>,
+    data: ShortcutDataBox,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Shortcut {
+    /// Returns true if the shortcut is enabled and its keys are `code` with exactly the `modifiers`
+    pub(crate) fn matches(
+        self: Pin<&Self>,
+        code: crate::input::KeyCode,
+        modifiers: KeyboardModifiers,
+    ) -> bool {
+        if !Self::FIELD_OFFSETS.enabled.apply_pin(self).get() {
+            return false;
+        }
+        let keys = Self::FIELD_OFFSETS.keys.apply_pin(self).get();
+        let mut parsed_keys = self.data.parsed_keys.borrow_mut();
+        if parsed_keys.0 != keys {
+            let key_sequence = crate::input::parse_key_sequence(keys.as_str());
+            *parsed_keys = (keys, key_sequence);
+        }
+        parsed_keys.1 == Some((code, modifiers))
+    }
+
+    pub(crate) fn activate(self: Pin<&Self>) {
+        Self::FIELD_OFFSETS.activated.apply_pin(self).emit(&());
+    }
+}
+
+impl Item for Shortcut {
+    fn init(self: Pin<&Self>, _window: &ComponentWindow) {}
+
+    fn geometry(self: Pin<&Self>) -> Rect {
+        euclid::rect(
+            Self::FIELD_OFFSETS.x.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.y.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.width.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.height.apply_pin(self).get(),
+        )
+    }
+    fn rendering_primitive(
+        self: Pin<&Self>,
+        _window: &ComponentWindow,
+    ) -> HighLevelRenderingPrimitive {
+        HighLevelRenderingPrimitive::NoContents
+    }
+
+    fn rendering_variables(self: Pin<&Self>, _window: &ComponentWindow) -> RenderingVariables {
+        RenderingVariables::default()
+    }
+
+    fn layouting_info(self: Pin<&Self>, _window: &ComponentWindow) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window: &ComponentWindow,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &ComponentWindow) -> KeyEventResult {
+        // The shortcuts are handled by crate::input::process_shortcuts
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &ComponentWindow) {}
+}
+
+impl ItemConsts for Shortcut {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        Shortcut,
+        CachedRenderingData,
+    > = Shortcut::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+ItemVTable_static! {
+    /// The VTable for `Shortcut`
+    #[no_mangle]
+    pub static ShortcutVTable for Shortcut
+}
+
+ItemVTable_static! {
+    /// The VTable for `Timer`
+    #[no_mangle]
+    pub static TimerItemVTable for TimerItem
+}
+
 #[repr(C)]
 #[derive(FieldOffsets, Default, BuiltinItem)]
 #[pin]
@@ -686,9 +1572,52 @@ fn deref(&self) -> &Self::Target {
     std::ptr::read(data);
 }
 
+/// The internal data of the `Shortcut` element
+#[derive(Default)]
+pub struct ShortcutData {
+    /// The `keys` that were parsed last, with the result of the parsing, so the keys are not
+    /// parsed again for every key press
+    parsed_keys:
+        core::cell::RefCell<(SharedString, Option<(crate::input::KeyCode, KeyboardModifiers)>)>,
+}
+
+#[repr(C)]
+/// Wraps the internal datastructure for the Shortcut
+pub struct ShortcutDataBox(core::ptr::NonNull<ShortcutData>);
+
+impl Default for ShortcutDataBox {
+    fn default() -> Self {
+        ShortcutDataBox(Box::leak(Box::new(ShortcutData::default())).into())
+    }
+}
+impl Drop for ShortcutDataBox {
+    fn drop(&mut self) {
+        // Safety: the self.0 was constructed from a Box::leak in ShortcutDataBox::default
+        unsafe {
+            Box::from_raw(self.0.as_ptr());
+        }
+    }
+}
+impl core::ops::Deref for ShortcutDataBox {
+    type Target = ShortcutData;
+    fn deref(&self) -> &Self::Target {
+        // Safety: initialized in ShortcutDataBox::default
+        unsafe { self.0.as_ref() }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sixtyfps_shortcut_data_init(data: *mut ShortcutDataBox) {
+    std::ptr::write(data, ShortcutDataBox::default());
+}
+#[no_mangle]
+pub unsafe extern "C" fn sixtyfps_shortcut_data_free(data: *mut ShortcutDataBox) {
+    std::ptr::read(data);
+}
+
 /// The implementation of the `PropertyAnimation` element
 #[repr(C)]
-#[derive(FieldOffsets, Default, BuiltinItem, Clone, Debug)]
+#[derive(FieldOffsets, BuiltinItem, Clone, Debug)]
 #[pin]
 pub struct PropertyAnimation {
     #[rtti_field]
@@ -697,6 +1626,43 @@ pub struct PropertyAnimation {
     pub loop_count: i32,
     #[rtti_field]
     pub easing: crate::animations::EasingCurve,
+    /// When greater than zero, the animation is driven by a spring with this stiffness
+    /// instead of the duration and easing curve
+    #[rtti_field]
+    pub stiffness: f32,
+    #[rtti_field]
+    pub damping: f32,
+    #[rtti_field]
+    pub mass: f32,
+    /// The time in milliseconds to wait before the animation starts
+    #[rtti_field]
+    pub delay: i32,
+    /// When true, every other loop runs backward
+    #[rtti_field]
+    pub alternate: bool,
+    /// Intermediate values, sorted by position
+    #[rtti_field]
+    pub keyframes: crate::SharedVector<crate::animations::KeyFrame>,
+    /// When false, the animation is paused
+    #[rtti_field]
+    pub running: bool,
+}
+
+impl Default for PropertyAnimation {
+    fn default() -> Self {
+        Self {
+            duration: 0,
+            loop_count: 0,
+            easing: Default::default(),
+            stiffness: 0.,
+            damping: 0.,
+            mass: 0.,
+            delay: 0,
+            alternate: false,
+            keyframes: Default::default(),
+            running: true,
+        }
+    }
 }
 
 /// The implementation of the `Window` element
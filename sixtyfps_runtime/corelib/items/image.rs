@@ -19,7 +19,7 @@
  - For the C++ code (new item only): the cbindgen.rs to export the new item, and the `using` declaration in sixtyfps.h
  - Don't forget to update the documentation
 */
-use super::{Item, ItemConsts, ItemRc};
+use super::{AccessibleRole, Item, ItemConsts, ItemRc};
 use crate::eventloop::ComponentWindow;
 use crate::graphics::{HighLevelRenderingPrimitive, IntRect, Rect, RenderingVariables, Resource};
 use crate::input::{FocusEvent, InputEventResult, KeyEvent, KeyEventResult, MouseEvent};
@@ -27,9 +27,7 @@
 use crate::layout::LayoutInfo;
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
-#[cfg(feature = "rtti")]
-use crate::Callback;
-use crate::Property;
+use crate::{Property, SharedString};
 use const_field_offset::FieldOffsets;
 use core::pin::Pin;
 use sixtyfps_corelib_macros::*;
@@ -59,6 +57,9 @@ pub struct Image {
     pub width: Property<f32>,
     pub height: Property<f32>,
     pub image_fit: Property<ImageFit>,
+    pub accessible_role: Property<AccessibleRole>,
+    pub accessible_label: Property<SharedString>,
+    pub accessible_description: Property<SharedString>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -134,6 +135,9 @@ pub struct ClippedImage {
     pub source_clip_y: Property<i32>,
     pub source_clip_width: Property<i32>,
     pub source_clip_height: Property<i32>,
+    pub accessible_role: Property<AccessibleRole>,
+    pub accessible_label: Property<SharedString>,
+    pub accessible_description: Property<SharedString>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
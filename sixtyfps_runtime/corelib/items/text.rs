@@ -20,7 +20,7 @@
  - Don't forget to update the documentation
 */
 
-use super::{Item, ItemConsts, ItemRc};
+use super::{AccessibleRole, Item, ItemConsts, ItemRc, VoidArg};
 use crate::eventloop::ComponentWindow;
 use crate::font::HasFont;
 use crate::graphics::{Color, HighLevelRenderingPrimitive, Point, Rect, RenderingVariables};
@@ -86,6 +86,9 @@ pub struct Text {
     pub y: Property<f32>,
     pub width: Property<f32>,
     pub height: Property<f32>,
+    pub accessible_role: Property<AccessibleRole>,
+    pub accessible_label: Property<SharedString>,
+    pub accessible_description: Property<SharedString>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -217,9 +220,13 @@ pub struct TextInput {
     pub cursor_visible: Property<bool>,
     pub has_focus: Property<bool>,
     pub enabled: Property<bool>,
-    pub accepted: Callback<()>,
-    pub edited: Callback<()>,
+    pub tab_index: Property<i32>,
+    pub accepted: Callback<VoidArg>,
+    pub edited: Callback<VoidArg>,
     pub pressed: std::cell::Cell<bool>,
+    pub accessible_role: Property<AccessibleRole>,
+    pub accessible_label: Property<SharedString>,
+    pub accessible_description: Property<SharedString>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -360,6 +367,12 @@ fn key_event(self: Pin<&Self>, event: &KeyEvent, window: &ComponentWindow) -> Ke
         }
 
         match event {
+            KeyEvent::CharacterInput { unicode_scalar, .. }
+                if char::try_from(*unicode_scalar).map_or(true, |ch| ch.is_control()) =>
+            {
+                // Tab, Return, Escape and other control characters are handled as key presses
+                KeyEventResult::EventIgnored
+            }
             KeyEvent::CharacterInput { unicode_scalar, .. } => {
                 self.delete_selection();
 
@@ -0,0 +1,181 @@
+/* LICENSE BEGIN
+    This file is part of the SixtyFPS Project -- https://sixtyfps.io
+    Copyright (c) 2020 Olivier Goffart <olivier.goffart@sixtyfps.io>
+    Copyright (c) 2020 Simon Hausmann <simon.hausmann@sixtyfps.io>
+
+    SPDX-License-Identifier: GPL-3.0-only
+    This file is also available under commercial licensing terms.
+    Please contact info@sixtyfps.io for more information.
+LICENSE END */
+/*!
+This module contains the builtin `Timer` item.
+
+When adding an item or a property, it needs to be kept in sync with different place.
+(This is less than ideal and maybe we can have some automation later)
+
+ - It needs to be changed in this module
+ - In the compiler: builtins.60
+ - In the interpreter: dynamic_component.rs
+ - For the C++ code (new item only): the cbindgen.rs to export the new item, and the `using` declaration in sixtyfps.h
+ - Don't forget to update the documentation
+*/
+
+use super::{Item, ItemConsts, ItemRc, VoidArg};
+use crate::eventloop::ComponentWindow;
+use crate::graphics::{HighLevelRenderingPrimitive, Rect, RenderingVariables};
+use crate::input::{FocusEvent, InputEventResult, KeyEvent, KeyEventResult, MouseEvent};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::LayoutInfo;
+use crate::properties::ChangeTracker;
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::timers::{Timer, TimerMode};
+use crate::{Callback, Property};
+use const_field_offset::FieldOffsets;
+use core::pin::Pin;
+use sixtyfps_corelib_macros::*;
+
+/// The implementation of the `Timer` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, BuiltinItem)]
+#[pin]
+pub struct TimerItem {
+    pub interval: Property<i64>,
+    pub running: Property<bool>,
+    pub repeat: Property<bool>,
+    pub triggered: Callback<VoidArg>,
+    data: TimerItemDataBox,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl TimerItem {
+    /// Start, restart or stop the timer according to the current value of the properties.
+    fn update_timer(self: Pin<&Self>) {
+        let interval = Self::FIELD_OFFSETS.interval.apply_pin(self).get_untracked();
+        let running = Self::FIELD_OFFSETS.running.apply_pin(self).get_untracked();
+        let repeat = Self::FIELD_OFFSETS.repeat.apply_pin(self).get_untracked();
+        if !running || interval <= 0 {
+            self.data.timer.stop();
+            return;
+        }
+        let mode = if repeat { TimerMode::Repeated } else { TimerMode::SingleShot };
+        let self_ptr = self.get_ref() as *const Self;
+        self.data.timer.start(
+            mode,
+            core::time::Duration::from_millis(interval as u64),
+            move || {
+                // Safety: the timer is owned by the item and is stopped when the item is dropped
+                let self_ = unsafe { Pin::new_unchecked(&*self_ptr) };
+                if !repeat {
+                    Self::FIELD_OFFSETS.running.apply_pin(self_).set(false);
+                }
+                // The item may be destroyed by the handler, so it must not be accessed after this.
+                Self::FIELD_OFFSETS.triggered.apply_pin(self_).emit(&());
+            },
+        );
+    }
+}
+
+impl Item for TimerItem {
+    fn init(self: Pin<&Self>, _window: &ComponentWindow) {
+        let self_ptr = self.get_ref() as *const Self;
+        // Safety: the tracker is owned by the item and is dropped with it
+        let self_ = move || unsafe { Pin::new_unchecked(&*self_ptr) };
+        self.data.tracker.init(
+            move || {
+                let self_ = self_();
+                (
+                    Self::FIELD_OFFSETS.interval.apply_pin(self_).get(),
+                    Self::FIELD_OFFSETS.running.apply_pin(self_).get(),
+                    Self::FIELD_OFFSETS.repeat.apply_pin(self_).get(),
+                )
+            },
+            move || self_().update_timer(),
+        );
+        self.update_timer();
+    }
+
+    fn geometry(self: Pin<&Self>) -> Rect {
+        Rect::default()
+    }
+
+    fn rendering_primitive(
+        self: Pin<&Self>,
+        _window: &ComponentWindow,
+    ) -> HighLevelRenderingPrimitive {
+        HighLevelRenderingPrimitive::NoContents
+    }
+
+    fn rendering_variables(self: Pin<&Self>, _window: &ComponentWindow) -> RenderingVariables {
+        RenderingVariables::default()
+    }
+
+    fn layouting_info(self: Pin<&Self>, _window: &ComponentWindow) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window: &ComponentWindow,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &ComponentWindow) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &ComponentWindow) {}
+}
+
+impl ItemConsts for TimerItem {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        TimerItem,
+        CachedRenderingData,
+    > = TimerItem::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+/// The internal data of the `Timer` element
+#[derive(Default)]
+pub struct TimerItemData {
+    timer: Timer,
+    /// Restarts the timer when one of the properties changes
+    tracker: ChangeTracker,
+}
+
+#[repr(C)]
+/// Wraps the internal datastructure for the Timer
+pub struct TimerItemDataBox(core::ptr::NonNull<TimerItemData>);
+
+impl Default for TimerItemDataBox {
+    fn default() -> Self {
+        TimerItemDataBox(Box::leak(Box::new(TimerItemData::default())).into())
+    }
+}
+impl Drop for TimerItemDataBox {
+    fn drop(&mut self) {
+        // Safety: the self.0 was constructed from a Box::leak in TimerItemDataBox::default
+        unsafe {
+            Box::from_raw(self.0.as_ptr());
+        }
+    }
+}
+impl core::ops::Deref for TimerItemDataBox {
+    type Target = TimerItemData;
+    fn deref(&self) -> &Self::Target {
+        // Safety: initialized in TimerItemDataBox::default
+        unsafe { self.0.as_ref() }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sixtyfps_timer_item_data_init(data: *mut TimerItemDataBox) {
+    std::ptr::write(data, TimerItemDataBox::default());
+}
+#[no_mangle]
+pub unsafe extern "C" fn sixtyfps_timer_item_data_free(data: *mut TimerItemDataBox) {
+    std::ptr::read(data);
+}
@@ -18,6 +18,7 @@
 
 #![deny(unsafe_code)]
 
+pub mod accessibility;
 /// The animation system
 pub mod animations;
 pub(crate) mod flickable;
@@ -68,6 +69,7 @@
 pub mod item_rendering;
 pub mod tests;
 pub mod timers;
+pub mod translations;
 
 /// One need to use at least one function in each module in order to get them
 /// exported in the final binary.
@@ -86,4 +88,6 @@ pub fn use_modules() -> usize {
         + eventloop::ffi::sixtyfps_component_window_drop as usize
         + component::ffi::sixtyfps_component_init_items as usize
         + timers::ffi::sixtyfps_timer_start as usize
+        + translations::ffi::sixtyfps_translate as usize
+        + animations::ffi::sixtyfps_set_animation_speed as usize
 }
@@ -31,26 +31,37 @@ pub struct ModelPeer {
 
 /// Dispatch notifications from a [`Model`] to one or several [`ModelPeer`].
 /// Typically, you would want to put this in the implementaiton of the Model
-#[derive(Default)]
 pub struct ModelNotify {
     inner: RefCell<weak_table::PtrWeakHashSet<Weak<RefCell<ModelPeerInner>>>>,
+    /// Bumped on every change so that bindings reading the model through
+    /// [`Model::track_changes`] get re-evaluated
+    tracker: Pin<Box<Property<usize>>>,
+}
+
+impl Default for ModelNotify {
+    fn default() -> Self {
+        Self { inner: Default::default(), tracker: Box::pin(Property::new(0)) }
+    }
 }
 
 impl ModelNotify {
     /// Notify the peers that a specific row was changed
     pub fn row_changed(&self, row: usize) {
+        self.mark_dirty();
         for peer in self.inner.borrow().iter() {
             peer.borrow_mut().row_changed(row)
         }
     }
     /// Notify the peers that rows were added
     pub fn row_added(&self, index: usize, count: usize) {
+        self.mark_dirty();
         for peer in self.inner.borrow().iter() {
             peer.borrow_mut().row_added(index, count)
         }
     }
     /// Notify the peers that rows were removed
     pub fn row_removed(&self, index: usize, count: usize) {
+        self.mark_dirty();
         for peer in self.inner.borrow().iter() {
             peer.borrow_mut().row_removed(index, count)
         }
@@ -59,6 +70,15 @@ pub fn row_removed(&self, index: usize, count: usize) {
     pub fn attach(&self, peer: ModelPeer) {
         peer.inner.upgrade().map(|rc| self.inner.borrow_mut().insert(rc));
     }
+    /// Register a dependency to this model in the currently evaluating binding, so that
+    /// the binding is re-evaluated when a row is changed, added or removed.
+    pub fn track_changes(&self) {
+        self.tracker.as_ref().get();
+    }
+    fn mark_dirty(&self) {
+        let tracker = self.tracker.as_ref();
+        tracker.set(tracker.get_untracked().wrapping_add(1));
+    }
 }
 
 /// A Model is providing Data for the Repeater or ListView elements of the `.60` language
@@ -75,6 +95,10 @@ pub trait Model {
     fn set_row_data(&self, _row: usize, _data: Self::Data) {}
     /// Should forward to the internal [`ModelNotify::attach`]
     fn attach_peer(&self, peer: ModelPeer);
+    /// Register a dependency to this model in the currently evaluating binding.
+    /// Models with an internal [`ModelNotify`] should forward to [`ModelNotify::track_changes`].
+    /// The default implementation does nothing, which is fine for models that never change.
+    fn track_changes(&self) {}
 
     /// Returns an iterator visiting all elements of the model.
     fn iter<'a>(&'a self) -> ModelIterator<'a, Self::Data>
@@ -140,6 +164,104 @@ pub fn remove(&self, index: usize) {
         self.array.borrow_mut().remove(index);
         self.notify.row_removed(index, 1)
     }
+
+    /// Inserts a row at the given index, shifting the rows after it
+    pub fn insert(&self, index: usize, value: T) {
+        self.array.borrow_mut().insert(index, value);
+        self.notify.row_added(index, 1)
+    }
+
+    /// Add the rows of the iterator at the end of the model
+    pub fn extend(&self, iter: impl IntoIterator<Item = T>) {
+        let (old_len, new_len) = {
+            let mut array = self.array.borrow_mut();
+            let old_len = array.len();
+            array.extend(iter);
+            (old_len, array.len())
+        };
+        if new_len > old_len {
+            self.notify.row_added(old_len, new_len - old_len)
+        }
+    }
+
+    /// Remove all the rows from the model
+    pub fn clear(&self) {
+        let len = core::mem::take(&mut *self.array.borrow_mut()).len();
+        if len > 0 {
+            self.notify.row_removed(0, len)
+        }
+    }
+
+    /// Exchange the data of two rows
+    pub fn swap(&self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        self.array.borrow_mut().swap(a, b);
+        self.notify.row_changed(a);
+        self.notify.row_changed(b);
+    }
+
+    /// Move the row at index `from` so that it ends up at index `to`.
+    /// The rows in between are reported as changed, so that the views keep their items.
+    pub fn move_row(&self, from: usize, to: usize) {
+        if from == to {
+            return;
+        }
+        {
+            let mut array = self.array.borrow_mut();
+            if from < to {
+                array[from..=to].rotate_left(1);
+            } else {
+                array[to..=from].rotate_right(1);
+            }
+        }
+        for row in from.min(to)..=from.max(to) {
+            self.notify.row_changed(row);
+        }
+    }
+
+    /// Replace the content of the model.
+    /// The rows that exist in both the old and the new content are reported as changed, and only
+    /// the difference in length is reported as added or removed rows.
+    pub fn set_vec(&self, new: impl Into<Vec<T>>) {
+        let new = new.into();
+        let new_len = new.len();
+        let old_len = core::mem::replace(&mut *self.array.borrow_mut(), new).len();
+        for row in 0..old_len.min(new_len) {
+            self.notify.row_changed(row);
+        }
+        if new_len > old_len {
+            self.notify.row_added(old_len, new_len - old_len);
+        } else if old_len > new_len {
+            self.notify.row_removed(new_len, old_len - new_len);
+        }
+    }
+
+    /// Only keep the rows for which `f` returns true.
+    /// Consecutive removed rows are reported together.
+    pub fn retain(&self, mut f: impl FnMut(&T) -> bool) {
+        // Runs of removed rows, as (index, count) in the model after the previous runs were removed
+        let mut removed: Vec<(usize, usize)> = Vec::new();
+        {
+            let mut array = self.array.borrow_mut();
+            let mut index = 0;
+            array.retain(|value| {
+                let keep = f(value);
+                if keep {
+                    index += 1;
+                } else if let Some(last) = removed.last_mut().filter(|last| last.0 == index) {
+                    last.1 += 1;
+                } else {
+                    removed.push((index, 1));
+                }
+                keep
+            });
+        }
+        for (index, count) in removed {
+            self.notify.row_removed(index, count);
+        }
+    }
 }
 
 impl<T> From<Vec<T>> for VecModel<T> {
@@ -167,6 +289,10 @@ fn set_row_data(&self, row: usize, data: Self::Data) {
     fn attach_peer(&self, peer: ModelPeer) {
         self.notify.attach(peer);
     }
+
+    fn track_changes(&self) {
+        self.notify.track_changes()
+    }
 }
 
 impl Model for usize {
@@ -203,6 +329,865 @@ fn attach_peer(&self, _peer: ModelPeer) {
     }
 }
 
+/// Receives the notifications of the source model of an adapter such as [`FilterModel`]
+trait SourceModelObserver {
+    fn source_row_changed(&self, row: usize);
+    fn source_row_added(&self, index: usize, count: usize);
+    fn source_row_removed(&self, index: usize, count: usize);
+}
+
+/// The peer attached to the source model of an adapter, forwarding the notifications to it
+struct AdapterPeer<A>(Weak<A>);
+
+impl<A: SourceModelObserver> ViewAbstraction for AdapterPeer<A> {
+    fn row_changed(&mut self, row: usize) {
+        if let Some(adapter) = self.0.upgrade() {
+            adapter.source_row_changed(row)
+        }
+    }
+    fn row_added(&mut self, index: usize, count: usize) {
+        if let Some(adapter) = self.0.upgrade() {
+            adapter.source_row_added(index, count)
+        }
+    }
+    fn row_removed(&mut self, index: usize, count: usize) {
+        if let Some(adapter) = self.0.upgrade() {
+            adapter.source_row_removed(index, count)
+        }
+    }
+}
+
+/// Attach a peer to the source model that forwards its notifications to the adapter.
+/// The returned peer must be kept alive as long as the adapter.
+fn attach_adapter<T, A: SourceModelObserver + 'static>(
+    source: &dyn Model<Data = T>,
+    adapter: &Rc<A>,
+) -> Rc<RefCell<ModelPeerInner>> {
+    let peer: Rc<RefCell<ModelPeerInner>> =
+        Rc::new(RefCell::new(AdapterPeer(Rc::downgrade(adapter))));
+    source.attach_peer(ModelPeer { inner: Rc::downgrade(&peer) });
+    peer
+}
+
+/// A model that contains the rows of a source model that are accepted by a filter function.
+///
+/// The rows are in the same order as in the source model. The changes of the source model are
+/// forwarded to the views as the rows that were actually added, removed or changed in this model.
+pub struct FilterModel<T> {
+    inner: Rc<FilterModelInner<T>>,
+    /// Keeps the peer attached to the source model alive
+    _peer: Rc<RefCell<ModelPeerInner>>,
+}
+
+struct FilterModelInner<T> {
+    source: Rc<dyn Model<Data = T>>,
+    filter: Box<dyn Fn(&T) -> bool>,
+    /// The row in the source model of each row of this model, in increasing order
+    mapping: RefCell<Vec<usize>>,
+    notify: ModelNotify,
+}
+
+impl<T: 'static> FilterModel<T> {
+    /// Creates a model with the rows of `source` for which `filter` returns true
+    pub fn new(source: Rc<dyn Model<Data = T>>, filter: impl Fn(&T) -> bool + 'static) -> Self {
+        let inner = Rc::new(FilterModelInner {
+            source,
+            filter: Box::new(filter),
+            mapping: Default::default(),
+            notify: Default::default(),
+        });
+        *inner.mapping.borrow_mut() = inner.accepted_rows();
+        let peer = attach_adapter(&*inner.source, &inner);
+        Self { inner, _peer: peer }
+    }
+
+    /// Applies the filter again on all the rows of the source model.
+    /// Call this when the filter function gives different results, for example because it depends
+    /// on a search string that was changed.
+    pub fn reset(&self) {
+        self.inner.reset()
+    }
+
+    /// Returns the row in the source model that corresponds to the given row of this model
+    pub fn source_row(&self, row: usize) -> usize {
+        self.inner.mapping.borrow()[row]
+    }
+}
+
+impl<T> FilterModelInner<T> {
+    fn accepts(&self, source_row: usize) -> bool {
+        (self.filter)(&self.source.row_data(source_row))
+    }
+
+    fn accepted_rows(&self) -> Vec<usize> {
+        (0..self.source.row_count()).filter(|row| self.accepts(*row)).collect()
+    }
+
+    fn reset(&self) {
+        let new_mapping = self.accepted_rows();
+        // Both mappings are sorted: walk them together, removing or inserting the rows that differ
+        let mut pos = 0;
+        let mut new_pos = 0;
+        loop {
+            let old_row = self.mapping.borrow().get(pos).copied();
+            let new_row = new_mapping.get(new_pos).copied();
+            match (old_row, new_row) {
+                (None, None) => break,
+                (Some(old_row), Some(new_row)) if old_row == new_row => {
+                    pos += 1;
+                    new_pos += 1;
+                }
+                (Some(old_row), _) if new_row.map_or(true, |new_row| old_row < new_row) => {
+                    let count = self.mapping.borrow()[pos..]
+                        .iter()
+                        .take_while(|old_row| new_row.map_or(true, |new_row| **old_row < new_row))
+                        .count();
+                    self.mapping.borrow_mut().drain(pos..pos + count);
+                    self.notify.row_removed(pos, count);
+                }
+                _ => {
+                    let count = new_mapping[new_pos..]
+                        .iter()
+                        .take_while(|new_row| old_row.map_or(true, |old_row| **new_row < old_row))
+                        .count();
+                    self.mapping
+                        .borrow_mut()
+                        .splice(pos..pos, new_mapping[new_pos..new_pos + count].iter().copied());
+                    self.notify.row_added(pos, count);
+                    pos += count;
+                    new_pos += count;
+                }
+            }
+        }
+    }
+}
+
+impl<T> SourceModelObserver for FilterModelInner<T> {
+    fn source_row_changed(&self, row: usize) {
+        let accepted = self.accepts(row);
+        let found = self.mapping.borrow().binary_search(&row);
+        match (found, accepted) {
+            (Ok(pos), true) => self.notify.row_changed(pos),
+            (Ok(pos), false) => {
+                self.mapping.borrow_mut().remove(pos);
+                self.notify.row_removed(pos, 1);
+            }
+            (Err(pos), true) => {
+                self.mapping.borrow_mut().insert(pos, row);
+                self.notify.row_added(pos, 1);
+            }
+            (Err(_), false) => {}
+        }
+    }
+
+    fn source_row_added(&self, index: usize, count: usize) {
+        let pos = {
+            let mut mapping = self.mapping.borrow_mut();
+            let pos = mapping.binary_search(&index).unwrap_or_else(|pos| pos);
+            for row in mapping[pos..].iter_mut() {
+                *row += count;
+            }
+            pos
+        };
+        let added: Vec<usize> = (index..index + count).filter(|row| self.accepts(*row)).collect();
+        if !added.is_empty() {
+            let added_count = added.len();
+            self.mapping.borrow_mut().splice(pos..pos, added);
+            self.notify.row_added(pos, added_count);
+        }
+    }
+
+    fn source_row_removed(&self, index: usize, count: usize) {
+        let (pos, removed_count) = {
+            let mut mapping = self.mapping.borrow_mut();
+            let begin = mapping.binary_search(&index).unwrap_or_else(|pos| pos);
+            let end = mapping.binary_search(&(index + count)).unwrap_or_else(|pos| pos);
+            mapping.drain(begin..end);
+            for row in mapping[begin..].iter_mut() {
+                *row -= count;
+            }
+            (begin, end - begin)
+        };
+        if removed_count > 0 {
+            self.notify.row_removed(pos, removed_count);
+        }
+    }
+}
+
+impl<T: 'static> Model for FilterModel<T> {
+    type Data = T;
+
+    fn row_count(&self) -> usize {
+        self.inner.mapping.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Self::Data {
+        let source_row = self.source_row(row);
+        self.inner.source.row_data(source_row)
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        let source_row = self.source_row(row);
+        self.inner.source.set_row_data(source_row, data)
+    }
+
+    fn attach_peer(&self, peer: ModelPeer) {
+        self.inner.notify.attach(peer);
+    }
+
+    fn track_changes(&self) {
+        self.inner.notify.track_changes()
+    }
+}
+
+/// A model that contains the rows of a source model sorted with a comparison function.
+///
+/// Rows that compare equal keep the order they have in the source model. When a row of the source
+/// model changes, it is moved to its new position.
+pub struct SortModel<T> {
+    inner: Rc<SortModelInner<T>>,
+    /// Keeps the peer attached to the source model alive
+    _peer: Rc<RefCell<ModelPeerInner>>,
+}
+
+struct SortModelInner<T> {
+    source: Rc<dyn Model<Data = T>>,
+    compare: Box<dyn Fn(&T, &T) -> core::cmp::Ordering>,
+    /// The row in the source model of each row of this model
+    mapping: RefCell<Vec<usize>>,
+    notify: ModelNotify,
+}
+
+impl<T: 'static> SortModel<T> {
+    /// Creates a model with the rows of `source` sorted according to `compare`
+    pub fn new(
+        source: Rc<dyn Model<Data = T>>,
+        compare: impl Fn(&T, &T) -> core::cmp::Ordering + 'static,
+    ) -> Self {
+        let inner = Rc::new(SortModelInner {
+            source,
+            compare: Box::new(compare),
+            mapping: Default::default(),
+            notify: Default::default(),
+        });
+        *inner.mapping.borrow_mut() = inner.sorted_rows();
+        let peer = attach_adapter(&*inner.source, &inner);
+        Self { inner, _peer: peer }
+    }
+
+    /// Sorts all the rows again.
+    /// Call this when the comparison function gives different results, for example because the
+    /// sort order was changed.
+    pub fn reset(&self) {
+        let new_mapping = self.inner.sorted_rows();
+        let old_mapping = self.inner.mapping.replace(new_mapping.clone());
+        for (row, (old, new)) in old_mapping.iter().zip(new_mapping.iter()).enumerate() {
+            if old != new {
+                self.inner.notify.row_changed(row);
+            }
+        }
+    }
+
+    /// Returns the row in the source model that corresponds to the given row of this model
+    pub fn source_row(&self, row: usize) -> usize {
+        self.inner.mapping.borrow()[row]
+    }
+}
+
+impl<T> SortModelInner<T> {
+    fn sorted_rows(&self) -> Vec<usize> {
+        let data: Vec<T> =
+            (0..self.source.row_count()).map(|row| self.source.row_data(row)).collect();
+        let mut rows: Vec<usize> = (0..data.len()).collect();
+        // The sort is stable, so equal rows stay in the order of the source model
+        rows.sort_by(|a, b| (self.compare)(&data[*a], &data[*b]));
+        rows
+    }
+
+    /// Returns the position at which the source row with the given data needs to be inserted
+    fn insert_position(&self, source_row: usize, data: &T) -> usize {
+        let mapping = self.mapping.borrow();
+        let (mut begin, mut end) = (0, mapping.len());
+        while begin < end {
+            let middle = (begin + end) / 2;
+            let other_row = mapping[middle];
+            let ordering = (self.compare)(&self.source.row_data(other_row), data)
+                .then(other_row.cmp(&source_row));
+            if ordering == core::cmp::Ordering::Less {
+                begin = middle + 1;
+            } else {
+                end = middle;
+            }
+        }
+        begin
+    }
+}
+
+impl<T> SourceModelObserver for SortModelInner<T> {
+    fn source_row_changed(&self, row: usize) {
+        let old_pos = match self.mapping.borrow().iter().position(|r| *r == row) {
+            Some(pos) => pos,
+            None => return,
+        };
+        self.mapping.borrow_mut().remove(old_pos);
+        let new_pos = self.insert_position(row, &self.source.row_data(row));
+        if new_pos == old_pos {
+            self.mapping.borrow_mut().insert(new_pos, row);
+            self.notify.row_changed(new_pos);
+        } else {
+            self.notify.row_removed(old_pos, 1);
+            self.mapping.borrow_mut().insert(new_pos, row);
+            self.notify.row_added(new_pos, 1);
+        }
+    }
+
+    fn source_row_added(&self, index: usize, count: usize) {
+        for row in self.mapping.borrow_mut().iter_mut() {
+            if *row >= index {
+                *row += count;
+            }
+        }
+        for row in index..index + count {
+            let pos = self.insert_position(row, &self.source.row_data(row));
+            self.mapping.borrow_mut().insert(pos, row);
+            self.notify.row_added(pos, 1);
+        }
+    }
+
+    fn source_row_removed(&self, index: usize, count: usize) {
+        let removed: Vec<usize> = {
+            let mut mapping = self.mapping.borrow_mut();
+            let removed = mapping
+                .iter()
+                .enumerate()
+                .filter(|(_, row)| (index..index + count).contains(*row))
+                .map(|(pos, _)| pos)
+                .collect();
+            for row in mapping.iter_mut() {
+                if *row >= index + count {
+                    *row -= count;
+                }
+            }
+            removed
+        };
+        // Remove the consecutive rows together, starting from the end so the positions stay valid
+        let mut positions = removed.into_iter().rev().peekable();
+        while let Some(last) = positions.next() {
+            let mut first = last;
+            while first > 0 && positions.peek() == Some(&(first - 1)) {
+                first -= 1;
+                positions.next();
+            }
+            self.mapping.borrow_mut().drain(first..=last);
+            self.notify.row_removed(first, last - first + 1);
+        }
+    }
+}
+
+impl<T: 'static> Model for SortModel<T> {
+    type Data = T;
+
+    fn row_count(&self) -> usize {
+        self.inner.mapping.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Self::Data {
+        let source_row = self.source_row(row);
+        self.inner.source.row_data(source_row)
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        let source_row = self.source_row(row);
+        self.inner.source.set_row_data(source_row, data)
+    }
+
+    fn attach_peer(&self, peer: ModelPeer) {
+        self.inner.notify.attach(peer);
+    }
+
+    fn track_changes(&self) {
+        self.inner.notify.track_changes()
+    }
+}
+
+/// A model whose rows are the rows of a source model converted with a mapping function.
+///
+/// The mapping function is called each time the data of a row is queried.
+pub struct MapModel<T, U> {
+    source: Rc<dyn Model<Data = T>>,
+    map: Box<dyn Fn(T) -> U>,
+}
+
+impl<T, U> MapModel<T, U> {
+    /// Creates a model whose rows are the rows of `source` converted by `map`
+    pub fn new(source: Rc<dyn Model<Data = T>>, map: impl Fn(T) -> U + 'static) -> Self {
+        Self { source, map: Box::new(map) }
+    }
+}
+
+impl<T, U> Model for MapModel<T, U> {
+    type Data = U;
+
+    fn row_count(&self) -> usize {
+        self.source.row_count()
+    }
+
+    fn row_data(&self, row: usize) -> Self::Data {
+        (self.map)(self.source.row_data(row))
+    }
+
+    fn attach_peer(&self, peer: ModelPeer) {
+        // The rows are the same as in the source model, so are the notifications
+        self.source.attach_peer(peer)
+    }
+
+    fn track_changes(&self) {
+        self.source.track_changes()
+    }
+}
+
+/// Identifies a node of a [`TreeModel`] by its row and the rows of its ancestors, starting from
+/// the top level. The root index, which is the parent of the top level nodes, has no row.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TreeIndex(Vec<usize>);
+
+impl TreeIndex {
+    /// The index of the invisible root node, whose children are the top level nodes
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    /// Returns the index of the child at the given row of this node
+    pub fn child(&self, row: usize) -> Self {
+        let mut rows = self.0.clone();
+        rows.push(row);
+        Self(rows)
+    }
+
+    /// Returns the index of the parent of this node, or None for the root
+    pub fn parent(&self) -> Option<Self> {
+        self.0.split_last().map(|(_, parent)| Self(parent.to_vec()))
+    }
+
+    /// Returns the row of this node within its parent, or None for the root
+    pub fn row(&self) -> Option<usize> {
+        self.0.last().copied()
+    }
+
+    /// The number of ancestors of this node, not counting the root: top level nodes have a depth of 0
+    pub fn depth(&self) -> usize {
+        self.0.len().saturating_sub(1)
+    }
+
+    /// The rows of this node and its ancestors, starting from the top level
+    pub fn rows(&self) -> &[usize] {
+        &self.0
+    }
+
+    fn is_ancestor_of(&self, other: &TreeIndex) -> bool {
+        other.0.len() > self.0.len() && other.0.starts_with(&self.0)
+    }
+}
+
+/// A TreeModel is providing hierarchical data, for example for the TreeView widget.
+///
+/// Use a [`TreeViewModel`] to present it to the views, which only accept flat [`Model`]s.
+pub trait TreeModel {
+    /// The data of each node of the tree
+    type Data;
+    /// The amount of children of the given node. Use [`TreeIndex::root()`] for the top level nodes.
+    fn child_count(&self, parent: &TreeIndex) -> usize;
+    /// Returns the data of a node. This function should be called with an existing node.
+    fn data(&self, index: &TreeIndex) -> Self::Data;
+    /// Sets the data of a node.
+    /// If the model cannot support data changes, then it is ok to do nothing (default implementation).
+    /// If the model can update the data, it should also call node_changed on its internal `TreeModelNotify`.
+    fn set_data(&self, _index: &TreeIndex, _data: Self::Data) {}
+    /// Should forward to the internal [`TreeModelNotify::attach`]
+    fn attach_peer(&self, peer: TreeModelPeer);
+}
+
+trait TreeViewAbstraction {
+    fn node_changed(&mut self, index: &TreeIndex);
+    fn children_added(&mut self, parent: &TreeIndex, row: usize, count: usize);
+    fn children_removed(&mut self, parent: &TreeIndex, row: usize, count: usize);
+}
+
+type TreeModelPeerInner = dyn TreeViewAbstraction;
+
+/// Represent a handle to a view that listens to changes to a tree model. See [`TreeModel::attach_peer`]
+#[derive(Clone)]
+pub struct TreeModelPeer {
+    inner: Weak<RefCell<TreeModelPeerInner>>,
+}
+
+/// Dispatch notifications from a [`TreeModel`] to one or several [`TreeModelPeer`].
+#[derive(Default)]
+pub struct TreeModelNotify {
+    inner: RefCell<weak_table::PtrWeakHashSet<Weak<RefCell<TreeModelPeerInner>>>>,
+}
+
+impl TreeModelNotify {
+    /// Notify the peers that the data of a node was changed
+    pub fn node_changed(&self, index: &TreeIndex) {
+        for peer in self.inner.borrow().iter() {
+            peer.borrow_mut().node_changed(index)
+        }
+    }
+    /// Notify the peers that `count` children were added to `parent`, starting at `row`
+    pub fn children_added(&self, parent: &TreeIndex, row: usize, count: usize) {
+        for peer in self.inner.borrow().iter() {
+            peer.borrow_mut().children_added(parent, row, count)
+        }
+    }
+    /// Notify the peers that `count` children were removed from `parent`, starting at `row`
+    pub fn children_removed(&self, parent: &TreeIndex, row: usize, count: usize) {
+        for peer in self.inner.borrow().iter() {
+            peer.borrow_mut().children_removed(parent, row, count)
+        }
+    }
+    /// Attach one peer. The peer will be notified when the model changes
+    pub fn attach(&self, peer: TreeModelPeer) {
+        peer.inner.upgrade().map(|rc| self.inner.borrow_mut().insert(rc));
+    }
+}
+
+/// A node of a [`VecTreeModel`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TreeNode<T> {
+    /// The data of this node
+    pub data: T,
+    /// The children of this node
+    pub children: Vec<TreeNode<T>>,
+}
+
+impl<T> TreeNode<T> {
+    /// A node without children
+    pub fn new(data: T) -> Self {
+        Self { data, children: Vec::new() }
+    }
+
+    /// A node with the given children
+    pub fn with_children(data: T, children: Vec<TreeNode<T>>) -> Self {
+        Self { data, children }
+    }
+}
+
+/// A tree model backed by a Vec of [`TreeNode`]
+#[derive(Default)]
+pub struct VecTreeModel<T> {
+    nodes: RefCell<Vec<TreeNode<T>>>,
+    notify: TreeModelNotify,
+}
+
+fn tree_children<'a, T>(nodes: &'a [TreeNode<T>], parent: &TreeIndex) -> &'a [TreeNode<T>] {
+    parent.rows().iter().fold(nodes, |nodes, row| &nodes[*row].children)
+}
+
+fn tree_children_mut<'a, T>(
+    nodes: &'a mut Vec<TreeNode<T>>,
+    parent: &TreeIndex,
+) -> &'a mut Vec<TreeNode<T>> {
+    parent.rows().iter().fold(nodes, |nodes, row| &mut nodes[*row].children)
+}
+
+impl<T: 'static> VecTreeModel<T> {
+    /// Inserts a node as the child of `parent` at the given row
+    pub fn insert(&self, parent: &TreeIndex, row: usize, node: TreeNode<T>) {
+        tree_children_mut(&mut self.nodes.borrow_mut(), parent).insert(row, node);
+        self.notify.children_added(parent, row, 1)
+    }
+
+    /// Remove the node with the given index and all its children
+    pub fn remove(&self, index: &TreeIndex) {
+        let (parent, row) = match (index.parent(), index.row()) {
+            (Some(parent), Some(row)) => (parent, row),
+            _ => panic!("cannot remove the root"),
+        };
+        tree_children_mut(&mut self.nodes.borrow_mut(), &parent).remove(row);
+        self.notify.children_removed(&parent, row, 1)
+    }
+}
+
+impl<T> From<Vec<TreeNode<T>>> for VecTreeModel<T> {
+    fn from(nodes: Vec<TreeNode<T>>) -> Self {
+        VecTreeModel { nodes: RefCell::new(nodes), notify: Default::default() }
+    }
+}
+
+impl<T: Clone> TreeModel for VecTreeModel<T> {
+    type Data = T;
+
+    fn child_count(&self, parent: &TreeIndex) -> usize {
+        tree_children(&self.nodes.borrow(), parent).len()
+    }
+
+    fn data(&self, index: &TreeIndex) -> Self::Data {
+        let (parent, row) = (index.parent().unwrap(), index.row().unwrap());
+        tree_children(&self.nodes.borrow(), &parent)[row].data.clone()
+    }
+
+    fn set_data(&self, index: &TreeIndex, data: Self::Data) {
+        let (parent, row) = (index.parent().unwrap(), index.row().unwrap());
+        tree_children_mut(&mut self.nodes.borrow_mut(), &parent)[row].data = data;
+        self.notify.node_changed(index);
+    }
+
+    fn attach_peer(&self, peer: TreeModelPeer) {
+        self.notify.attach(peer);
+    }
+}
+
+/// Presents the visible nodes of a [`TreeModel`] as a flat [`Model`] of [`TreeViewItem`], which
+/// is the model of the TreeView widget.
+///
+/// The children of a node are only queried when the node is expanded, so collapsed branches of
+/// large trees cost nothing. Expanding or collapsing a node is reported as rows added or removed
+/// after it. Setting the `expanded` field of a row with [`Model::set_row_data`], which is what the
+/// TreeView widget does, expands or collapses the node.
+///
+/// The data of the nodes must be their text, as a [`SharedString`](crate::SharedString), since
+/// that is the only data a [`TreeViewItem`] shows. Use a tree model that converts the data of your
+/// nodes to their text to show other kinds of trees.
+pub struct TreeViewModel {
+    inner: Rc<TreeViewModelInner>,
+    /// Keeps the peer attached to the source model alive
+    _peer: Rc<RefCell<TreeModelPeerInner>>,
+}
+
+struct VisibleNode {
+    index: TreeIndex,
+    expanded: bool,
+}
+
+struct TreeViewModelInner {
+    source: Rc<dyn TreeModel<Data = crate::SharedString>>,
+    /// The nodes whose ancestors are all expanded, in display order
+    rows: RefCell<Vec<VisibleNode>>,
+    notify: ModelNotify,
+}
+
+/// The peer attached to the source model of a [`TreeViewModel`]
+struct TreeViewModelPeer(Weak<TreeViewModelInner>);
+
+impl TreeViewAbstraction for TreeViewModelPeer {
+    fn node_changed(&mut self, index: &TreeIndex) {
+        if let Some(inner) = self.0.upgrade() {
+            inner.node_changed(index)
+        }
+    }
+    fn children_added(&mut self, parent: &TreeIndex, row: usize, count: usize) {
+        if let Some(inner) = self.0.upgrade() {
+            inner.children_added(parent, row, count)
+        }
+    }
+    fn children_removed(&mut self, parent: &TreeIndex, row: usize, count: usize) {
+        if let Some(inner) = self.0.upgrade() {
+            inner.children_removed(parent, row, count)
+        }
+    }
+}
+
+impl TreeViewModel {
+    /// Creates a model that shows the top level nodes of `source`, all collapsed
+    pub fn new(source: Rc<dyn TreeModel<Data = crate::SharedString>>) -> Self {
+        let root = TreeIndex::root();
+        let rows = (0..source.child_count(&root))
+            .map(|row| VisibleNode { index: root.child(row), expanded: false })
+            .collect();
+        let inner = Rc::new(TreeViewModelInner {
+            source,
+            rows: RefCell::new(rows),
+            notify: Default::default(),
+        });
+        let peer: Rc<RefCell<TreeModelPeerInner>> =
+            Rc::new(RefCell::new(TreeViewModelPeer(Rc::downgrade(&inner))));
+        inner.source.attach_peer(TreeModelPeer { inner: Rc::downgrade(&peer) });
+        Self { inner, _peer: peer }
+    }
+
+    /// Expands or collapses the node shown at the given row
+    pub fn set_expanded(&self, row: usize, expanded: bool) {
+        self.inner.set_expanded(row, expanded)
+    }
+
+    /// Returns the index in the source model of the node shown at the given row
+    pub fn index(&self, row: usize) -> TreeIndex {
+        self.inner.rows.borrow()[row].index.clone()
+    }
+
+    /// Returns the row showing the node with the given index, or None if one of its ancestors is collapsed
+    pub fn row_of(&self, index: &TreeIndex) -> Option<usize> {
+        self.inner.row_of(index)
+    }
+}
+
+impl TreeViewModelInner {
+    fn row_of(&self, index: &TreeIndex) -> Option<usize> {
+        self.rows.borrow().iter().position(|node| node.index == *index)
+    }
+
+    fn set_expanded(&self, row: usize, expanded: bool) {
+        let index = {
+            let rows = self.rows.borrow();
+            if rows[row].expanded == expanded {
+                return;
+            }
+            rows[row].index.clone()
+        };
+        if expanded {
+            let count = self.source.child_count(&index);
+            if count == 0 {
+                return;
+            }
+            {
+                let mut rows = self.rows.borrow_mut();
+                rows[row].expanded = true;
+                rows.splice(
+                    row + 1..row + 1,
+                    (0..count).map(|r| VisibleNode { index: index.child(r), expanded: false }),
+                );
+            }
+            self.notify.row_changed(row);
+            self.notify.row_added(row + 1, count);
+        } else {
+            let count = {
+                let mut rows = self.rows.borrow_mut();
+                rows[row].expanded = false;
+                let count = rows[row + 1..]
+                    .iter()
+                    .take_while(|node| index.is_ancestor_of(&node.index))
+                    .count();
+                rows.drain(row + 1..row + 1 + count);
+                count
+            };
+            self.notify.row_changed(row);
+            if count > 0 {
+                self.notify.row_removed(row + 1, count);
+            }
+        }
+    }
+
+    /// Returns the row at which the child `child_row` of `parent` is, or would be, shown.
+    /// Returns None if the children of `parent` are not visible.
+    fn children_position(&self, parent: &TreeIndex, child_row: usize) -> Option<usize> {
+        let rows = self.rows.borrow();
+        let begin = if *parent == TreeIndex::root() {
+            0
+        } else {
+            let parent_row = rows.iter().position(|node| node.index == *parent)?;
+            if !rows[parent_row].expanded {
+                return None;
+            }
+            parent_row + 1
+        };
+        let depth = parent.rows().len();
+        Some(
+            begin
+                + rows[begin..]
+                    .iter()
+                    .take_while(|node| {
+                        parent.is_ancestor_of(&node.index) && node.index.rows()[depth] < child_row
+                    })
+                    .count(),
+        )
+    }
+
+    /// Adjust the indexes of the visible descendants of `parent` for a change of the rows of its
+    /// children starting at `from`
+    fn shift_children(&self, parent: &TreeIndex, from: usize, added: usize, removed: usize) {
+        let depth = parent.rows().len();
+        for node in self.rows.borrow_mut().iter_mut() {
+            if parent.is_ancestor_of(&node.index) && node.index.0[depth] >= from {
+                node.index.0[depth] = node.index.0[depth] + added - removed;
+            }
+        }
+    }
+
+    fn node_changed(&self, index: &TreeIndex) {
+        if let Some(row) = self.row_of(index) {
+            self.notify.row_changed(row);
+        }
+    }
+
+    fn children_added(&self, parent: &TreeIndex, row: usize, count: usize) {
+        self.shift_children(parent, row, count, 0);
+        if let Some(pos) = self.children_position(parent, row) {
+            self.rows.borrow_mut().splice(
+                pos..pos,
+                (row..row + count).map(|r| VisibleNode { index: parent.child(r), expanded: false }),
+            );
+            self.notify.row_added(pos, count);
+        }
+        // The parent may now have children
+        self.node_changed(parent);
+    }
+
+    fn children_removed(&self, parent: &TreeIndex, row: usize, count: usize) {
+        if let Some(pos) = self.children_position(parent, row) {
+            let removed = {
+                let mut rows = self.rows.borrow_mut();
+                let depth = parent.rows().len();
+                let removed = rows[pos..]
+                    .iter()
+                    .take_while(|node| {
+                        parent.is_ancestor_of(&node.index) && node.index.rows()[depth] < row + count
+                    })
+                    .count();
+                rows.drain(pos..pos + removed);
+                removed
+            };
+            if removed > 0 {
+                self.notify.row_removed(pos, removed);
+            }
+        }
+        self.shift_children(parent, row + count, 0, count);
+        if let Some(parent_row) = self.row_of(parent) {
+            if self.source.child_count(parent) == 0 {
+                self.rows.borrow_mut()[parent_row].expanded = false;
+            }
+            self.notify.row_changed(parent_row);
+        }
+    }
+}
+
+impl Model for TreeViewModel {
+    type Data = TreeViewItem;
+
+    fn row_count(&self) -> usize {
+        self.inner.rows.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Self::Data {
+        let (index, expanded) = {
+            let rows = self.inner.rows.borrow();
+            (rows[row].index.clone(), rows[row].expanded)
+        };
+        TreeViewItem {
+            text: self.inner.source.data(&index),
+            depth: index.depth() as i32,
+            has_children: self.inner.source.child_count(&index) > 0,
+            expanded,
+        }
+    }
+
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        let index = self.index(row);
+        if self.inner.source.data(&index) != data.text {
+            self.inner.source.set_data(&index, data.text);
+        }
+        self.set_expanded(row, data.expanded);
+    }
+
+    fn attach_peer(&self, peer: ModelPeer) {
+        self.inner.notify.attach(peer);
+    }
+
+    fn track_changes(&self) {
+        self.inner.notify.track_changes()
+    }
+}
+
 /// Properties of type array in the .60 language are represented as
 /// an [`Option`] of an [`Rc`] of something implemented the [`Model`] trait
 #[derive(derive_more::Deref, derive_more::DerefMut, derive_more::From, derive_more::Into)]
@@ -257,6 +1242,12 @@ fn row_data(&self, row: usize) -> Self::Data {
     fn attach_peer(&self, peer: ModelPeer) {
         self.0.as_ref().map(|model| model.attach_peer(peer));
     }
+
+    fn track_changes(&self) {
+        if let Some(model) = &self.0 {
+            model.track_changes()
+        }
+    }
 }
 
 /// Component that can be instantiated by a repeater.
@@ -267,6 +1258,21 @@ pub trait RepeatedComponent: crate::component::Component {
     /// Update this component at the given index and the given data
     fn update(&self, index: usize, data: Self::Data);
 
+    /// Called once after the component was created and updated for the first time.
+    /// Runs the `init` callbacks of the component.
+    fn init(self: Pin<&Self>) {}
+
+    /// Called when the component is removed from the repeater. Starts the `exit` transition and
+    /// returns true if the component must be kept alive until [`Self::exit_finished`] returns true.
+    fn start_exit(self: Pin<&Self>) -> bool {
+        false
+    }
+
+    /// Returns true once the animations of the `exit` transition started by [`Self::start_exit`] are over
+    fn exit_finished(self: Pin<&Self>) -> bool {
+        true
+    }
+
     /// Layout this item in the listview
     ///
     /// offset_y is the `y` position where this item should be placed.
@@ -293,6 +1299,8 @@ enum RepeatedComponentState {
 }
 struct RepeaterInner<C: RepeatedComponent> {
     components: Vec<(RepeatedComponentState, Option<ComponentRc<C>>)>,
+    /// The components that were removed but are kept alive until their exit transition is over
+    exiting: Vec<ComponentRc<C>>,
     is_dirty: bool,
     /// The model row (index) of the first component in the `components` vector.
     /// Only used for ListView
@@ -305,6 +1313,7 @@ impl<C: RepeatedComponent> Default for RepeaterInner<C> {
     fn default() -> Self {
         RepeaterInner {
             components: Default::default(),
+            exiting: Default::default(),
             is_dirty: true,
             offset: 0,
             cached_item_height: 0.,
@@ -312,6 +1321,20 @@ fn default() -> Self {
     }
 }
 
+impl<C: RepeatedComponent> RepeaterInner<C> {
+    /// Start the exit transition of the removed components, and keep the ones that have one alive
+    fn remove_components(
+        &mut self,
+        removed: impl IntoIterator<Item = (RepeatedComponentState, Option<ComponentRc<C>>)>,
+    ) {
+        for c in removed.into_iter().filter_map(|c| c.1) {
+            if c.as_pin_ref().start_exit() {
+                self.exiting.push(c);
+            }
+        }
+    }
+}
+
 impl<C: RepeatedComponent> Clone for RepeaterInner<C> {
     fn clone(&self) -> Self {
         panic!("Clone is there so we can make_mut the RepeaterInner, to dissociate the weaks, but there should only be one inner")
@@ -370,7 +1393,8 @@ fn row_removed(&mut self, mut index: usize, mut count: usize) {
             count = self.components.len() - index;
         }
         self.is_dirty = true;
-        self.components.drain(index..(index + count));
+        let removed: Vec<_> = self.components.drain(index..(index + count)).collect();
+        self.remove_components(removed);
         for c in self.components[index..].iter_mut() {
             // Because all the indexes are dirty
             c.0 = RepeatedComponentState::Dirty;
@@ -407,7 +1431,14 @@ fn model(self: Pin<&Self>) -> ModelHandle<C::Data> {
 
         if model.is_dirty() {
             // Invalidate previuos weeks on the previous models
-            (*Rc::make_mut(&mut self.inner.borrow_mut()).get_mut()) = RepeaterInner::default();
+            let mut new_inner = RepeaterInner::default();
+            {
+                let mut inner = self.inner.borrow_mut();
+                let inner = Rc::make_mut(&mut inner).get_mut();
+                new_inner.exiting = core::mem::take(&mut inner.exiting);
+                new_inner.remove_components(core::mem::take(&mut inner.components));
+                *inner = new_inner;
+            }
             if let ModelHandle(Some(m)) = model.get() {
                 let peer: Rc<RefCell<dyn ViewAbstraction>> = self.inner.borrow().clone();
                 m.attach_peer(ModelPeer { inner: Rc::downgrade(&peer) });
@@ -421,31 +1452,60 @@ fn model(self: Pin<&Self>) -> ModelHandle<C::Data> {
     pub fn ensure_updated(self: Pin<&Self>, init: impl Fn() -> ComponentRc<C>) {
         if let ModelHandle(Some(model)) = self.model() {
             if self.inner.borrow().borrow().is_dirty {
-                self.ensure_updated_impl(init, &model, model.row_count());
+                let created = self.ensure_updated_impl(init, &model, model.row_count());
+                self.init_components(created);
             }
         } else {
-            self.inner.borrow().borrow_mut().components.clear();
+            let inner = self.inner.borrow();
+            let mut inner = inner.borrow_mut();
+            let removed = core::mem::take(&mut inner.components);
+            inner.remove_components(removed);
         }
+        self.remove_exited_components();
     }
-    // returns true if new items were created
+
+    /// Drop the components whose exit transition is over
+    fn remove_exited_components(&self) {
+        let finished: Vec<_> = {
+            let inner = self.inner.borrow();
+            let mut inner = inner.borrow_mut();
+            let (finished, exiting) = core::mem::take(&mut inner.exiting)
+                .into_iter()
+                .partition(|c| c.as_pin_ref().exit_finished());
+            inner.exiting = exiting;
+            finished
+        };
+        // The components are dropped once the repeater is no longer borrowed
+        core::mem::drop(finished);
+    }
+
+    /// Returns the components that were created. The caller must call `init_components` with them.
     fn ensure_updated_impl(
         self: Pin<&Self>,
         init: impl Fn() -> ComponentRc<C>,
         model: &Rc<dyn Model<Data = C::Data>>,
         count: usize,
-    ) -> bool {
+    ) -> Vec<ComponentRc<C>> {
         let inner = self.inner.borrow();
         let mut inner = inner.borrow_mut();
+        if count < inner.components.len() {
+            let removed: Vec<_> = inner.components.drain(count..).collect();
+            inner.remove_components(removed);
+        }
         inner.components.resize_with(count, || (RepeatedComponentState::Dirty, None));
         let offset = inner.offset;
-        let mut created = false;
+        let mut created = vec![];
         for (i, c) in inner.components.iter_mut().enumerate() {
             if c.0 == RepeatedComponentState::Dirty {
-                if c.1.is_none() {
-                    created = true;
+                let is_new = c.1.is_none();
+                if is_new {
                     c.1 = Some(init());
                 }
-                c.1.as_ref().unwrap().update(i + offset, model.row_data(i + offset));
+                let component = c.1.as_ref().unwrap();
+                component.update(i + offset, model.row_data(i + offset));
+                if is_new {
+                    created.push(component.clone());
+                }
                 c.0 = RepeatedComponentState::Clean;
             }
         }
@@ -453,6 +1513,28 @@ fn ensure_updated_impl(
         created
     }
 
+    /// Runs the `init` of the components that were created and are still in the repeater.
+    /// The init callbacks may access the repeater and set properties, so this must be called
+    /// once the repeater is no longer borrowed, and outside of any binding evaluation.
+    fn init_components(&self, created: Vec<ComponentRc<C>>) {
+        let created: Vec<_> = {
+            let inner = self.inner.borrow();
+            let inner = inner.borrow();
+            created
+                .into_iter()
+                .filter(|component| {
+                    inner
+                        .components
+                        .iter()
+                        .any(|c| c.1.as_ref().map_or(false, |c| vtable::VRc::ptr_eq(c, component)))
+                })
+                .collect()
+        };
+        for component in &created {
+            component.as_pin_ref().init();
+        }
+    }
+
     /// Same as `Self::ensuer_updated` but for a ListView
     pub fn ensure_updated_listview(
         self: Pin<&Self>,
@@ -463,6 +1545,7 @@ pub fn ensure_updated_listview(
         listview_width: f32,
         listview_height: Pin<&Property<f32>>,
     ) {
+        self.remove_exited_components();
         let empty_model = || {
             self.inner.borrow().borrow_mut().components.clear();
             viewport_height.set(0.);
@@ -483,6 +1566,7 @@ pub fn ensure_updated_listview(
         // Safety: Repeater does not implement drop and never let access model as mutable
         let listview_geometry_tracker =
             unsafe { self.map_unchecked(|s| &s.listview_geometry_tracker) };
+        let mut created = vec![];
         if listview_geometry_tracker.is_dirty() {
             listview_geometry_tracker.evaluate_if_dirty(|| {
                 // Compute the element height
@@ -511,7 +1595,7 @@ pub fn ensure_updated_listview(
                         inner.offset = inner.offset.min(row_count - 1);
                     }
 
-                    self.ensure_updated_impl(&init, &model, 1);
+                    created.extend(self.ensure_updated_impl(&init, &model, 1));
                     if let Some(c) = self.inner.borrow().borrow().components.get(0) {
                         c.1.as_ref().map(|x| {
                             x.as_pin_ref().apply_layout(Default::default());
@@ -533,16 +1617,19 @@ pub fn ensure_updated_listview(
                 let count =
                     ((listview_height / element_height).ceil() as usize).min(row_count - offset);
                 self.set_offset(offset, count);
-                self.ensure_updated_impl(init, &model, count);
+                created.extend(self.ensure_updated_impl(init, &model, count));
                 self.compute_layout_listview(viewport_width, listview_width);
             });
         } else {
             if self.inner.borrow().borrow().is_dirty {
                 let count = self.inner.borrow().borrow().components.len();
-                self.ensure_updated_impl(init, &model, count);
+                created = self.ensure_updated_impl(init, &model, count);
                 self.compute_layout_listview(viewport_width, listview_width);
             }
         }
+        // Not within the evaluation of the tracker, so that the properties accessed by the
+        // init callbacks do not become dependencies of the geometry of the ListView
+        self.init_components(created);
     }
 
     fn set_offset(&self, offset: usize, count: usize) {
@@ -588,19 +1675,38 @@ pub fn set_model_binding(&self, binding: impl Fn() -> ModelHandle<C::Data> + 'st
     }
 
     /// Call the visitor for each component
+    ///
+    /// The components whose exit transition is running are only visited in the `BackToFront`
+    /// order, which is the order used for rendering: they are still drawn on top of the others,
+    /// but they no longer receive the input events or the focus.
     pub fn visit(
         &self,
         order: crate::item_tree::TraversalOrder,
         mut visitor: crate::item_tree::ItemVisitorRefMut,
     ) -> crate::item_tree::VisitChildrenResult {
+        let back_to_front = order == crate::item_tree::TraversalOrder::BackToFront;
         // We can't keep self.inner borrowed because the event might modify the model
-        let count = self.inner.borrow().borrow().components.len();
-        for i in 0..count {
-            let c = self.inner.borrow().borrow().components.get(i).and_then(|c| c.1.clone());
+        let (count, exiting_count) = {
+            let inner = self.inner.borrow();
+            let inner = inner.borrow();
+            (inner.components.len(), if back_to_front { inner.exiting.len() } else { 0 })
+        };
+        let total = count + exiting_count;
+        for i in 0..total {
+            let index = if back_to_front { i } else { total - 1 - i };
+            let c = {
+                let inner = self.inner.borrow();
+                let inner = inner.borrow();
+                if index < count {
+                    inner.components.get(index).and_then(|c| c.1.clone())
+                } else {
+                    inner.exiting.get(index - count).cloned()
+                }
+            };
             if let Some(c) = c {
                 if c.as_pin_ref().visit_children_item(-1, order, visitor.borrow_mut()).has_aborted()
                 {
-                    return crate::item_tree::VisitChildrenResult::abort(i, 0);
+                    return crate::item_tree::VisitChildrenResult::abort(index, 0);
                 }
             }
         }
@@ -647,3 +1753,249 @@ pub struct StandardListViewItem {
     /// The text content of the item
     pub text: crate::SharedString,
 }
+
+/// Represent an item in a TreeView. See [`TreeViewModel`]
+#[repr(C)]
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct TreeViewItem {
+    /// The text content of the item
+    pub text: crate::SharedString,
+    /// The amount of ancestors of the item: top level items have a depth of 0
+    pub depth: i32,
+    /// Whether the item has children that can be shown by expanding it
+    pub has_children: bool,
+    /// Whether the children of the item are shown
+    pub expanded: bool,
+}
+
+/// Represent a column of a StandardTableView
+#[repr(C)]
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct TableColumn {
+    /// The title shown in the header
+    pub title: crate::SharedString,
+    /// The width of the column
+    pub width: f32,
+    /// The sort indicator shown in the header
+    pub sort_order: crate::items::SortOrder,
+}
+
+#[cfg(test)]
+mod adapter_tests {
+    use super::*;
+
+    /// Records the notifications it receives
+    #[derive(Default)]
+    struct TestView {
+        changes: Vec<(&'static str, usize, usize)>,
+    }
+
+    impl ViewAbstraction for TestView {
+        fn row_changed(&mut self, row: usize) {
+            self.changes.push(("changed", row, 1));
+        }
+        fn row_added(&mut self, index: usize, count: usize) {
+            self.changes.push(("added", index, count));
+        }
+        fn row_removed(&mut self, index: usize, count: usize) {
+            self.changes.push(("removed", index, count));
+        }
+    }
+
+    fn attach_view<T>(model: &dyn Model<Data = T>) -> Rc<RefCell<TestView>> {
+        let view = Rc::new(RefCell::new(TestView::default()));
+        let peer: Rc<RefCell<ModelPeerInner>> = view.clone();
+        model.attach_peer(ModelPeer { inner: Rc::downgrade(&peer) });
+        view
+    }
+
+    fn rows<T>(model: &dyn Model<Data = T>) -> Vec<T> {
+        (0..model.row_count()).map(|row| model.row_data(row)).collect()
+    }
+
+    #[test]
+    fn vec_model() {
+        let model = VecModel::from(vec![1, 2, 3]);
+        let view = attach_view(&model);
+        model.insert(1, 4);
+        model.extend(vec![5, 6, 7]);
+        model.extend(vec![]);
+        assert_eq!(rows(&model), vec![1, 4, 2, 3, 5, 6, 7]);
+        model.swap(0, 2);
+        model.move_row(4, 1);
+        assert_eq!(rows(&model), vec![2, 5, 4, 1, 3, 6, 7]);
+        model.retain(|x| *x != 5 && *x < 3);
+        assert_eq!(rows(&model), vec![2, 1]);
+        model.set_vec(vec![8, 9, 10]);
+        assert_eq!(rows(&model), vec![8, 9, 10]);
+        model.clear();
+        assert_eq!(model.row_count(), 0);
+        assert_eq!(
+            view.borrow().changes,
+            vec![
+                ("added", 1, 1),
+                ("added", 4, 3),
+                ("changed", 0, 1),
+                ("changed", 2, 1),
+                ("changed", 1, 1),
+                ("changed", 2, 1),
+                ("changed", 3, 1),
+                ("changed", 4, 1),
+                ("removed", 1, 2),
+                ("removed", 2, 3),
+                ("changed", 0, 1),
+                ("changed", 1, 1),
+                ("added", 2, 1),
+                ("removed", 0, 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn filter_model() {
+        let source = Rc::new(VecModel::from(vec![1, 2, 3, 4, 5, 6]));
+        let filter = Rc::new(Cell::new(2));
+        let filter_copy = filter.clone();
+        let model = FilterModel::new(source.clone(), move |x| x % filter_copy.get() == 0);
+        let view = attach_view(&model);
+        assert_eq!(rows(&model), vec![2, 4, 6]);
+
+        source.push(8);
+        source.push(9);
+        assert_eq!(rows(&model), vec![2, 4, 6, 8]);
+        source.remove(1);
+        assert_eq!(rows(&model), vec![4, 6, 8]);
+        source.set_row_data(0, 10);
+        source.set_row_data(2, 11);
+        assert_eq!(rows(&model), vec![10, 6, 8]);
+        assert_eq!(model.source_row(1), 4);
+        model.set_row_data(2, 12);
+        assert_eq!(source.row_data(5), 12);
+        assert_eq!(
+            std::mem::take(&mut view.borrow_mut().changes),
+            vec![
+                ("added", 3, 1),
+                ("removed", 0, 1),
+                ("added", 0, 1),
+                ("removed", 1, 1),
+                ("changed", 2, 1)
+            ]
+        );
+
+        filter.set(3);
+        model.reset();
+        assert_eq!(rows(&model), vec![3, 6, 12, 9]);
+        assert_eq!(
+            view.borrow().changes,
+            vec![("removed", 0, 1), ("added", 0, 1), ("added", 3, 1)]
+        );
+    }
+
+    #[test]
+    fn sort_model() {
+        let source = Rc::new(VecModel::from(vec![(3, 'a'), (1, 'b'), (2, 'c'), (1, 'd')]));
+        let model = SortModel::new(source.clone(), |a, b| a.0.cmp(&b.0));
+        let view = attach_view(&model);
+        assert_eq!(rows(&model), vec![(1, 'b'), (1, 'd'), (2, 'c'), (3, 'a')]);
+
+        source.push((2, 'e'));
+        assert_eq!(rows(&model), vec![(1, 'b'), (1, 'd'), (2, 'c'), (2, 'e'), (3, 'a')]);
+        source.set_row_data(1, (4, 'b'));
+        assert_eq!(rows(&model), vec![(1, 'd'), (2, 'c'), (2, 'e'), (3, 'a'), (4, 'b')]);
+        source.set_row_data(0, (3, 'f'));
+        source.remove(2);
+        assert_eq!(rows(&model), vec![(1, 'd'), (2, 'e'), (3, 'f'), (4, 'b')]);
+        assert_eq!(model.source_row(0), 2);
+        assert_eq!(
+            view.borrow().changes,
+            vec![
+                ("added", 3, 1),
+                ("removed", 0, 1),
+                ("added", 4, 1),
+                ("changed", 3, 1),
+                ("removed", 1, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn tree_view_model() {
+        let tree = Rc::new(VecTreeModel::from(vec![
+            TreeNode::with_children(
+                crate::SharedString::from("a"),
+                vec![TreeNode::new("a1".into()), TreeNode::new("a2".into())],
+            ),
+            TreeNode::new("b".into()),
+        ]));
+        let model = TreeViewModel::new(tree.clone());
+        let view = attach_view(&model);
+        let texts = |model: &TreeViewModel| -> Vec<(String, i32)> {
+            rows(model).into_iter().map(|item| (item.text.to_string(), item.depth)).collect()
+        };
+        assert_eq!(texts(&model), vec![("a".into(), 0), ("b".into(), 0)]);
+        assert_eq!(
+            model.row_data(0),
+            TreeViewItem { text: "a".into(), depth: 0, has_children: true, expanded: false }
+        );
+
+        let mut item = model.row_data(0);
+        item.expanded = true;
+        model.set_row_data(0, item);
+        assert_eq!(
+            texts(&model),
+            vec![("a".into(), 0), ("a1".into(), 1), ("a2".into(), 1), ("b".into(), 0)]
+        );
+        assert_eq!(model.index(2), TreeIndex::root().child(0).child(1));
+
+        tree.insert(&TreeIndex::root().child(0), 0, TreeNode::new("a0".into()));
+        tree.insert(&TreeIndex::root().child(1), 0, TreeNode::new("b1".into()));
+        tree.insert(&TreeIndex::root(), 1, TreeNode::new("c".into()));
+        assert_eq!(
+            texts(&model),
+            vec![
+                ("a".into(), 0),
+                ("a0".into(), 1),
+                ("a1".into(), 1),
+                ("a2".into(), 1),
+                ("c".into(), 0),
+                ("b".into(), 0)
+            ]
+        );
+        assert!(model.row_data(5).has_children);
+        assert_eq!(model.row_of(&TreeIndex::root().child(2)), Some(5));
+        assert_eq!(model.row_of(&TreeIndex::root().child(2).child(0)), None);
+
+        tree.remove(&TreeIndex::root().child(0).child(1));
+        tree.set_data(&TreeIndex::root().child(0).child(1), "a3".into());
+        model.set_expanded(0, false);
+        assert_eq!(texts(&model), vec![("a".into(), 0), ("c".into(), 0), ("b".into(), 0)]);
+        assert_eq!(
+            view.borrow().changes,
+            vec![
+                ("changed", 0, 1),
+                ("added", 1, 2),
+                ("added", 1, 1),
+                ("changed", 0, 1),
+                ("changed", 4, 1),
+                ("added", 4, 1),
+                ("removed", 2, 1),
+                ("changed", 0, 1),
+                ("changed", 2, 1),
+                ("changed", 0, 1),
+                ("removed", 1, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn map_model() {
+        let source = Rc::new(VecModel::from(vec![1, 2, 3]));
+        let model = MapModel::new(source.clone(), |x: i32| x.to_string());
+        let view = attach_view(&model);
+        assert_eq!(rows(&model), vec!["1", "2", "3"]);
+        source.push(4);
+        source.set_row_data(0, 5);
+        assert_eq!(rows(&model), vec!["5", "2", "3", "4"]);
+        assert_eq!(view.borrow().changes, vec![("added", 3, 1), ("changed", 0, 1)]);
+    }
+}
@@ -660,25 +660,30 @@ impl<T: Clone + InterpolatedPropertyValue + 'static> Property<T> {
     /// be marked as dirty.
     pub fn set_animated_value(&self, value: T, animation_data: PropertyAnimation) {
         // FIXME if the current value is a dirty binding, we must run it, but we do not have the context
-        let d = RefCell::new(PropertyValueAnimationData::new(
+        let d = PropertyValueAnimationData::new(self.get_internal(), value, animation_data);
+        // Safety: the BindingCallable will cast its arguement to T
+        unsafe { self.handle.set_binding(animated_value_binding(d, || None)) };
+    }
+
+    /// Like set_animated_value, but the animation details depend on other properties.
+    ///
+    /// The details are evaluated when the animation starts, and again while it is
+    /// running so that changing its `running` field pauses or resumes it.
+    pub fn set_dynamic_animated_value(
+        &self,
+        value: T,
+        compute_animation_details: impl Fn() -> PropertyAnimation + 'static,
+    ) {
+        let d = PropertyValueAnimationData::new(
             self.get_internal(),
             value,
-            animation_data,
-        ));
+            compute_animation_details(),
+        );
         // Safety: the BindingCallable will cast its arguement to T
         unsafe {
-            self.handle.set_binding(move |val: *mut ()| {
-                let (value, finished) = d.borrow_mut().compute_interpolated_value();
-                *(val as *mut T) = value;
-                if finished {
-                    BindingResult::RemoveBinding
-                } else {
-                    crate::animations::CURRENT_ANIMATION_DRIVER
-                        .with(|driver| driver.set_has_active_animations());
-                    BindingResult::KeepBinding
-                }
-            });
-        }
+            self.handle
+                .set_binding(animated_value_binding(d, move || Some(compute_animation_details())))
+        };
     }
 
     /// Set a binding to this property.
@@ -738,7 +743,44 @@ pub fn set_animated_binding_for_transition(
                 T::default(),
                 PropertyAnimation::default(),
             )),
-            compute_animation_details: move || Some(compute_animation_details()),
+            compute_animation_details: move || {
+                let (animation, start_time) = compute_animation_details();
+                Some((animation, Some(start_time)))
+            },
+        };
+
+        // Safety: the AnimatedBindingCallable's type match the property type
+        unsafe { self.handle.set_binding(binding_callable) };
+        self.handle.mark_dirty();
+    }
+
+    /// Set a binding to this property, with an animation whose details depend on other properties.
+    ///
+    /// The animation details are evaluated when the animation starts, and again while
+    /// it is running so that changing its `running` field pauses or resumes it.
+    pub fn set_dynamic_animated_binding(
+        &self,
+        binding: impl Binding<T> + 'static,
+        compute_animation_details: impl Fn() -> PropertyAnimation + 'static,
+    ) {
+        let binding_callable = AnimatedBindingCallable::<T, _> {
+            original_binding: PropertyHandle {
+                handle: Cell::new(
+                    (alloc_binding_holder(move |val: *mut ()| unsafe {
+                        let val = &mut *(val as *mut T);
+                        *(val as *mut T) = binding.evaluate(val);
+                        BindingResult::KeepBinding
+                    }) as usize)
+                        | 0b10,
+                ),
+            },
+            state: Cell::new(AnimatedBindingState::NotAnimating),
+            animation_data: RefCell::new(PropertyValueAnimationData::new(
+                T::default(),
+                T::default(),
+                PropertyAnimation::default(),
+            )),
+            compute_animation_details: move || Some((compute_animation_details(), None)),
         };
 
         // Safety: the AnimatedBindingCallable's type match the property type
@@ -887,33 +929,169 @@ struct PropertyValueAnimationData<T> {
     to_value: T,
     details: PropertyAnimation,
     start_time: crate::animations::Instant,
-    loop_iteration: i32,
+    /// The tick at which the animation was paused, if it is paused
+    paused_at: Option<crate::animations::Instant>,
+    /// For spring animations: the velocity at start_time, in units of the
+    /// from_value..to_value range per second
+    initial_velocity: f32,
+    /// For spring animations: the velocity at the last computed value
+    velocity: f32,
 }
 
 impl<T: InterpolatedPropertyValue> PropertyValueAnimationData<T> {
     fn new(from_value: T, to_value: T, details: PropertyAnimation) -> Self {
-        let start_time = crate::animations::current_tick();
-        Self { from_value, to_value, details, start_time, loop_iteration: 0 }
+        let start_time = crate::animations::animation_tick();
+        let paused_at = if details.running { None } else { Some(start_time) };
+        Self {
+            from_value,
+            to_value,
+            details,
+            start_time,
+            paused_at,
+            initial_velocity: 0.,
+            velocity: 0.,
+        }
+    }
+
+    /// Change the details of the animation that (re)starts at start_time
+    fn set_details(&mut self, details: PropertyAnimation) {
+        self.paused_at = if details.running { None } else { Some(self.start_time) };
+        self.details = details;
+    }
+
+    /// Pause or resume the animation. The time spent paused does not count in the animation.
+    fn set_running(&mut self, running: bool) {
+        self.details.running = running;
+        match self.paused_at {
+            Some(paused_at) if running => {
+                self.start_time += crate::animations::animation_tick().duration_since(paused_at);
+                self.paused_at = None;
+            }
+            None if !running => self.paused_at = Some(crate::animations::animation_tick()),
+            _ => {}
+        }
+    }
+
+    /// Tell the animation driver that a new frame is needed, unless the animation is paused
+    fn request_next_frame(&self) {
+        if self.paused_at.is_none() {
+            crate::animations::CURRENT_ANIMATION_DRIVER
+                .with(|driver| driver.set_has_active_animations());
+        }
+    }
+
+    /// Called when the animation restarts towards a new target from the current value.
+    /// `previous_from` and `previous_to` are the previous from_value and to_value.
+    fn retarget(&mut self, previous_from: T, previous_to: T) {
+        self.initial_velocity = if self.details.stiffness > 0. {
+            self.velocity * previous_from.span_ratio(previous_to, self.from_value, self.to_value)
+        } else {
+            0.
+        };
+        self.velocity = self.initial_velocity;
+    }
+
+    fn compute_spring_value(&mut self, time: core::time::Duration) -> (T, bool) {
+        let spring = crate::animations::Spring {
+            stiffness: self.details.stiffness,
+            damping: self.details.damping,
+            mass: self.details.mass,
+        };
+        // The spring rests at the target, so the displacement starts at -1 in progress units
+        let (displacement, velocity) = spring.state(-1., self.initial_velocity, time.as_secs_f32());
+        const EPSILON: f32 = 0.001;
+        if displacement.abs() < EPSILON && velocity.abs() < EPSILON {
+            self.velocity = 0.;
+            return (self.to_value.clone(), true);
+        }
+        self.velocity = velocity;
+        (self.from_value.interpolate(self.to_value, 1. + displacement), false)
+    }
+
+    /// Returns the value at the progress t, going through the keyframes if there are any
+    fn value_at(&self, t: f32) -> T {
+        let keyframes = self
+            .details
+            .keyframes
+            .iter()
+            .filter_map(|k| Some((k.position, T::from_keyframe_value(k.value)?)));
+        let mut previous = (0., self.from_value);
+        for next in keyframes.chain(core::iter::once((1., self.to_value))) {
+            if t < next.0 || next.0 >= 1. {
+                let span = next.0 - previous.0;
+                let t = if span > 0. { (t - previous.0) / span } else { 1. };
+                return previous.1.interpolate(next.1, t);
+            }
+            previous = next;
+        }
+        self.to_value
     }
 
     fn compute_interpolated_value(&mut self) -> (T, bool) {
+        let speed = crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| driver.speed());
+        if speed <= 0. {
+            // The animations are disabled
+            return (self.to_value.clone(), true);
+        }
+        let delay = self.details.delay.max(0) as u128;
+        // When paused, the time is frozen, so don't register a dependency to the current tick
+        let new_tick = self.paused_at.unwrap_or_else(crate::animations::animation_tick);
+        let elapsed = new_tick.duration_since(self.start_time).as_millis();
+        if elapsed < delay {
+            return (self.from_value.clone(), false);
+        }
+        let mut time_progress = elapsed - delay;
+        if self.details.stiffness > 0. {
+            return self
+                .compute_spring_value(core::time::Duration::from_millis(time_progress as u64));
+        }
         let duration = self.details.duration as u128;
-        let new_tick = crate::animations::current_tick();
-        let mut time_progress = new_tick.duration_since(self.start_time).as_millis();
+        let mut loop_iteration = 0;
         if time_progress >= duration {
-            if self.loop_iteration < self.details.loop_count || self.details.loop_count < 0 {
-                self.loop_iteration += (time_progress / duration) as i32;
+            loop_iteration = if duration > 0 { time_progress / duration } else { 0 };
+            if loop_iteration > 0
+                && (loop_iteration <= self.details.loop_count as u128
+                    || self.details.loop_count < 0)
+            {
                 time_progress = time_progress % duration;
-                self.start_time = new_tick - std::time::Duration::from_millis(time_progress as u64);
+            } else if self.details.alternate && self.details.loop_count % 2 == 1 {
+                // The last iteration ran backward, so the animation ends on the start value
+                return (self.from_value.clone(), true);
             } else {
                 return (self.to_value.clone(), true);
             }
         }
-        let progress = time_progress as f32 / self.details.duration as f32;
+        let mut progress = time_progress as f32 / self.details.duration as f32;
         assert!(progress <= 1.);
+        if self.details.alternate && loop_iteration % 2 == 1 {
+            progress = 1. - progress;
+        }
         let t = crate::animations::easing_curve(&self.details.easing, progress);
-        let val = self.from_value.interpolate(self.to_value, t);
-        (val, false)
+        (self.value_at(t), false)
+    }
+}
+
+/// The binding that animates a property towards the value passed to set_animated_value.
+/// The binding is removed once the animation is finished.
+fn animated_value_binding<T: InterpolatedPropertyValue>(
+    animation_data: PropertyValueAnimationData<T>,
+    compute_animation_details: impl Fn() -> Option<PropertyAnimation>,
+) -> impl Fn(*mut ()) -> BindingResult {
+    let animation_data = RefCell::new(animation_data);
+    move |val: *mut ()| {
+        let mut d = animation_data.borrow_mut();
+        // The `running` property of the animation may have changed
+        if let Some(details) = compute_animation_details() {
+            d.set_running(details.running);
+        }
+        let (value, finished) = d.compute_interpolated_value();
+        unsafe { *(val as *mut T) = value };
+        if finished {
+            BindingResult::RemoveBinding
+        } else {
+            d.request_next_frame();
+            BindingResult::KeepBinding
+        }
     }
 }
 
@@ -931,7 +1109,8 @@ struct AnimatedBindingCallable<T, A> {
     compute_animation_details: A,
 }
 
-type AnimationDetail = Option<(PropertyAnimation, crate::animations::Instant)>;
+/// The details of the animation, and the time at which it starts if it is not the time at which the binding changed
+type AnimationDetail = Option<(PropertyAnimation, Option<crate::animations::Instant>)>;
 
 impl<T: InterpolatedPropertyValue, A: Fn() -> AnimationDetail> BindingCallable
     for AnimatedBindingCallable<T, A>
@@ -940,13 +1119,17 @@ unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
         self.original_binding.register_as_dependency_to_current_binding();
         match self.state.get() {
             AnimatedBindingState::Animating => {
-                let (val, finished) = self.animation_data.borrow_mut().compute_interpolated_value();
+                let mut animation_data = self.animation_data.borrow_mut();
+                // The `running` property of the animation may have changed
+                if let Some((details, _)) = (self.compute_animation_details)() {
+                    animation_data.set_running(details.running);
+                }
+                let (val, finished) = animation_data.compute_interpolated_value();
                 *(value as *mut T) = val;
                 if finished {
                     self.state.set(AnimatedBindingState::NotAnimating)
                 } else {
-                    crate::animations::CURRENT_ANIMATION_DRIVER
-                        .with(|driver| driver.set_has_active_animations());
+                    animation_data.request_next_frame();
                 }
             }
             AnimatedBindingState::NotAnimating => {
@@ -956,19 +1139,23 @@ unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
                 let value = &mut *(value as *mut T);
                 self.state.set(AnimatedBindingState::Animating);
                 let mut animation_data = self.animation_data.borrow_mut();
+                let (previous_from, previous_to) =
+                    (animation_data.from_value, animation_data.to_value);
                 animation_data.from_value = value.clone();
                 self.original_binding.update((&mut animation_data.to_value) as *mut T as *mut ());
                 if let Some((details, start_time)) = (self.compute_animation_details)() {
-                    animation_data.start_time = start_time;
-                    animation_data.details = details;
+                    if let Some(start_time) = start_time {
+                        animation_data.start_time = start_time;
+                    }
+                    animation_data.set_details(details);
                 }
+                animation_data.retarget(previous_from, previous_to);
                 let (val, finished) = animation_data.compute_interpolated_value();
                 *value = val;
                 if finished {
                     self.state.set(AnimatedBindingState::NotAnimating)
                 } else {
-                    crate::animations::CURRENT_ANIMATION_DRIVER
-                        .with(|driver| driver.set_has_active_animations());
+                    animation_data.request_next_frame();
                 }
             }
         };
@@ -981,7 +1168,7 @@ fn mark_dirty(self: Pin<&Self>) {
         let original_dirty = self.original_binding.access(|b| b.unwrap().dirty.get());
         if original_dirty {
             self.state.set(AnimatedBindingState::ShouldStart);
-            self.animation_data.borrow_mut().start_time = crate::animations::current_tick();
+            self.animation_data.borrow_mut().start_time = crate::animations::animation_tick();
         }
     }
 }
@@ -996,30 +1183,78 @@ pub trait InterpolatedPropertyValue:
     /// progress parameter t that's usually between 0 and 1. With certain animation
     /// easing curves it may over- or undershoot though.
     fn interpolate(self, target_value: Self, t: f32) -> Self;
+
+    /// Returns the signed length of the span from self to target_value, measured in units of
+    /// the span from `from` to `to`. This is used to carry the velocity of a spring animation
+    /// over when its target changes, also when the new target is in the other direction.
+    fn span_ratio(self, target_value: Self, from: Self, to: Self) -> f32;
+
+    /// Converts the value of a keyframe to this type. Returns None if keyframes are not
+    /// supported for this type.
+    fn from_keyframe_value(_value: f32) -> Option<Self> {
+        None
+    }
+}
+
+/// Returns the ratio between two signed spans, or 0 if the second one is empty
+fn signed_ratio(span: f32, unit: f32) -> f32 {
+    if unit != 0. {
+        span / unit
+    } else {
+        0.
+    }
 }
 
 impl InterpolatedPropertyValue for f32 {
     fn interpolate(self, target_value: Self, t: f32) -> Self {
         self + t * (target_value - self)
     }
+
+    fn span_ratio(self, target_value: Self, from: Self, to: Self) -> f32 {
+        signed_ratio(target_value - self, to - from)
+    }
+
+    fn from_keyframe_value(value: f32) -> Option<Self> {
+        Some(value)
+    }
 }
 
 impl InterpolatedPropertyValue for i32 {
     fn interpolate(self, target_value: Self, t: f32) -> Self {
         self + (t * (target_value - self) as f32) as i32
     }
+
+    fn span_ratio(self, target_value: Self, from: Self, to: Self) -> f32 {
+        signed_ratio(target_value as f32 - self as f32, to as f32 - from as f32)
+    }
+
+    fn from_keyframe_value(value: f32) -> Option<Self> {
+        Some(value as i32)
+    }
 }
 
 impl InterpolatedPropertyValue for i64 {
     fn interpolate(self, target_value: Self, t: f32) -> Self {
         self + (t * (target_value - self) as f32) as Self
     }
+
+    fn span_ratio(self, target_value: Self, from: Self, to: Self) -> f32 {
+        signed_ratio(target_value as f32 - self as f32, to as f32 - from as f32)
+    }
+
+    fn from_keyframe_value(value: f32) -> Option<Self> {
+        Some(value as i64)
+    }
 }
 
 impl InterpolatedPropertyValue for u8 {
     fn interpolate(self, target_value: Self, t: f32) -> Self {
         ((self as f32) + (t * ((target_value as f32) - (self as f32)))).min(255.).max(0.) as u8
     }
+
+    fn span_ratio(self, target_value: Self, from: Self, to: Self) -> f32 {
+        signed_ratio(target_value as f32 - self as f32, to as f32 - from as f32)
+    }
 }
 
 #[cfg(test)]
@@ -1210,6 +1445,264 @@ fn test_loop_overshoot() {
         // the binding should be removed
         compo.width.handle.access(|binding| assert!(binding.is_none()));
     }
+
+    #[test]
+    fn properties_test_spring_animation_retarget() {
+        let compo = Component::new_test_component();
+
+        // critically damped spring
+        let animation_details = PropertyAnimation {
+            stiffness: 100.,
+            damping: 20.,
+            mass: 1.,
+            ..PropertyAnimation::default()
+        };
+
+        let w = Rc::downgrade(&compo);
+        compo.width.set_animated_binding(
+            move || {
+                let compo = w.upgrade().unwrap();
+                get_prop_value(&compo.feed_property)
+            },
+            animation_details,
+        );
+
+        compo.feed_property.set(0);
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        let start_time = crate::animations::current_tick();
+        compo.feed_property.set(1000);
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + instant::Duration::from_millis(100))
+        });
+        // 1000 * (1 - 2 * e^-1)
+        assert_eq!(get_prop_value(&compo.width), 264);
+
+        // Change the target while animating: the value does not jump and keeps its velocity
+        compo.feed_property.set(2000);
+        assert_eq!(get_prop_value(&compo.width), 264);
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + instant::Duration::from_millis(110))
+        });
+        let width = get_prop_value(&compo.width);
+        assert!(width > 295 && width < 315, "unexpected width {}", width);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + instant::Duration::from_millis(5000))
+        });
+        assert_eq!(get_prop_value(&compo.width), 2000);
+        assert_eq!(get_prop_value(&compo.width_times_two), 4000);
+
+        let start_time = crate::animations::current_tick();
+        compo.feed_property.set(0);
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + instant::Duration::from_millis(100))
+        });
+        // 2000 - 2000 * (1 - 2 * e^-1)
+        assert_eq!(get_prop_value(&compo.width), 1472);
+
+        // Reverse the direction while animating: the value keeps decreasing before turning back
+        compo.feed_property.set(3000);
+        assert_eq!(get_prop_value(&compo.width), 1472);
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + instant::Duration::from_millis(110))
+        });
+        let width = get_prop_value(&compo.width);
+        assert!(width > 1400 && width < 1425, "unexpected width {}", width);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + instant::Duration::from_millis(5000))
+        });
+        assert_eq!(get_prop_value(&compo.width), 3000);
+    }
+
+    #[test]
+    fn properties_test_spring_animation_default_damping() {
+        let compo = Component::new_test_component();
+
+        // Without damping, the spring is critically damped and comes to rest
+        let animation_details =
+            PropertyAnimation { stiffness: 100., ..PropertyAnimation::default() };
+
+        compo.width.set(0);
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        let start_time = crate::animations::current_tick();
+        compo.width.set_animated_value(1000, animation_details);
+        assert_eq!(get_prop_value(&compo.width), 0);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + instant::Duration::from_millis(100))
+        });
+        // 1000 * (1 - 2 * e^-1), as with a damping of 20
+        assert_eq!(get_prop_value(&compo.width), 264);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
+            driver.update_animations(start_time + instant::Duration::from_millis(5000))
+        });
+        assert_eq!(get_prop_value(&compo.width), 1000);
+
+        // the animation is finished, so the binding should be removed
+        compo.width.handle.access(|binding| assert!(binding.is_none()));
+    }
+
+    #[test]
+    fn properties_test_keyframes_with_delay() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            delay: 1000,
+            keyframes: crate::SharedVector::from_slice(&[crate::animations::KeyFrame {
+                position: 0.5,
+                value: 400.,
+            }]),
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(100);
+        let start_time = crate::animations::current_tick();
+        let delay = instant::Duration::from_millis(1000);
+
+        compo.width.set_animated_value(200, animation_details);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + delay / 2));
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + delay + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 250);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + delay + DURATION * 3 / 4));
+        assert_eq!(get_prop_value(&compo.width), 300);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + delay + DURATION));
+        assert_eq!(get_prop_value(&compo.width), 200);
+        assert_eq!(get_prop_value(&compo.width_times_two), 400);
+    }
+
+    #[test]
+    fn properties_test_animation_paused() {
+        let compo = Component::new_test_component();
+        let running = Rc::pin(Property::new(true));
+
+        let w = Rc::downgrade(&compo);
+        let r = running.clone();
+        compo.width.set_dynamic_animated_binding(
+            move || {
+                let compo = w.upgrade().unwrap();
+                get_prop_value(&compo.feed_property)
+            },
+            move || PropertyAnimation {
+                duration: DURATION.as_millis() as _,
+                running: r.as_ref().get(),
+                ..PropertyAnimation::default()
+            },
+        );
+
+        compo.feed_property.set(100);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        let start_time = crate::animations::current_tick();
+        compo.feed_property.set(200);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 125);
+
+        running.as_ref().set(false);
+        assert_eq!(get_prop_value(&compo.width), 125);
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION));
+        assert_eq!(get_prop_value(&compo.width), 125);
+        assert!(!crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.has_active_animations()));
+
+        // The time spent paused does not count
+        running.as_ref().set(true);
+        assert_eq!(get_prop_value(&compo.width), 125);
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 150);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 2));
+        assert_eq!(get_prop_value(&compo.width), 200);
+    }
+
+    #[test]
+    fn properties_test_animated_value_paused() {
+        let compo = Component::new_test_component();
+        let running = Rc::pin(Property::new(false));
+
+        compo.width.set(100);
+        let start_time = crate::animations::current_tick();
+
+        // Assigning while paused keeps the current value until the animation is resumed
+        let r = running.clone();
+        compo.width.set_dynamic_animated_value(200, move || PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            running: r.as_ref().get(),
+            ..PropertyAnimation::default()
+        });
+        assert_eq!(get_prop_value(&compo.width), 100);
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION));
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        running.as_ref().set(true);
+        assert_eq!(get_prop_value(&compo.width), 100);
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 125);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 2));
+        assert_eq!(get_prop_value(&compo.width), 200);
+    }
+
+    #[test]
+    fn properties_test_animation_speed() {
+        let compo = Component::new_test_component();
+
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(100);
+        let start_time = crate::animations::current_tick();
+
+        crate::animations::set_animation_speed(0.5);
+        compo.width.set_animated_value(200, animation_details.clone());
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION));
+        assert_eq!(get_prop_value(&compo.width), 150);
+
+        // Changing the speed while animating does not make the value jump
+        crate::animations::set_animation_speed(1.);
+        assert_eq!(get_prop_value(&compo.width), 150);
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION + DURATION / 4));
+        assert_eq!(get_prop_value(&compo.width), 175);
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION * 2));
+        assert_eq!(get_prop_value(&compo.width), 200);
+
+        // Disabled animations end immediately
+        crate::animations::set_animation_speed(0.);
+        compo.width.set_animated_value(300, animation_details);
+        assert_eq!(get_prop_value(&compo.width), 300);
+
+        crate::animations::set_animation_speed(1.);
+    }
 }
 
 /// Value of the state property
@@ -1222,7 +1715,7 @@ pub struct StateInfo {
     pub current_state: i32,
     /// The previous state
     pub previous_state: i32,
-    /// The instant in which the state changed last
+    /// The instant in which the state changed last, see [`crate::animations::animation_tick`]
     pub change_time: crate::animations::Instant,
 }
 
@@ -1239,7 +1732,7 @@ unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
         let timestamp = self.dirty_time.take();
         if new_state != value.current_state {
             value.previous_state = value.current_state;
-            value.change_time = timestamp.unwrap_or_else(crate::animations::current_tick);
+            value.change_time = timestamp.unwrap_or_else(crate::animations::animation_tick);
             value.current_state = new_state;
         }
         BindingResult::KeepBinding
@@ -1247,7 +1740,7 @@ unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
 
     fn mark_dirty(self: Pin<&Self>) {
         if self.dirty_time.get().is_none() {
-            self.dirty_time.set(Some(crate::animations::current_tick()))
+            self.dirty_time.set(Some(crate::animations::animation_tick()))
         }
     }
 }
@@ -1344,6 +1837,193 @@ fn test_property_listener_scope() {
     assert!(ok);
 }
 
+struct ChangeTrackerInner {
+    /// Evaluate the tracked expression, and returns true if its value changed since the last call
+    evaluate: Box<dyn Fn() -> bool>,
+    /// The handler. It is in a Rc because the handler may destroy the tracker
+    notify: Rc<dyn Fn()>,
+    /// True when this tracker is in the CHANGED_TRACKERS queue
+    queued: Cell<bool>,
+}
+
+thread_local! {
+    /// The trackers whose dependencies have changed and that need to be evaluated
+    /// by [`ChangeTracker::run_change_handlers`]
+    static CHANGED_TRACKERS: RefCell<std::collections::VecDeque<*const BindingHolder>> =
+        Default::default()
+}
+
+/// A ChangeTracker calls a handler when the value of an expression changes.
+///
+/// The expression is evaluated when the tracker is initialized, and the properties it accesses
+/// are recorded. When one of them changes, the tracker is queued, and the handler is called by the
+/// next call to [`ChangeTracker::run_change_handlers`] if the value is different.
+/// This way, handlers are never called from within the evaluation of a binding.
+pub struct ChangeTracker {
+    /// Null if the tracker was not initialized. Otherwise, this is owned by the tracker.
+    inner: Cell<*mut BindingHolder<ChangeTrackerInner>>,
+}
+
+impl Default for ChangeTracker {
+    fn default() -> Self {
+        Self { inner: Cell::new(core::ptr::null_mut()) }
+    }
+}
+
+impl Drop for ChangeTracker {
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl ChangeTracker {
+    /// Initialize the tracker: `notify_fn` will be called when the value returned by `eval_fn` changes.
+    /// Any previous initialization is discarded.
+    pub fn init<T: PartialEq + 'static>(
+        &self,
+        eval_fn: impl Fn() -> T + 'static,
+        notify_fn: impl Fn() + 'static,
+    ) {
+        let value: RefCell<Option<T>> = RefCell::new(None);
+        self.init_impl(
+            Box::new(move || {
+                let new_value = eval_fn();
+                let mut value = value.borrow_mut();
+                if value.as_ref() == Some(&new_value) {
+                    false
+                } else {
+                    *value = Some(new_value);
+                    true
+                }
+            }),
+            Rc::new(notify_fn),
+        )
+    }
+
+    fn init_impl(&self, evaluate: Box<dyn Fn() -> bool>, notify: Rc<dyn Fn()>) {
+        /// Safety: _self must be a pointer that comes from a `Box<BindingHolder<ChangeTrackerInner>>::into_raw()`
+        unsafe fn drop(_self: *mut BindingHolder) {
+            Box::from_raw(_self as *mut BindingHolder<ChangeTrackerInner>);
+        }
+        /// Safety: _self must be a pointer to a `BindingHolder<ChangeTrackerInner>`
+        unsafe fn mark_dirty(_self: *const BindingHolder) {
+            let inner = &(*(_self as *const BindingHolder<ChangeTrackerInner>)).binding;
+            if !inner.queued.replace(true) {
+                CHANGED_TRACKERS.with(|queue| queue.borrow_mut().push_back(_self));
+            }
+        }
+        static VT: &'static BindingVTable = &BindingVTable {
+            drop,
+            evaluate: |_, _| BindingResult::KeepBinding,
+            mark_dirty,
+            intercept_set: |_, _| false,
+            intercept_set_binding: |_, _| false,
+        };
+
+        self.clear();
+        let holder = Box::into_raw(Box::new(BindingHolder {
+            dependencies: Cell::new(0),
+            dep_nodes: Default::default(),
+            vtable: VT,
+            dirty: Cell::new(false),
+            pinned: PhantomPinned,
+            binding: ChangeTrackerInner { evaluate, notify, queued: Cell::new(false) },
+        }));
+        self.inner.set(holder);
+        // The first evaluation records the initial value and the dependencies
+        unsafe { Self::evaluate(holder as *const BindingHolder) };
+    }
+
+    /// Evaluate the expression of the tracker, recording its dependencies.
+    /// Returns true if the value changed.
+    ///
+    /// Safety: holder must be a valid pointer to a `BindingHolder<ChangeTrackerInner>`
+    unsafe fn evaluate(holder: *const BindingHolder) -> bool {
+        let inner = &(*(holder as *const BindingHolder<ChangeTrackerInner>)).binding;
+        // clear all the nodes so that we can start from scratch
+        *(*holder).dep_nodes.borrow_mut() = Default::default();
+        let changed = CURRENT_BINDING.set(Pin::new_unchecked(&*holder), || (inner.evaluate)());
+        (*holder).dirty.set(false);
+        changed
+    }
+
+    /// Stops tracking: the notify function will not be called anymore, until the next `init`.
+    pub fn clear(&self) {
+        let holder = self.inner.replace(core::ptr::null_mut());
+        if !holder.is_null() {
+            let _ = CHANGED_TRACKERS.try_with(|queue| {
+                queue.borrow_mut().retain(|x| *x != holder as *const BindingHolder)
+            });
+            unsafe { Box::from_raw(holder) };
+        }
+    }
+
+    /// Call the handlers of the trackers whose value has changed since the last time.
+    /// Returns true if any handler was called.
+    ///
+    /// This is called by the event loop. Handlers that change properties may cause more
+    /// handlers to be called within the same call.
+    pub fn run_change_handlers() -> bool {
+        let mut any_called = false;
+        while let Some(holder) = CHANGED_TRACKERS.with(|queue| queue.borrow_mut().pop_front()) {
+            // Safety: trackers remove themselves from the queue when they are dropped,
+            // so the holder is still valid.
+            let notify = unsafe {
+                let inner = &(*(holder as *const BindingHolder<ChangeTrackerInner>)).binding;
+                inner.queued.set(false);
+                if Self::evaluate(holder) {
+                    Some(inner.notify.clone())
+                } else {
+                    None
+                }
+            };
+            if let Some(notify) = notify {
+                any_called = true;
+                notify();
+            }
+        }
+        any_called
+    }
+}
+
+#[test]
+fn test_change_tracker() {
+    let prop1 = Rc::pin(Property::new(42));
+    let prop2 = Rc::pin(Property::<i32>::default());
+    prop2.as_ref().set_binding({
+        let prop1 = prop1.clone();
+        move || prop1.as_ref().get() * 2
+    });
+    let count = Rc::new(Cell::new(0));
+    let tracker = ChangeTracker::default();
+    tracker.init(
+        {
+            let prop2 = prop2.clone();
+            move || prop2.as_ref().get()
+        },
+        {
+            let count = count.clone();
+            move || count.set(count.get() + 1)
+        },
+    );
+    assert!(!ChangeTracker::run_change_handlers());
+    prop1.as_ref().set(10);
+    // the handler is only called when running the handlers
+    assert_eq!(count.get(), 0);
+    assert!(ChangeTracker::run_change_handlers());
+    assert_eq!(count.get(), 1);
+    assert!(!ChangeTracker::run_change_handlers());
+    // setting a value that evaluates to the same value does not call the handler
+    prop1.as_ref().set(5);
+    prop1.as_ref().set(10);
+    assert!(!ChangeTracker::run_change_handlers());
+    assert_eq!(count.get(), 1);
+    prop1.as_ref().set(11);
+    drop(tracker);
+    assert!(!ChangeTracker::run_change_handlers());
+    assert_eq!(count.get(), 1);
+}
+
 pub(crate) mod ffi {
     use super::*;
     use core::pin::Pin;
@@ -1506,33 +2186,58 @@ fn c_set_animated_value<T: InterpolatedPropertyValue>(
         from: T,
         to: T,
         animation_data: &PropertyAnimation,
+        compute_animation_details: Option<
+            extern "C" fn(user_data: *mut c_void) -> PropertyAnimation,
+        >,
+        user_data: *mut c_void,
+        drop_user_data: Option<extern "C" fn(*mut c_void)>,
     ) {
-        let d = RefCell::new(PropertyValueAnimationData::new(from, to, animation_data.clone()));
+        struct UserData {
+            user_data: *mut c_void,
+            drop_user_data: Option<extern "C" fn(*mut c_void)>,
+        }
+        impl Drop for UserData {
+            fn drop(&mut self) {
+                if let Some(x) = self.drop_user_data {
+                    x(self.user_data)
+                }
+            }
+        }
+        let user_data = UserData { user_data, drop_user_data };
+        let d = PropertyValueAnimationData::new(from, to, animation_data.clone());
         // Safety: The BindingCallable is for type T
         unsafe {
-            handle.0.set_binding(move |val: *mut ()| {
-                let (value, finished) = d.borrow_mut().compute_interpolated_value();
-                *(val as *mut T) = value;
-                if finished {
-                    BindingResult::RemoveBinding
-                } else {
-                    crate::animations::CURRENT_ANIMATION_DRIVER
-                        .with(|driver| driver.set_has_active_animations());
-                    BindingResult::KeepBinding
-                }
-            })
+            handle.0.set_binding(animated_value_binding(d, move || {
+                compute_animation_details.map(|compute| compute(user_data.user_data))
+            }))
         };
     }
 
     /// Internal function to set up a property animation to the specified target value for an integer property.
+    ///
+    /// If compute_animation_details is set, it is called while the animation runs, with the
+    /// user_data, so that the animation can be paused and resumed.
     #[no_mangle]
     pub unsafe extern "C" fn sixtyfps_property_set_animated_value_int(
         handle: &PropertyHandleOpaque,
         from: i32,
         to: i32,
         animation_data: &PropertyAnimation,
+        compute_animation_details: Option<
+            extern "C" fn(user_data: *mut c_void) -> PropertyAnimation,
+        >,
+        user_data: *mut c_void,
+        drop_user_data: Option<extern "C" fn(*mut c_void)>,
     ) {
-        c_set_animated_value(handle, from, to, animation_data)
+        c_set_animated_value(
+            handle,
+            from,
+            to,
+            animation_data,
+            compute_animation_details,
+            user_data,
+            drop_user_data,
+        )
     }
 
     /// Internal function to set up a property animation to the specified target value for a float property.
@@ -1542,8 +2247,21 @@ fn c_set_animated_value<T: InterpolatedPropertyValue>(
         from: f32,
         to: f32,
         animation_data: &PropertyAnimation,
+        compute_animation_details: Option<
+            extern "C" fn(user_data: *mut c_void) -> PropertyAnimation,
+        >,
+        user_data: *mut c_void,
+        drop_user_data: Option<extern "C" fn(*mut c_void)>,
     ) {
-        c_set_animated_value(handle, from, to, animation_data)
+        c_set_animated_value(
+            handle,
+            from,
+            to,
+            animation_data,
+            compute_animation_details,
+            user_data,
+            drop_user_data,
+        )
     }
 
     /// Internal function to set up a property animation to the specified target value for a color property.
@@ -1553,8 +2271,21 @@ fn c_set_animated_value<T: InterpolatedPropertyValue>(
         from: Color,
         to: Color,
         animation_data: &PropertyAnimation,
+        compute_animation_details: Option<
+            extern "C" fn(user_data: *mut c_void) -> PropertyAnimation,
+        >,
+        user_data: *mut c_void,
+        drop_user_data: Option<extern "C" fn(*mut c_void)>,
     ) {
-        c_set_animated_value(handle, from, to, animation_data);
+        c_set_animated_value(
+            handle,
+            from,
+            to,
+            animation_data,
+            compute_animation_details,
+            user_data,
+            drop_user_data,
+        );
     }
 
     unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue>(
@@ -1594,9 +2325,15 @@ unsafe fn c_set_animated_binding<T: InterpolatedPropertyValue>(
                 state: Cell::new(AnimatedBindingState::NotAnimating),
                 animation_data,
                 compute_animation_details: move || -> AnimationDetail {
-                    let mut start_instant = 0;
+                    // Left untouched by the callback if the animation starts when the binding changes
+                    let mut start_instant = u64::MAX;
                     let anim = transition_data(user_data, &mut start_instant);
-                    Some((anim, crate::animations::Instant(start_instant)))
+                    let start_instant = if start_instant == u64::MAX {
+                        None
+                    } else {
+                        Some(crate::animations::Instant(start_instant))
+                    };
+                    Some((anim, start_instant))
                 },
             });
         } else {
@@ -1749,4 +2486,64 @@ pub struct PropertyTrackerOpaque {
     pub unsafe extern "C" fn sixtyfps_property_tracker_drop(handle: *mut PropertyTrackerOpaque) {
         core::ptr::read(handle as *mut PropertyTracker);
     }
+
+    #[repr(C)]
+    /// Opaque type representing the ChangeTracker
+    pub struct ChangeTrackerOpaque {
+        inner: *const c_void,
+    }
+
+    static_assertions::assert_eq_align!(ChangeTrackerOpaque, ChangeTracker);
+    static_assertions::assert_eq_size!(ChangeTrackerOpaque, ChangeTracker);
+
+    /// Initialize the ChangeTracker.
+    /// `out` is assumed to be uninitialized
+    /// sixtyfps_change_tracker_drop need to be called after that
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_change_tracker_construct(out: *mut ChangeTrackerOpaque) {
+        core::ptr::write(out as *mut ChangeTracker, ChangeTracker::default());
+    }
+
+    /// Set the functions of the tracker. `eval_fn` evaluates the expression and returns true if
+    /// its value is different from the previous call. `notify_fn` is the handler.
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_change_tracker_init(
+        handle: *const ChangeTrackerOpaque,
+        user_data: *mut c_void,
+        drop_user_data: Option<extern "C" fn(user_data: *mut c_void)>,
+        eval_fn: extern "C" fn(user_data: *mut c_void) -> bool,
+        notify_fn: extern "C" fn(user_data: *mut c_void),
+    ) {
+        struct CUserData {
+            user_data: *mut c_void,
+            drop_user_data: Option<extern "C" fn(*mut c_void)>,
+        }
+
+        impl Drop for CUserData {
+            fn drop(&mut self) {
+                if let Some(x) = self.drop_user_data {
+                    x(self.user_data)
+                }
+            }
+        }
+
+        let data = Rc::new(CUserData { user_data, drop_user_data });
+        let notify_data = data.clone();
+        (*(handle as *const ChangeTracker)).init_impl(
+            Box::new(move || eval_fn(data.user_data)),
+            Rc::new(move || notify_fn(notify_data.user_data)),
+        )
+    }
+
+    /// Destroy the ChangeTracker
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_change_tracker_drop(handle: *mut ChangeTrackerOpaque) {
+        core::ptr::read(handle as *mut ChangeTracker);
+    }
+
+    /// Call the handlers of the change trackers whose value changed
+    #[no_mangle]
+    pub extern "C" fn sixtyfps_run_change_handlers() -> bool {
+        ChangeTracker::run_change_handlers()
+    }
 }
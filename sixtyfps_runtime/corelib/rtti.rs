@@ -35,10 +35,22 @@ pub trait ValueType: 'static $(+ TryInto<$ty> + TryFrom<$ty>)* {}
     crate::Color,
     crate::PathData,
     crate::animations::EasingCurve,
+    crate::SharedVector<crate::animations::KeyFrame>,
     crate::items::TextHorizontalAlignment,
     crate::items::TextVerticalAlignment,
     crate::model::StandardListViewItem,
+    crate::model::TreeViewItem,
     crate::items::ImageFit,
+    crate::items::PointerEventButton,
+    crate::items::PointerEventKind,
+    crate::items::PointerEvent,
+    crate::items::MouseCursor,
+    crate::items::AccessibleRole,
+    crate::items::SortOrder,
+    crate::model::TableColumn,
+    crate::items::DropEvent,
+    crate::items::KeyboardModifierState,
+    crate::items::KeyboardEvent,
 ];
 
 /// What kind of animation is on a binding
@@ -49,27 +61,14 @@ pub enum AnimatedBindingKind {
     Animation(PropertyAnimation),
     /// Transition
     Transition(Box<dyn Fn() -> (PropertyAnimation, crate::animations::Instant)>),
-}
-
-impl AnimatedBindingKind {
-    /// return a PropertyAnimation if self contains AnimatedBindingKind::Animation
-    pub fn as_animation(self) -> Option<PropertyAnimation> {
-        match self {
-            AnimatedBindingKind::NotAnimated => None,
-            AnimatedBindingKind::Animation(a) => Some(a),
-            AnimatedBindingKind::Transition(_) => None,
-        }
-    }
+    /// Single animation whose details depend on other properties
+    DynamicAnimation(Box<dyn Fn() -> PropertyAnimation>),
 }
 
 pub trait PropertyInfo<Item, Value> {
     fn get(&self, item: Pin<&Item>) -> Result<Value, ()>;
-    fn set(
-        &self,
-        item: Pin<&Item>,
-        value: Value,
-        animation: Option<PropertyAnimation>,
-    ) -> Result<(), ()>;
+    fn set(&self, item: Pin<&Item>, value: Value, animation: AnimatedBindingKind)
+        -> Result<(), ()>;
     fn set_binding(
         &self,
         item: Pin<&Item>,
@@ -110,9 +109,9 @@ fn set(
         &self,
         item: Pin<&Item>,
         value: Value,
-        animation: Option<PropertyAnimation>,
+        animation: AnimatedBindingKind,
     ) -> Result<(), ()> {
-        if animation.is_some() {
+        if !matches!(animation, AnimatedBindingKind::NotAnimated) {
             Err(())
         } else {
             self.apply_pin(item).set(value.try_into().map_err(|_| ())?);
@@ -166,13 +165,23 @@ fn set(
         &self,
         item: Pin<&Item>,
         value: Value,
-        animation: Option<PropertyAnimation>,
+        animation: AnimatedBindingKind,
     ) -> Result<(), ()> {
-        if let Some(animation) = animation {
-            self.apply_pin(item).set_animated_value(value.try_into().map_err(|_| ())?, animation);
-            Ok(())
-        } else {
-            self.0.set(item, value, None)
+        match animation {
+            AnimatedBindingKind::Animation(animation) => {
+                self.apply_pin(item)
+                    .set_animated_value(value.try_into().map_err(|_| ())?, animation);
+                Ok(())
+            }
+            AnimatedBindingKind::DynamicAnimation(animation) => {
+                self.apply_pin(item)
+                    .set_dynamic_animated_value(value.try_into().map_err(|_| ())?, animation);
+                Ok(())
+            }
+            // A transition only applies to bindings
+            AnimatedBindingKind::NotAnimated | AnimatedBindingKind::Transition(_) => {
+                self.0.set(item, value, AnimatedBindingKind::NotAnimated)
+            }
         }
     }
     fn set_binding(
@@ -203,6 +212,15 @@ fn set_binding(
                 );
                 Ok(())
             }
+            AnimatedBindingKind::DynamicAnimation(animation) => {
+                self.apply_pin(item).set_dynamic_animated_binding(
+                    move || {
+                        binding().try_into().map_err(|_| ()).expect("binding was of the wrong type")
+                    },
+                    animation,
+                );
+                Ok(())
+            }
         }
     }
     fn offset(&self) -> usize {
@@ -233,12 +251,92 @@ fn set_field(&self, item: &mut Item, value: Value) -> Result<(), ()> {
     }
 }
 
+pub trait CallbackInfo<Item, Value> {
+    fn call(&self, item: Pin<&Item>, args: &[Value]) -> Result<Value, ()>;
+    fn set_handler(
+        &self,
+        item: Pin<&Item>,
+        handler: Box<dyn Fn(&[Value]) -> Value>,
+    ) -> Result<(), ()>;
+}
+
+impl<Item, Value: Default + 'static> CallbackInfo<Item, Value>
+    for FieldOffset<Item, crate::Callback<()>>
+{
+    fn call(&self, item: Pin<&Item>, _args: &[Value]) -> Result<Value, ()> {
+        self.apply_pin(item).emit(&());
+        Ok(Value::default())
+    }
+
+    fn set_handler(
+        &self,
+        item: Pin<&Item>,
+        handler: Box<dyn Fn(&[Value]) -> Value>,
+    ) -> Result<(), ()> {
+        self.apply_pin(item).set_handler(move |()| {
+            handler(&[]);
+        });
+        Ok(())
+    }
+}
+
+impl<Item, A: Clone + 'static, Value: Default + Clone + 'static> CallbackInfo<Item, Value>
+    for FieldOffset<Item, crate::Callback<(A,)>>
+where
+    Value: TryInto<A>,
+    A: TryInto<Value>,
+{
+    fn call(&self, item: Pin<&Item>, args: &[Value]) -> Result<Value, ()> {
+        let arg = args.first().ok_or(())?.clone().try_into().map_err(|_| ())?;
+        self.apply_pin(item).emit(&(arg,));
+        Ok(Value::default())
+    }
+
+    fn set_handler(
+        &self,
+        item: Pin<&Item>,
+        handler: Box<dyn Fn(&[Value]) -> Value>,
+    ) -> Result<(), ()> {
+        self.apply_pin(item).set_handler(move |(a,)| {
+            if let Ok(v) = a.clone().try_into() {
+                handler(&[v]);
+            }
+        });
+        Ok(())
+    }
+}
+
+impl<Item, A: Clone + 'static, Value: Default + Clone + 'static> CallbackInfo<Item, Value>
+    for FieldOffset<Item, crate::Callback<(A,), bool>>
+where
+    Value: TryInto<A> + TryInto<bool>,
+    A: TryInto<Value>,
+    bool: TryInto<Value>,
+{
+    fn call(&self, item: Pin<&Item>, args: &[Value]) -> Result<Value, ()> {
+        let arg = args.first().ok_or(())?.clone().try_into().map_err(|_| ())?;
+        self.apply_pin(item).emit(&(arg,)).try_into().map_err(|_| ())
+    }
+
+    fn set_handler(
+        &self,
+        item: Pin<&Item>,
+        handler: Box<dyn Fn(&[Value]) -> Value>,
+    ) -> Result<(), ()> {
+        self.apply_pin(item).set_handler(move |(a,)| {
+            a.clone()
+                .try_into()
+                .ok()
+                .and_then(|v| TryInto::<bool>::try_into(handler(&[v])).ok())
+                .unwrap_or_default()
+        });
+        Ok(())
+    }
+}
+
 pub trait BuiltinItem: Sized {
     fn name() -> &'static str;
     fn properties<Value: ValueType>() -> Vec<(&'static str, &'static dyn PropertyInfo<Self, Value>)>;
     fn fields<Value: ValueType>() -> Vec<(&'static str, &'static dyn FieldInfo<Self, Value>)>;
-    fn callbacks() -> Vec<(
-        &'static str,
-        const_field_offset::FieldOffset<Self, crate::Callback<()>, const_field_offset::AllowPin>,
-    )>;
+    fn callbacks<Value: ValueType>() -> Vec<(&'static str, &'static dyn CallbackInfo<Self, Value>)>;
 }
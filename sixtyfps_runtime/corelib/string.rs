@@ -48,6 +48,54 @@ pub fn as_str(&self) -> &str {
         }
     }
 
+    /// Creates a string from a number, with at most 6 decimals and without trailing zeros,
+    /// so that rounding errors are not shown.
+    ///
+    /// ```
+    /// # use sixtyfps_corelib::SharedString;
+    /// assert_eq!(SharedString::from_number(42.), "42");
+    /// assert_eq!(SharedString::from_number(0.1 + 0.2), "0.3");
+    /// ```
+    pub fn from_number(n: f64) -> Self {
+        Self::from_formatted_number(n, 0, -1, false)
+    }
+
+    /// Creates a string from a number with `precision` decimals, padded to `width` characters
+    /// with spaces, or with zeros after the sign if `zero_padding` is true.
+    /// With a negative precision, the number is formatted like [`from_number`](#method.from_number).
+    ///
+    /// ```
+    /// # use sixtyfps_corelib::SharedString;
+    /// assert_eq!(SharedString::from_formatted_number(3.14159, 0, 2, false), "3.14");
+    /// assert_eq!(SharedString::from_formatted_number(-4.5, 6, 1, true), "-004.5");
+    /// assert_eq!(SharedString::from_formatted_number(42., 4, -1, false), "  42");
+    /// ```
+    pub fn from_formatted_number(n: f64, width: usize, precision: i32, zero_padding: bool) -> Self {
+        let mut s = if precision < 0 {
+            let mut s = format!("{:.6}", n);
+            if s.contains('.') {
+                let len = s.trim_end_matches('0').trim_end_matches('.').len();
+                s.truncate(len);
+            }
+            if s == "-0" {
+                s = "0".into();
+            }
+            s
+        } else {
+            format!("{:.*}", precision as usize, n)
+        };
+        let len = s.chars().count();
+        if len < width {
+            if zero_padding && n.is_finite() {
+                let sign_len = if s.starts_with('-') { 1 } else { 0 };
+                s.insert_str(sign_len, &"0".repeat(width - len));
+            } else {
+                s.insert_str(0, &" ".repeat(width - len));
+            }
+        }
+        s.as_str().into()
+    }
+
     /// Append a string to this string
     ///
     /// ```
@@ -363,9 +411,24 @@ pub extern "C" fn sixtyfps_shared_string_bytes(ss: &SharedString) -> *const c_ch
     /// The resulting structure must be passed to sixtyfps_shared_string_drop
     #[no_mangle]
     pub unsafe extern "C" fn sixtyfps_shared_string_from_number(out: *mut SharedString, n: f64) {
-        // TODO: implement Write for SharedString so this can be done without alocation
-        let str = format!("{}", n);
-        core::ptr::write(out, SharedString::from(str.as_str()));
+        core::ptr::write(out, SharedString::from_number(n));
+    }
+
+    /// Create a string from a number with the given width and precision,
+    /// see SharedString::from_formatted_number.
+    /// The resulting structure must be passed to sixtyfps_shared_string_drop
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_shared_string_from_formatted_number(
+        out: *mut SharedString,
+        n: f64,
+        width: usize,
+        precision: i32,
+        zero_padding: bool,
+    ) {
+        core::ptr::write(
+            out,
+            SharedString::from_formatted_number(n, width, precision, zero_padding),
+        );
     }
 
     #[test]
@@ -389,6 +452,61 @@ fn test_sixtyfps_shared_string_from_number() {
         }
     }
 
+    #[test]
+    fn test_sixtyfps_shared_string_from_number_rounding() {
+        unsafe {
+            let mut s = core::mem::MaybeUninit::uninit();
+            sixtyfps_shared_string_from_number(s.as_mut_ptr(), 0.1 + 0.2);
+            assert_eq!(s.assume_init(), "0.3");
+
+            let mut s = core::mem::MaybeUninit::uninit();
+            sixtyfps_shared_string_from_number(s.as_mut_ptr(), (0.1f32 + 0.2f32) as f64);
+            assert_eq!(s.assume_init(), "0.3");
+
+            let mut s = core::mem::MaybeUninit::uninit();
+            sixtyfps_shared_string_from_formatted_number(s.as_mut_ptr(), 7., 3, 0, true);
+            assert_eq!(s.assume_init(), "007");
+        }
+    }
+
+    /// Returns the number of characters (unicode scalar values) in the string
+    #[no_mangle]
+    pub extern "C" fn sixtyfps_shared_string_character_count(ss: &SharedString) -> usize {
+        ss.chars().count()
+    }
+
+    /// Create the upper case version of a string.
+    /// The resulting structure must be passed to sixtyfps_shared_string_drop
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_shared_string_to_uppercase(
+        out: *mut SharedString,
+        ss: &SharedString,
+    ) {
+        core::ptr::write(out, SharedString::from(ss.to_uppercase()));
+    }
+
+    /// Create the lower case version of a string.
+    /// The resulting structure must be passed to sixtyfps_shared_string_drop
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_shared_string_to_lowercase(
+        out: *mut SharedString,
+        ss: &SharedString,
+    ) {
+        core::ptr::write(out, SharedString::from(ss.to_lowercase()));
+    }
+
+    /// Create a string from the `length` characters of `ss` that start at the character `start`.
+    /// The resulting structure must be passed to sixtyfps_shared_string_drop
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_shared_string_substring(
+        out: *mut SharedString,
+        ss: &SharedString,
+        start: usize,
+        length: usize,
+    ) {
+        core::ptr::write(out, ss.chars().skip(start).take(length).collect::<String>().into());
+    }
+
     /// Append some bytes to an existing shared string
     ///
     /// bytes must be a valid utf8 array of size `len`, without null bytes inside
@@ -11,18 +11,27 @@
 #![warn(missing_docs)]
 
 use crate::input::{MouseEvent, MouseEventType};
+use crate::items::PointerEventButton;
 
 /// SixtyFPS animations do not use real time, but use a mocked time.
 /// Normally, the event loop update the time of the animation using
 /// real time, but in tests, it is more convinient to use the fake time.
-/// This function will add some milliseconds to the fake time
+/// This function will add some milliseconds to the fake time.
+/// Like an iteration of the event loop, it also calls the handlers of the
+/// `changed` callbacks whose property has changed, and the timers that expired.
+/// From the first call, the timers use the fake time instead of the real time.
 #[no_mangle]
 pub extern "C" fn sixtyfps_mock_elapsed_time(time_in_ms: u64) {
+    crate::timers::use_mocked_time();
+    // Changes made before the time elapsed may start or stop timers
+    crate::properties::ChangeTracker::run_change_handlers();
     crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| {
         let mut tick = driver.current_tick();
         tick += instant::Duration::from_millis(time_in_ms);
         driver.update_animations(tick)
-    })
+    });
+    crate::timers::TimerList::maybe_activate_timers();
+    crate::properties::ChangeTracker::run_change_handlers();
 }
 
 /// Simulate a click on a position within the component.
@@ -32,6 +41,18 @@ pub extern "C" fn sixtyfps_send_mouse_click(
     x: f32,
     y: f32,
     window: &crate::eventloop::ComponentWindow,
+) {
+    sixtyfps_send_mouse_button_click(component, x, y, PointerEventButton::left, window)
+}
+
+/// Simulate a click with the given mouse button on a position within the component.
+#[no_mangle]
+pub extern "C" fn sixtyfps_send_mouse_button_click(
+    component: &crate::component::ComponentRc,
+    x: f32,
+    y: f32,
+    button: PointerEventButton,
+    window: &crate::eventloop::ComponentWindow,
 ) {
     let mut state = crate::input::MouseInputState::default();
     vtable::VRc::borrow_pin(component).as_ref().apply_layout(window.0.get_geometry());
@@ -40,25 +61,92 @@ pub extern "C" fn sixtyfps_send_mouse_click(
 
     state = crate::input::process_mouse_input(
         component.clone(),
-        MouseEvent { pos, what: MouseEventType::MouseMoved },
+        MouseEvent { pos, what: MouseEventType::MouseMoved, button: PointerEventButton::none },
         window,
         state,
     );
     state = crate::input::process_mouse_input(
         component.clone(),
-        MouseEvent { pos, what: MouseEventType::MousePressed },
+        MouseEvent { pos, what: MouseEventType::MousePressed, button },
         window,
         state,
     );
     sixtyfps_mock_elapsed_time(50);
     crate::input::process_mouse_input(
         component.clone(),
-        MouseEvent { pos, what: MouseEventType::MouseReleased },
+        MouseEvent { pos, what: MouseEventType::MouseReleased, button },
+        window,
+        state,
+    );
+}
+
+/// Simulate a drag with the left mouse button, from a position to another within the component.
+#[no_mangle]
+pub extern "C" fn sixtyfps_send_mouse_drag(
+    component: &crate::component::ComponentRc,
+    from_x: f32,
+    from_y: f32,
+    to_x: f32,
+    to_y: f32,
+    window: &crate::eventloop::ComponentWindow,
+) {
+    let mut state = crate::input::MouseInputState::default();
+    vtable::VRc::borrow_pin(component).as_ref().apply_layout(window.0.get_geometry());
+
+    let from = euclid::point2(from_x, from_y);
+    let to = euclid::point2(to_x, to_y);
+    let button = PointerEventButton::left;
+
+    state = crate::input::process_mouse_input(
+        component.clone(),
+        MouseEvent {
+            pos: from,
+            what: MouseEventType::MouseMoved,
+            button: PointerEventButton::none,
+        },
+        window,
+        state,
+    );
+    state = crate::input::process_mouse_input(
+        component.clone(),
+        MouseEvent { pos: from, what: MouseEventType::MousePressed, button },
+        window,
+        state,
+    );
+    const STEPS: usize = 10;
+    for i in 1..=STEPS {
+        let pos = from.lerp(to, i as f32 / STEPS as f32);
+        sixtyfps_mock_elapsed_time(10);
+        state = crate::input::process_mouse_input(
+            component.clone(),
+            MouseEvent { pos, what: MouseEventType::MouseMoved, button },
+            window,
+            state,
+        );
+    }
+    crate::input::process_mouse_input(
+        component.clone(),
+        MouseEvent { pos: to, what: MouseEventType::MouseReleased, button },
         window,
         state,
     );
 }
 
+/// Simulate a touch event on the window of the component. Several touch points can be active
+/// at the same time, they are distinguished by their `id`.
+#[no_mangle]
+pub extern "C" fn sixtyfps_send_touch_event(
+    component: &crate::component::ComponentRc,
+    id: u64,
+    x: f32,
+    y: f32,
+    phase: crate::input::TouchPhase,
+    window: &crate::eventloop::ComponentWindow,
+) {
+    vtable::VRc::borrow_pin(component).as_ref().apply_layout(window.0.get_geometry());
+    window.process_touch_input(id, winit::dpi::PhysicalPosition::new(x as _, y as _), phase);
+}
+
 /// Simulate a change in keyboard modifiers pressed.
 #[no_mangle]
 pub extern "C" fn sixtyfps_set_keyboard_modifiers(
@@ -133,3 +221,13 @@ pub extern "C" fn send_keyboard_string_sequence(
         window.set_current_keyboard_modifiers(crate::input::NO_MODIFIER.into());
     }
 }
+
+/// Writes in `result` a textual dump of the accessibility tree of the component, with one
+/// element per line. See [`crate::accessibility::dump_accessibility_tree`].
+#[no_mangle]
+pub extern "C" fn sixtyfps_accessibility_tree_dump(
+    component: &crate::component::ComponentRc,
+    result: &mut crate::SharedString,
+) {
+    *result = crate::accessibility::dump_accessibility_tree(component).into();
+}
@@ -11,6 +11,8 @@
     Support for timers.
 
     Timers are just a bunch of callbacks sorted by expiry date.
+    Once the time is mocked in tests, the timers follow the mocked time of the animations
+    instead of the real time.
 */
 
 #![warn(missing_docs)]
@@ -184,7 +186,7 @@ pub fn next_timeout() -> Option<instant::Instant> {
     /// Activates any expired timers by calling their callback function. Returns true if any timers were
     /// activated; false otherwise.
     pub fn maybe_activate_timers() -> bool {
-        let now = instant::Instant::now();
+        let now = now();
         // Shortcut: Is there any timer worth activating?
         if TimerList::next_timeout().map(|timeout| now < timeout).unwrap_or(false) {
             return false;
@@ -261,7 +263,7 @@ fn deactivate_timer(&mut self, id: usize) {
     fn activate_timer(&mut self, timer_id: usize) {
         self.register_active_timer(ActiveTimer {
             id: timer_id,
-            timeout: instant::Instant::now() + self.timers[timer_id].duration,
+            timeout: now() + self.timers[timer_id].duration,
         });
     }
 
@@ -285,6 +287,38 @@ fn remove_timer(&mut self, timer_id: usize) {
 }
 
 thread_local!(static CURRENT_TIMERS : RefCell<TimerList> = RefCell::default());
+thread_local!(static MOCKED_TIME : Cell<bool> = Cell::new(false));
+
+/// Makes the timers follow the mocked time of the animations instead of the real time.
+/// This is called when the time is mocked in tests. The timers that are already running keep
+/// the same remaining time.
+pub(crate) fn use_mocked_time() {
+    if MOCKED_TIME.with(|mocked| mocked.replace(true)) {
+        return;
+    }
+    let real_now = instant::Instant::now();
+    let mocked_now = now();
+    CURRENT_TIMERS.with(|timers| {
+        for active_timer in timers.borrow_mut().active_timers.iter_mut() {
+            let remaining = if active_timer.timeout > real_now {
+                active_timer.timeout - real_now
+            } else {
+                core::time::Duration::from_secs(0)
+            };
+            active_timer.timeout = mocked_now + remaining;
+        }
+    })
+}
+
+/// The current time of the timers: the real time, or the time of the last animation tick once
+/// the time is mocked
+fn now() -> instant::Instant {
+    if MOCKED_TIME.with(|mocked| mocked.get()) {
+        crate::animations::CURRENT_ANIMATION_DRIVER.with(|driver| driver.current_instant())
+    } else {
+        instant::Instant::now()
+    }
+}
 
 fn lower_bound<T>(vec: &Vec<T>, mut less_than: impl FnMut(&T) -> bool) -> usize {
     let mut left = 0;
@@ -0,0 +1,626 @@
+/* LICENSE BEGIN
+    This file is part of the SixtyFPS Project -- https://sixtyfps.io
+    Copyright (c) 2020 Olivier Goffart <olivier.goffart@sixtyfps.io>
+    Copyright (c) 2020 Simon Hausmann <simon.hausmann@sixtyfps.io>
+
+    SPDX-License-Identifier: GPL-3.0-only
+    This file is also available under commercial licensing terms.
+    Please contact info@sixtyfps.io for more information.
+LICENSE END */
+/*!
+    Runtime support for the `@tr(...)` expression.
+
+    The translated strings are looked up in gettext catalogs (`.po` or `.mo` files) which
+    are registered for a language with [`add_catalog`] or [`load_catalog`].
+    The current language is selected with [`set_language`], and defaults to the language
+    of the environment. Changing the language, or the catalogs, re-evaluates the bindings
+    that use translated strings.
+*/
+
+#![warn(missing_docs)]
+use crate::properties::Property;
+use crate::SharedString;
+use core::pin::Pin;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind};
+use std::rc::Rc;
+
+/// An expression of the `plural=` part of the `Plural-Forms` header, which computes
+/// the index of the plural form for a count `n`.
+#[derive(Debug, Clone, PartialEq)]
+enum PluralExpression {
+    N,
+    Number(u64),
+    Not(Box<PluralExpression>),
+    Binary(Box<PluralExpression>, &'static str, Box<PluralExpression>),
+    Conditional(Box<PluralExpression>, Box<PluralExpression>, Box<PluralExpression>),
+}
+
+impl PluralExpression {
+    /// Parses the C expression of a `plural=` rule, such as `(n != 1)`
+    fn parse(source: &str) -> Option<Self> {
+        let mut tokens = vec![];
+        let mut rest = source.trim_start();
+        while !rest.is_empty() {
+            let len = if rest.starts_with(|c: char| c.is_ascii_digit()) {
+                rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len())
+            } else if ["==", "!=", "<=", ">=", "&&", "||"].iter().any(|op| rest.starts_with(op)) {
+                2
+            } else if rest.starts_with(|c: char| "n!<>+-*/%?:()".contains(c)) {
+                1
+            } else {
+                return None;
+            };
+            tokens.push(&rest[..len]);
+            rest = rest[len..].trim_start();
+        }
+        let mut parser = PluralParser { tokens, pos: 0 };
+        let expression = parser.parse_conditional()?;
+        if parser.pos != parser.tokens.len() {
+            return None;
+        }
+        Some(expression)
+    }
+
+    fn evaluate(&self, n: u64) -> u64 {
+        match self {
+            Self::N => n,
+            Self::Number(x) => *x,
+            Self::Not(e) => (e.evaluate(n) == 0) as u64,
+            Self::Conditional(cond, a, b) => {
+                if cond.evaluate(n) != 0 {
+                    a.evaluate(n)
+                } else {
+                    b.evaluate(n)
+                }
+            }
+            Self::Binary(lhs, op, rhs) => {
+                let (lhs, rhs) = (lhs.evaluate(n), rhs.evaluate(n));
+                match *op {
+                    "||" => (lhs != 0 || rhs != 0) as u64,
+                    "&&" => (lhs != 0 && rhs != 0) as u64,
+                    "==" => (lhs == rhs) as u64,
+                    "!=" => (lhs != rhs) as u64,
+                    "<" => (lhs < rhs) as u64,
+                    ">" => (lhs > rhs) as u64,
+                    "<=" => (lhs <= rhs) as u64,
+                    ">=" => (lhs >= rhs) as u64,
+                    "+" => lhs.wrapping_add(rhs),
+                    "-" => lhs.wrapping_sub(rhs),
+                    "*" => lhs.wrapping_mul(rhs),
+                    "/" => lhs.checked_div(rhs).unwrap_or(0),
+                    "%" => lhs.checked_rem(rhs).unwrap_or(0),
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+}
+
+struct PluralParser<'a> {
+    tokens: Vec<&'a str>,
+    pos: usize,
+}
+
+impl<'a> PluralParser<'a> {
+    /// The binary operators, from the lowest to the highest precedence
+    const BINARY_OPERATORS: &'static [&'static [&'static str]] =
+        &[&["||"], &["&&"], &["==", "!="], &["<", ">", "<=", ">="], &["+", "-"], &["*", "/", "%"]];
+
+    fn next(&mut self) -> Option<&'a str> {
+        let token = self.tokens.get(self.pos).copied();
+        self.pos += 1;
+        token
+    }
+
+    fn parse_conditional(&mut self) -> Option<PluralExpression> {
+        let condition = self.parse_binary(0)?;
+        if self.tokens.get(self.pos) != Some(&"?") {
+            return Some(condition);
+        }
+        self.pos += 1;
+        let true_expr = self.parse_conditional()?;
+        if self.next()? != ":" {
+            return None;
+        }
+        let false_expr = self.parse_conditional()?;
+        Some(PluralExpression::Conditional(
+            Box::new(condition),
+            Box::new(true_expr),
+            Box::new(false_expr),
+        ))
+    }
+
+    fn parse_binary(&mut self, level: usize) -> Option<PluralExpression> {
+        let operators = match Self::BINARY_OPERATORS.get(level) {
+            Some(operators) => operators,
+            None => return self.parse_unary(),
+        };
+        let mut lhs = self.parse_binary(level + 1)?;
+        while let Some(op) =
+            self.tokens.get(self.pos).and_then(|t| operators.iter().find(|op| *op == t))
+        {
+            self.pos += 1;
+            let rhs = self.parse_binary(level + 1)?;
+            lhs = PluralExpression::Binary(Box::new(lhs), op, Box::new(rhs));
+        }
+        Some(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Option<PluralExpression> {
+        match self.next()? {
+            "n" => Some(PluralExpression::N),
+            "!" => Some(PluralExpression::Not(Box::new(self.parse_unary()?))),
+            "(" => {
+                let expression = self.parse_conditional()?;
+                if self.next()? != ")" {
+                    return None;
+                }
+                Some(expression)
+            }
+            number => number.parse().ok().map(PluralExpression::Number),
+        }
+    }
+}
+
+/// The translations of one language, loaded from a gettext catalog.
+#[derive(Debug, Default, Clone)]
+pub struct Catalog {
+    /// The translations, indexed by context and original string.
+    /// There is one string for each plural form.
+    messages: HashMap<(String, String), Vec<String>>,
+    plural_rule: Option<PluralExpression>,
+}
+
+impl Catalog {
+    /// Parses a catalog from the content of a `.po` file.
+    /// Entries marked as fuzzy are ignored.
+    pub fn from_po(source: &str) -> std::io::Result<Self> {
+        #[derive(Default)]
+        struct Entry {
+            context: String,
+            id: String,
+            strings: Vec<String>,
+            fuzzy: bool,
+        }
+
+        #[derive(Clone, Copy)]
+        enum Field {
+            None,
+            Context,
+            Id,
+            Plural,
+            Str(usize),
+        }
+
+        let mut catalog = Self::default();
+        let mut entry = Entry::default();
+        let mut field = Field::None;
+        let mut has_msgstr = false;
+
+        let mut finish_entry = |entry: &mut Entry, has_msgstr: &mut bool| {
+            let entry = std::mem::take(entry);
+            if *has_msgstr && (!entry.fuzzy || entry.id.is_empty()) {
+                catalog.messages.insert((entry.context, entry.id), entry.strings);
+            }
+            *has_msgstr = false;
+        };
+
+        for (line_number, line) in source.lines().enumerate() {
+            let invalid =
+                || Error::new(ErrorKind::InvalidData, format!("Invalid line {}", line_number + 1));
+            let line = line.trim();
+            if line.is_empty() {
+                finish_entry(&mut entry, &mut has_msgstr);
+                field = Field::None;
+                continue;
+            }
+            if let Some(flags) = line.strip_prefix("#,") {
+                entry.fuzzy |= flags.split(',').any(|f| f.trim() == "fuzzy");
+                continue;
+            }
+            if line.starts_with('#') {
+                continue;
+            }
+            let (keyword, string) = match line.find('"') {
+                Some(pos) => (line[..pos].trim(), &line[pos..]),
+                None => return Err(invalid()),
+            };
+            let string = unescape_po_string(string).ok_or_else(invalid)?;
+            if keyword.is_empty() {
+                // continuation of the previous string
+            } else if keyword == "msgctxt" || keyword == "msgid" {
+                if has_msgstr {
+                    finish_entry(&mut entry, &mut has_msgstr);
+                }
+                field = if keyword == "msgctxt" { Field::Context } else { Field::Id };
+            } else if keyword == "msgid_plural" {
+                field = Field::Plural;
+            } else if keyword == "msgstr" {
+                field = Field::Str(0);
+            } else if let Some(index) =
+                keyword.strip_prefix("msgstr[").and_then(|k| k.strip_suffix(']'))
+            {
+                field = Field::Str(index.parse().map_err(|_| invalid())?);
+            } else {
+                return Err(invalid());
+            }
+            match field {
+                Field::None => return Err(invalid()),
+                Field::Context => entry.context += &string,
+                Field::Id => entry.id += &string,
+                Field::Plural => {}
+                Field::Str(index) => {
+                    has_msgstr = true;
+                    if entry.strings.len() <= index {
+                        entry.strings.resize(index + 1, String::new());
+                    }
+                    entry.strings[index] += &string;
+                }
+            }
+        }
+        finish_entry(&mut entry, &mut has_msgstr);
+        catalog.read_header()?;
+        Ok(catalog)
+    }
+
+    /// Parses a catalog from the content of a `.mo` file.
+    pub fn from_mo(data: &[u8]) -> std::io::Result<Self> {
+        let invalid = || Error::new(ErrorKind::InvalidData, "Invalid .mo file");
+        let big_endian = match data.get(0..4) {
+            Some([0xde, 0x12, 0x04, 0x95]) => false,
+            Some([0x95, 0x04, 0x12, 0xde]) => true,
+            _ => return Err(invalid()),
+        };
+        let word = |offset: usize| -> std::io::Result<usize> {
+            let bytes = data.get(offset..offset + 4).ok_or_else(invalid)?;
+            let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+            Ok(if big_endian { u32::from_be_bytes(bytes) } else { u32::from_le_bytes(bytes) }
+                as usize)
+        };
+        let string = |table: usize, index: usize| -> std::io::Result<&str> {
+            let len = word(table + index * 8)?;
+            let offset = word(table + index * 8 + 4)?;
+            let bytes = data.get(offset..offset + len).ok_or_else(invalid)?;
+            std::str::from_utf8(bytes).map_err(|_| invalid())
+        };
+
+        let count = word(8)?;
+        let originals = word(12)?;
+        let translations = word(16)?;
+        let mut catalog = Self::default();
+        for index in 0..count {
+            let original = string(originals, index)?;
+            let (context, original) = match original.find('\u{4}') {
+                Some(pos) => (&original[..pos], &original[pos + 1..]),
+                None => ("", original),
+            };
+            // The plural form of the original string follows a null character
+            let id = original.split('\0').next().unwrap_or_default();
+            let strings = string(translations, index)?.split('\0').map(String::from).collect();
+            catalog.messages.insert((context.into(), id.into()), strings);
+        }
+        catalog.read_header()?;
+        Ok(catalog)
+    }
+
+    /// Reads the plural rule from the `Plural-Forms` header, which is the translation of
+    /// the empty string.
+    fn read_header(&mut self) -> std::io::Result<()> {
+        let header = match self.messages.get(&(String::new(), String::new())) {
+            Some(header) => header.first().map(String::as_str).unwrap_or_default(),
+            None => return Ok(()),
+        };
+        let rule = header
+            .lines()
+            .filter_map(|line| line.strip_prefix("Plural-Forms:"))
+            .flat_map(|forms| forms.split(';'))
+            .filter_map(|part| part.trim().strip_prefix("plural="))
+            .next();
+        if let Some(rule) = rule {
+            self.plural_rule = Some(PluralExpression::parse(rule).ok_or_else(|| {
+                Error::new(ErrorKind::InvalidData, format!("Invalid plural rule: {}", rule))
+            })?);
+        }
+        Ok(())
+    }
+
+    /// Returns the translation of `original` in the given context, or None if there is no
+    /// translation. When `plural` is not empty, the form for the count `n` is returned.
+    fn lookup(&self, context: &str, original: &str, plural: &str, n: i32) -> Option<&str> {
+        let forms = self.messages.get(&(context.into(), original.into()))?;
+        let index = if plural.is_empty() {
+            0
+        } else {
+            let n = n.max(0) as u64;
+            match &self.plural_rule {
+                Some(rule) => rule.evaluate(n) as usize,
+                None => (n != 1) as usize,
+            }
+        };
+        forms.get(index).map(String::as_str).filter(|s| !s.is_empty())
+    }
+}
+
+/// Unescape a string literal from a `.po` file, including the quotes
+fn unescape_po_string(string: &str) -> Option<String> {
+    let string = string.strip_prefix('"')?.strip_suffix('"')?;
+    let mut result = String::with_capacity(string.len());
+    let mut chars = string.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        result.push(match chars.next()? {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            '"' => '"',
+            '\\' => '\\',
+            _ => return None,
+        });
+    }
+    Some(result)
+}
+
+/// Returns the language configured in the environment, for example `de_CH` for `LANG=de_CH.UTF-8`
+fn language_from_environment() -> String {
+    ["LANGUAGE", "LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .map(|value| {
+            let value = value.split(':').next().unwrap_or_default();
+            value.split(&['.', '@'][..]).next().unwrap_or_default().to_owned()
+        })
+        .find(|value| !value.is_empty())
+        .filter(|value| value != "C" && value != "POSIX")
+        .unwrap_or_default()
+}
+
+thread_local! {
+    static CATALOGS: RefCell<HashMap<String, Rc<Catalog>>> = Default::default();
+    static LANGUAGE: RefCell<String> = RefCell::new(language_from_environment());
+    /// Changed every time the language or the catalogs change, so that the bindings that
+    /// depend on a translated string are re-evaluated.
+    static GENERATION: Pin<Box<Property<u32>>> = Box::pin(Property::new(0));
+}
+
+fn mark_translations_dirty() {
+    GENERATION.with(|g| Property::set(g, g.as_ref().get_untracked().wrapping_add(1)));
+}
+
+/// Returns the catalog for the current language. A catalog for the language without
+/// territory (`de` for `de_CH`) is used if there is none for the full language.
+fn current_catalog() -> Option<Rc<Catalog>> {
+    let language = LANGUAGE.with(|l| l.borrow().clone());
+    CATALOGS.with(|catalogs| {
+        let catalogs = catalogs.borrow();
+        catalogs.get(&language).or_else(|| catalogs.get(language.split('_').next()?)).cloned()
+    })
+}
+
+/// Sets the language used to translate the strings, for example `fr` or `de_CH`.
+pub fn set_language(language: &str) {
+    LANGUAGE.with(|l| *l.borrow_mut() = language.into());
+    mark_translations_dirty();
+}
+
+/// Registers the catalog for the given language, replacing any previous catalog
+/// of that language.
+pub fn add_catalog(language: &str, catalog: Catalog) {
+    CATALOGS.with(|catalogs| catalogs.borrow_mut().insert(language.into(), Rc::new(catalog)));
+    mark_translations_dirty();
+}
+
+/// Loads a catalog for the given language from a `.mo` file, or from a `.po` file
+/// for any other extension.
+pub fn load_catalog(language: &str, path: &std::path::Path) -> std::io::Result<()> {
+    let catalog = if path.extension().map_or(false, |ext| ext == "mo") {
+        Catalog::from_mo(&std::fs::read(path)?)?
+    } else {
+        Catalog::from_po(&std::fs::read_to_string(path)?)?
+    };
+    add_catalog(language, catalog);
+    Ok(())
+}
+
+/// Replaces the placeholders of a format string: `{}` by the next argument, `{0}`, `{1}`, ...
+/// by the argument at that position, and `{n}` by the count.
+/// Placeholders that do not refer to an argument are kept as is, and so is the whole string
+/// if it is not a valid format string.
+fn format(format: &str, arguments: &[SharedString], n: i32) -> String {
+    let pieces = match sixtyfps_format_string::parse(format) {
+        Ok(pieces) => pieces,
+        Err(_) => return format.into(),
+    };
+    let mut result = String::with_capacity(format.len());
+    let mut next_argument = 0;
+    for piece in pieces {
+        match piece {
+            sixtyfps_format_string::Piece::Literal(literal) => result += literal,
+            sixtyfps_format_string::Piece::Placeholder("n") => result += &n.to_string(),
+            sixtyfps_format_string::Piece::Placeholder(placeholder) => {
+                let index = if placeholder.is_empty() {
+                    next_argument += 1;
+                    Some(next_argument - 1)
+                } else {
+                    placeholder.parse::<usize>().ok()
+                };
+                match index.and_then(|i| arguments.get(i)) {
+                    Some(argument) => result += argument.as_str(),
+                    None => {
+                        result += "{";
+                        result += placeholder;
+                        result += "}";
+                    }
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Translates `original` in the given `context` to the current language, and formats it with
+/// the arguments. When `plural` is not empty, the plural form for the count `n` is used.
+/// Strings without a translation use `original`, or `plural` if `n` is not 1.
+///
+/// This is called for each `@tr(...)` expression. When called from a binding, the binding is
+/// re-evaluated when the language changes.
+pub fn translate(
+    original: &str,
+    context: &str,
+    plural: &str,
+    n: i32,
+    arguments: &[SharedString],
+) -> SharedString {
+    GENERATION.with(|g| g.as_ref().get());
+    let catalog = current_catalog();
+    let format_string = catalog
+        .as_ref()
+        .and_then(|catalog| catalog.lookup(context, original, plural, n))
+        .unwrap_or(if plural.is_empty() || n == 1 { original } else { plural });
+    format(format_string, arguments, n).as_str().into()
+}
+
+#[allow(unsafe_code)]
+pub(crate) mod ffi {
+    use super::*;
+
+    /// Translates a string, see [`translate`].
+    /// `arguments` must point to `arguments_len` strings.
+    #[no_mangle]
+    pub unsafe extern "C" fn sixtyfps_translate(
+        original: &SharedString,
+        context: &SharedString,
+        plural: &SharedString,
+        n: i32,
+        arguments: *const SharedString,
+        arguments_len: usize,
+        out: &mut SharedString,
+    ) {
+        let arguments: &[SharedString] = if arguments_len == 0 {
+            &[]
+        } else {
+            core::slice::from_raw_parts(arguments, arguments_len)
+        };
+        *out = translate(original, context, plural, n, arguments);
+    }
+
+    /// Sets the language used to translate the strings, see [`set_language`].
+    #[no_mangle]
+    pub extern "C" fn sixtyfps_translations_set_language(language: &SharedString) {
+        set_language(language)
+    }
+
+    /// Loads a catalog for the given language, see [`load_catalog`].
+    /// Returns false if the file cannot be loaded.
+    #[no_mangle]
+    pub extern "C" fn sixtyfps_translations_load_catalog(
+        language: &SharedString,
+        path: &SharedString,
+    ) -> bool {
+        load_catalog(language, std::path::Path::new(path.as_str())).is_ok()
+    }
+}
+
+#[test]
+fn test_plural_rules() {
+    let rule = |s: &str| PluralExpression::parse(s).unwrap();
+    let germanic = rule("(n != 1)");
+    assert_eq!([0, 1, 2].iter().map(|n| germanic.evaluate(*n)).collect::<Vec<_>>(), [1, 0, 1]);
+    let polish = rule("(n==1 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2)");
+    assert_eq!(
+        [1, 2, 5, 12, 22, 25].iter().map(|n| polish.evaluate(*n)).collect::<Vec<_>>(),
+        [0, 1, 2, 2, 1, 2]
+    );
+    assert_eq!(rule("0").evaluate(42), 0);
+    assert!(PluralExpression::parse("n +").is_none());
+    assert!(PluralExpression::parse("(n").is_none());
+}
+
+#[test]
+fn test_po_catalog() {
+    let catalog = Catalog::from_po(
+        r#"
+# A comment
+msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=UTF-8\n"
+"Plural-Forms: nplurals=2; plural=(n > 1);\n"
+
+msgid "Hello {}"
+msgstr "Bonjour {}"
+
+msgctxt "menu"
+msgid "Open"
+msgstr "Ouvrir"
+
+msgid "{n} file"
+msgid_plural "{n} files"
+msgstr[0] "{n} fichier"
+msgstr[1] "{n} fichiers"
+
+#, fuzzy
+msgid "Fuzzy"
+msgstr "Flou"
+
+msgid "Multi"
+"line"
+msgstr "Multi"
+"\"ligne\""
+"#,
+    )
+    .unwrap();
+    assert_eq!(catalog.lookup("", "Hello {}", "", 1), Some("Bonjour {}"));
+    assert_eq!(catalog.lookup("menu", "Open", "", 1), Some("Ouvrir"));
+    assert_eq!(catalog.lookup("", "Open", "", 1), None);
+    assert_eq!(catalog.lookup("", "{n} file", "{n} files", 0), Some("{n} fichier"));
+    assert_eq!(catalog.lookup("", "{n} file", "{n} files", 2), Some("{n} fichiers"));
+    assert_eq!(catalog.lookup("", "Fuzzy", "", 1), None);
+    assert_eq!(catalog.lookup("", "Multiline", "", 1), Some("Multi\"ligne\""));
+    assert!(Catalog::from_po("msgid \"unterminated").is_err());
+}
+
+#[test]
+fn test_mo_catalog() {
+    // A catalog with the header and the "ctx\u{4}Hello" entry, as written by msgfmt
+    let entries: &[(&str, &str)] =
+        &[("", "Plural-Forms: nplurals=1; plural=0;\n"), ("ctx\u{4}Hello\0Hellos", "Salut")];
+    let mut data = vec![];
+    let header_len = 28 + entries.len() * 16;
+    let mut strings = vec![];
+    let mut tables = [vec![], vec![]];
+    for (table, part) in tables.iter_mut().zip([0, 1].iter()) {
+        for entry in entries {
+            let s = if *part == 0 { entry.0 } else { entry.1 };
+            table.push((s.len() as u32, (header_len + strings.len()) as u32));
+            strings.extend_from_slice(s.as_bytes());
+            strings.push(0);
+        }
+    }
+    for word in &[0x950412de, 0, entries.len() as u32, 28, 28 + entries.len() as u32 * 8, 0, 0] {
+        data.extend_from_slice(&u32::to_le_bytes(*word));
+    }
+    for (len, offset) in tables.iter().flatten() {
+        data.extend_from_slice(&len.to_le_bytes());
+        data.extend_from_slice(&offset.to_le_bytes());
+    }
+    data.extend_from_slice(&strings);
+
+    let catalog = Catalog::from_mo(&data).unwrap();
+    assert_eq!(catalog.lookup("ctx", "Hello", "Hellos", 5), Some("Salut"));
+    assert_eq!(catalog.lookup("", "Hello", "", 1), None);
+    assert!(Catalog::from_mo(&data[..20]).is_err());
+}
+
+#[test]
+fn test_format() {
+    let args = [SharedString::from("a"), SharedString::from("b")];
+    assert_eq!(format("{} and {}", &args, 1), "a and b");
+    assert_eq!(format("{1} {0} {}", &args, 1), "b a a");
+    assert_eq!(format("{n} items {{}}", &args, 42), "42 items {}");
+    assert_eq!(format("{5}", &args, 1), "{5}");
+    assert_eq!(format("{0} }", &args, 1), "{0} }");
+}
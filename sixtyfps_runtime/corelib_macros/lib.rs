@@ -60,8 +60,11 @@ pub fn builtin_item(input: TokenStream) -> TokenStream {
         .map(|f| (f.ident.as_ref().unwrap(), &f.ty))
         .unzip();
 
-    let callback_field_names =
-        fields.iter().filter(|f| is_callback(&f.ty)).map(|f| f.ident.as_ref().unwrap());
+    let (callback_field_names, callback_field_types): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .filter(|f| is_callback(&f.ty))
+        .map(|f| (f.ident.as_ref().unwrap(), &f.ty))
+        .unzip();
 
     let item_name = &input.ident;
 
@@ -85,10 +88,12 @@ fn fields<Value: ValueType>() -> Vec<(&'static str, &'static dyn FieldInfo<Self,
                     (stringify!(#plain_field_names), &O as &'static dyn FieldInfo<Self, Value> )
                 } ),*]
             }
-            fn callbacks() -> Vec<(&'static str, const_field_offset::FieldOffset<Self, Callback<()>, const_field_offset::AllowPin>)> {
-                vec![#(
-                    (stringify!(#callback_field_names),#item_name::FIELD_OFFSETS.#callback_field_names)
-                ),*]
+            fn callbacks<Value: ValueType>() -> Vec<(&'static str, &'static dyn CallbackInfo<Self, Value>)> {
+                vec![#( {
+                    const O : const_field_offset::FieldOffset<#item_name, #callback_field_types, const_field_offset::AllowPin> =
+                        #item_name::FIELD_OFFSETS.#callback_field_names;
+                    (stringify!(#callback_field_names), &O as &'static dyn CallbackInfo<Self, Value> )
+                } ),*]
             }
         }
     )
@@ -129,6 +134,17 @@ fn from(code: winit::event::VirtualKeyCode) -> Self {
                 }
             }
         }
+        impl KeyCode {
+            /// The names of the key codes, in the order of their declaration
+            pub const NAMES: &'static [&'static str] = &[#(stringify!(#variants)),*];
+            /// Returns the key code with the given name, ignoring the case
+            pub fn from_name(name: &str) -> Option<Self> {
+                #(if name.eq_ignore_ascii_case(stringify!(#variants)) {
+                    return Some(Self::#variants);
+                })*
+                None
+            }
+        }
     )
     .into()
 }
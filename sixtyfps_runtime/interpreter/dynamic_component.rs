@@ -28,7 +28,7 @@
 use sixtyfps_corelib::layout::{LayoutInfo, Padding};
 use sixtyfps_corelib::model::RepeatedComponent;
 use sixtyfps_corelib::model::Repeater;
-use sixtyfps_corelib::properties::InterpolatedPropertyValue;
+use sixtyfps_corelib::properties::{ChangeTracker, InterpolatedPropertyValue};
 use sixtyfps_corelib::rtti::{self, AnimatedBindingKind, FieldOffset, PropertyInfo};
 use sixtyfps_corelib::slice::Slice;
 use sixtyfps_corelib::{Color, Property, SharedString};
@@ -139,6 +139,44 @@ fn update(&self, index: usize, data: Self::Data) {
         s.component_type.set_property(s.borrow(), "model_data", data).unwrap();
     }
 
+    fn init(self: Pin<&Self>) {
+        generativity::make_guard!(guard);
+        let s = self.unerase(guard);
+        run_init_code(s.borrow_instance());
+    }
+
+    fn start_exit(self: Pin<&Self>) -> bool {
+        generativity::make_guard!(guard);
+        let s = self.unerase(guard);
+        let exit = match exit_transition(&s.component_type.original) {
+            Some(exit) => exit,
+            None => return false,
+        };
+        let element = exit.exiting.element.upgrade().unwrap();
+        eval::store_property(
+            s.borrow_instance(),
+            &element,
+            &exit.exiting.name,
+            eval::Value::Bool(true),
+        )
+        .expect("cannot set exiting");
+        true
+    }
+
+    fn exit_finished(self: Pin<&Self>) -> bool {
+        generativity::make_guard!(guard);
+        let s = self.unerase(guard);
+        let exit = match exit_transition(&s.component_type.original) {
+            Some(exit) => exit,
+            None => return true,
+        };
+        let element = exit.exit_finished.element.upgrade().unwrap();
+        eval::load_property(s.borrow_instance(), &element, &exit.exit_finished.name)
+            .ok()
+            .and_then(|v| v.try_into().ok())
+            .unwrap_or(true)
+    }
+
     fn listview_layout(self: Pin<&Self>, offset_y: &mut f32, viewport_width: Pin<&Property<f32>>) {
         generativity::make_guard!(guard);
         let s = self.unerase(guard);
@@ -223,11 +261,17 @@ pub(crate) struct ComponentExtraData {
     pub(crate) globals: HashMap<String, Pin<Rc<dyn crate::global_component::GlobalComponent>>>,
     pub(crate) self_weak:
         once_cell::unsync::OnceCell<vtable::VWeak<ComponentVTable, ErasedComponentBox>>,
+    /// One tracker for each `changed` callback in the component
+    pub(crate) change_trackers: once_cell::unsync::OnceCell<Vec<ChangeTracker>>,
 }
 
 impl Default for ComponentExtraData {
     fn default() -> Self {
-        Self { globals: HashMap::new(), self_weak: Default::default() }
+        Self {
+            globals: HashMap::new(),
+            self_weak: Default::default(),
+            change_trackers: Default::default(),
+        }
     }
 }
 
@@ -370,9 +414,7 @@ pub(crate) struct ItemRTTI {
     vtable: &'static ItemVTable,
     type_info: dynamic_type::StaticTypeInfo,
     pub(crate) properties: HashMap<&'static str, Box<dyn eval::ErasedPropertyInfo>>,
-    /// The uszie is an offset within this item to the Callback.
-    /// Ideally, we would need a vtable::VFieldOffset<ItemVTable, corelib::Callback<()>>
-    pub(crate) callbacks: HashMap<&'static str, usize>,
+    pub(crate) callbacks: HashMap<&'static str, Box<dyn eval::ErasedCallbackInfo>>,
 }
 
 fn rtti_for<T: 'static + Default + rtti::BuiltinItem + vtable::HasStaticVTable<ItemVTable>>(
@@ -384,7 +426,10 @@ fn rtti_for<T: 'static + Default + rtti::BuiltinItem + vtable::HasStaticVTable<I
             .into_iter()
             .map(|(k, v)| (k, Box::new(v) as Box<dyn eval::ErasedPropertyInfo>))
             .collect(),
-        callbacks: T::callbacks().into_iter().map(|(k, v)| (k, v.get_byte_offset())).collect(),
+        callbacks: T::callbacks()
+            .into_iter()
+            .map(|(k, v)| (k, Box::new(v) as Box<dyn eval::ErasedCallbackInfo>))
+            .collect(),
     };
     (T::name(), Rc::new(rtti))
 }
@@ -408,12 +453,7 @@ impl eval::ErasedPropertyInfo for FlickableViewPortPropertyInfo {
         fn get(&self, item: Pin<ItemRef>) -> eval::Value {
             (*self.0).get(viewport(item)).unwrap()
         }
-        fn set(
-            &self,
-            item: Pin<ItemRef>,
-            value: eval::Value,
-            animation: Option<PropertyAnimation>,
-        ) {
+        fn set(&self, item: Pin<ItemRef>, value: eval::Value, animation: AnimatedBindingKind) {
             (*self.0).set(viewport(item), value, animation).unwrap()
         }
         fn set_binding(
@@ -483,6 +523,12 @@ fn generate_component<'id>(
                 rtti_for::<Rectangle>(),
                 rtti_for::<BorderRectangle>(),
                 rtti_for::<TouchArea>(),
+                rtti_for::<PinchArea>(),
+                rtti_for::<DragArea>(),
+                rtti_for::<DropArea>(),
+                rtti_for::<FocusScope>(),
+                rtti_for::<Shortcut>(),
+                rtti_for::<TimerItem>(),
                 rtti_for::<Path>(),
                 rtti_for_flickable(),
                 rtti_for::<Window>(),
@@ -617,12 +663,15 @@ fn animated_property_info<T: Clone + Default + InterpolatedPropertyValue + 'stat
                 custom_callbacks.insert(name.clone(), builder.add_field_type::<Callback>());
                 continue;
             }
+            // functions are evaluated from the body in the bindings
+            Type::Function { .. } => continue,
             Type::Object { name: Some(name), .. } if name.ends_with("::StateInfo") => {
                 property_info::<sixtyfps_corelib::properties::StateInfo>()
             }
             Type::Object { .. } => property_info::<eval::Value>(),
             Type::Array(_) => property_info::<eval::Value>(),
             Type::Percent => property_info::<f32>(),
+            Type::Enumeration(e) if e.node.is_some() => property_info::<eval::Value>(),
             Type::Enumeration(e) => match e.name.as_ref() {
                 "LayoutAlignment" => property_info::<sixtyfps_corelib::layout::LayoutAlignment>(),
                 "TextHorizontalAlignment" => {
@@ -632,6 +681,12 @@ fn animated_property_info<T: Clone + Default + InterpolatedPropertyValue + 'stat
                     property_info::<sixtyfps_corelib::items::TextVerticalAlignment>()
                 }
                 "ImageFit" => property_info::<sixtyfps_corelib::items::ImageFit>(),
+                "PointerEventButton" => {
+                    property_info::<sixtyfps_corelib::items::PointerEventButton>()
+                }
+                "PointerEventKind" => property_info::<sixtyfps_corelib::items::PointerEventKind>(),
+                "MouseCursor" => property_info::<sixtyfps_corelib::items::MouseCursor>(),
+                "SortOrder" => property_info::<sixtyfps_corelib::items::SortOrder>(),
                 _ => panic!("unkown enum"),
             },
             _ => panic!("bad type"),
@@ -704,6 +759,29 @@ pub fn animation_for_property(
     property_name: &str,
 ) -> AnimatedBindingKind {
     match element.property_animations.get(property_name) {
+        Some(sixtyfps_compilerlib::object_tree::PropertyAnimation::Static(anim_elem))
+            if sixtyfps_compilerlib::generator::is_dynamic_animation(anim_elem) =>
+        {
+            let component_ptr = component.as_ptr();
+            let vtable = NonNull::from(&component.component_type.ct).cast();
+            let anim_elem = anim_elem.clone();
+            AnimatedBindingKind::DynamicAnimation(Box::new(move || -> PropertyAnimation {
+                generativity::make_guard!(guard);
+                let component = unsafe {
+                    InstanceRef::from_pin_ref(
+                        Pin::new_unchecked(vtable::VRef::from_raw(
+                            vtable,
+                            NonNull::new_unchecked(component_ptr as *mut u8),
+                        )),
+                        guard,
+                    )
+                };
+                eval::new_struct_with_bindings(
+                    &anim_elem.borrow().bindings,
+                    &mut eval::EvalLocalContext::from_component_instance(component),
+                )
+            }))
+        }
         Some(sixtyfps_compilerlib::object_tree::PropertyAnimation::Static(anim_elem)) => {
             AnimatedBindingKind::Animation(eval::new_struct_with_bindings(
                 &anim_elem.borrow().bindings,
@@ -805,12 +883,40 @@ pub fn instantiate<'id>(
         &eval::window_ref(instance_ref).unwrap(),
     );
 
+    for (name, decl) in &component_type.original.root_element.borrow().property_declarations {
+        let e = match &decl.property_type {
+            Type::Enumeration(e) if e.node.is_some() => e,
+            _ => continue,
+        };
+        if let Some(PropertiesWithinComponent { offset, prop, .. }) =
+            component_type.custom_properties.get(name)
+        {
+            // The enums declared in .60 files are stored as a Value, which must be initialized
+            // with the default value of the enum
+            let value = eval::Value::EnumerationValue(
+                e.name.clone(),
+                e.clone().default_value().to_string(),
+            );
+            unsafe {
+                prop.set(
+                    Pin::new_unchecked(&*instance_ref.as_ptr().add(*offset)),
+                    value,
+                    AnimatedBindingKind::NotAnimated,
+                )
+                .unwrap();
+            }
+        }
+    }
+
     for item_within_component in component_type.items.values() {
         unsafe {
             let item = item_within_component.item_from_component(instance_ref.as_ptr());
             let elem = item_within_component.elem.borrow();
             for (prop, expr) in &elem.bindings {
                 let ty = elem.lookup_property(prop.as_str());
+                if let Type::Function { .. } = ty {
+                    continue;
+                }
                 if let Type::Callback { .. } = ty {
                     let expr = expr.clone();
                     let component_type = component_type.clone();
@@ -819,20 +925,20 @@ pub fn instantiate<'id>(
                         NonNull::from(&component_type.ct).cast(),
                         instance.cast(),
                     ));
-                    if let Some(callback_offset) =
-                        item_within_component.rtti.callbacks.get(prop.as_str())
+                    if let Some(callback) = item_within_component.rtti.callbacks.get(prop.as_str())
                     {
-                        let callback = &*(item.as_ptr().add(*callback_offset)
-                            as *const sixtyfps_corelib::Callback<()>);
-                        callback.set_handler(move |_: &()| {
-                            generativity::make_guard!(guard);
-                            eval::eval_expression(
-                                &expr,
-                                &mut eval::EvalLocalContext::from_component_instance(
-                                    InstanceRef::from_pin_ref(c, guard),
-                                ),
-                            );
-                        })
+                        callback.set_handler(
+                            item,
+                            Box::new(move |args| {
+                                generativity::make_guard!(guard);
+                                let mut local_context =
+                                    eval::EvalLocalContext::from_function_arguments(
+                                        InstanceRef::from_pin_ref(c, guard),
+                                        args.iter().cloned().collect(),
+                                    );
+                                eval::eval_expression(&expr, &mut local_context)
+                            }),
+                        )
                     } else if let Some(callback_offset) =
                         component_type.custom_callbacks.get(prop.as_str())
                     {
@@ -869,7 +975,7 @@ pub fn instantiate<'id>(
                                             instance_ref,
                                         ),
                                     ),
-                                    maybe_animation.as_animation(),
+                                    maybe_animation,
                                 );
                             } else {
                                 let e = e.clone();
@@ -953,7 +1059,7 @@ pub fn instantiate<'id>(
                                         instance_ref,
                                     ),
                                 );
-                                prop_info.set(item, v, None).unwrap();
+                                prop_info.set(item, v, AnimatedBindingKind::NotAnimated).unwrap();
                             } else {
                                 let e = e.clone();
                                 prop_info
@@ -1021,11 +1127,80 @@ pub fn instantiate<'id>(
                 &mut eval::EvalLocalContext::from_component_instance(instance_ref),
             );
         }
+
+        let mut change_callbacks = Vec::new();
+        object_tree::recurse_elem(&component_type.original.root_element, &(), &mut |elem, _| {
+            change_callbacks.extend(elem.borrow().change_callbacks.iter().cloned());
+        });
+        if !change_callbacks.is_empty() {
+            let extra_data = component_type.extra_data_offset.apply(instance_ref.as_ref());
+            let trackers = extra_data.change_trackers.get_or_init(|| {
+                change_callbacks.iter().map(|_| ChangeTracker::default()).collect()
+            });
+            for (tracker, (nr, handler)) in trackers.iter().zip(change_callbacks.into_iter()) {
+                let weak_for_eval = vtable::VRc::downgrade(&comp_rc);
+                let weak_for_notify = weak_for_eval.clone();
+                tracker.init(
+                    move || {
+                        let comp_rc = weak_for_eval.upgrade().unwrap();
+                        generativity::make_guard!(guard);
+                        let comp = comp_rc.unerase(guard);
+                        eval::load_property(
+                            comp.borrow_instance(),
+                            &nr.element.upgrade().unwrap(),
+                            &nr.name,
+                        )
+                        .unwrap()
+                    },
+                    move || {
+                        let comp_rc = weak_for_notify.upgrade().unwrap();
+                        generativity::make_guard!(guard);
+                        let comp = comp_rc.unerase(guard);
+                        eval::eval_expression(
+                            &handler,
+                            &mut eval::EvalLocalContext::from_component_instance(
+                                comp.borrow_instance(),
+                            ),
+                        );
+                    },
+                );
+            }
+        }
+
+        // The init code of repeated components is run by the Repeater, once the model data is set
+        if !component_type.original.is_repeated() {
+            run_init_code(instance_ref);
+        }
     }
 
     comp_rc
 }
 
+/// The properties driving the `exit` transition of a repeated component, if it has one
+fn exit_transition(
+    component: &Rc<object_tree::Component>,
+) -> Option<object_tree::ExitTransitionInfo> {
+    let parent_element = component.parent_element.upgrade()?;
+    let parent_element = parent_element.borrow();
+    parent_element.repeated.as_ref()?.exit_transition.clone()
+}
+
+/// Run the `init => { ... }` handlers of the component
+fn run_init_code(instance_ref: InstanceRef) {
+    object_tree::recurse_elem(
+        &instance_ref.component_type.original.root_element,
+        &(),
+        &mut |elem, _| {
+            for code in &elem.borrow().init_code {
+                eval::eval_expression(
+                    code,
+                    &mut eval::EvalLocalContext::from_component_instance(instance_ref),
+                );
+            }
+        },
+    );
+}
+
 fn get_property_ptr(nr: &NamedReference, instance: InstanceRef) -> *const () {
     let element = nr.element.upgrade().unwrap();
     generativity::make_guard!(guard);
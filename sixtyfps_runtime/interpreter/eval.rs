@@ -20,15 +20,14 @@
 use sixtyfps_corelib as corelib;
 use sixtyfps_corelib::rtti::AnimatedBindingKind;
 use sixtyfps_corelib::{
-    graphics::PathElement, items::ItemRef, items::PropertyAnimation, Callback, Color, PathData,
-    Resource, SharedString, SharedVector,
+    graphics::PathElement, items::ItemRef, Color, PathData, Resource, SharedString, SharedVector,
 };
 use std::collections::HashMap;
 use std::rc::Rc;
 
 pub trait ErasedPropertyInfo {
     fn get(&self, item: Pin<ItemRef>) -> Value;
-    fn set(&self, item: Pin<ItemRef>, value: Value, animation: Option<PropertyAnimation>);
+    fn set(&self, item: Pin<ItemRef>, value: Value, animation: AnimatedBindingKind);
     fn set_binding(
         &self,
         item: Pin<ItemRef>,
@@ -48,7 +47,7 @@ impl<Item: vtable::HasStaticVTable<corelib::items::ItemVTable>> ErasedPropertyIn
     fn get(&self, item: Pin<ItemRef>) -> Value {
         (*self).get(ItemRef::downcast_pin(item).unwrap()).unwrap()
     }
-    fn set(&self, item: Pin<ItemRef>, value: Value, animation: Option<PropertyAnimation>) {
+    fn set(&self, item: Pin<ItemRef>, value: Value, animation: AnimatedBindingKind) {
         (*self).set(ItemRef::downcast_pin(item).unwrap(), value, animation).unwrap()
     }
     fn set_binding(
@@ -68,6 +67,22 @@ unsafe fn link_two_ways(&self, item: Pin<ItemRef>, property2: *const ()) {
     }
 }
 
+pub trait ErasedCallbackInfo {
+    fn call(&self, item: Pin<ItemRef>, args: &[Value]) -> Value;
+    fn set_handler(&self, item: Pin<ItemRef>, handler: Box<dyn Fn(&[Value]) -> Value>);
+}
+
+impl<Item: vtable::HasStaticVTable<corelib::items::ItemVTable>> ErasedCallbackInfo
+    for &'static dyn corelib::rtti::CallbackInfo<Item, Value>
+{
+    fn call(&self, item: Pin<ItemRef>, args: &[Value]) -> Value {
+        (*self).call(ItemRef::downcast_pin(item).unwrap(), args).unwrap()
+    }
+    fn set_handler(&self, item: Pin<ItemRef>, handler: Box<dyn Fn(&[Value]) -> Value>) {
+        (*self).set_handler(ItemRef::downcast_pin(item).unwrap(), handler).unwrap()
+    }
+}
+
 /// A Pointer to a model
 #[derive(Clone, derive_more::Deref, derive_more::From)]
 pub struct ModelPtr(pub Rc<dyn corelib::model::Model<Data = Value>>);
@@ -186,7 +201,30 @@ fn try_into(self) -> Result<$name, ()> {
 }
 
 declare_value_struct_conversion!(struct corelib::model::StandardListViewItem { text });
+declare_value_struct_conversion!(struct corelib::model::TreeViewItem { text, depth, has_children, expanded });
+declare_value_struct_conversion!(struct corelib::model::TableColumn { title, width, sort_order });
+declare_value_struct_conversion!(struct corelib::items::PointerEvent { button, kind });
+declare_value_struct_conversion!(struct corelib::items::DropEvent { data, mime_type });
+declare_value_struct_conversion!(struct corelib::items::KeyboardModifierState { alt, control, logo, shift });
+declare_value_struct_conversion!(struct corelib::items::KeyboardEvent { key, modifiers, text });
 declare_value_struct_conversion!(struct corelib::properties::StateInfo { current_state, previous_state, change_time });
+declare_value_struct_conversion!(struct corelib::animations::KeyFrame { position, value });
+
+impl TryFrom<SharedVector<corelib::animations::KeyFrame>> for Value {
+    type Error = ();
+    fn try_from(v: SharedVector<corelib::animations::KeyFrame>) -> Result<Self, ()> {
+        Ok(Value::Array(v.iter().map(|k| k.clone().try_into()).collect::<Result<_, _>>()?))
+    }
+}
+impl TryInto<SharedVector<corelib::animations::KeyFrame>> for Value {
+    type Error = ();
+    fn try_into(self) -> Result<SharedVector<corelib::animations::KeyFrame>, ()> {
+        match self {
+            Self::Array(a) => a.into_iter().map(|v| v.try_into()).collect(),
+            _ => Err(()),
+        }
+    }
+}
 
 macro_rules! declare_value_enum_conversion {
     ($ty:ty, $n:ident) => {
@@ -219,6 +257,11 @@ fn try_into(self) -> Result<$ty, ()> {
 declare_value_enum_conversion!(corelib::items::TextVerticalAlignment, TextVerticalAlignment);
 declare_value_enum_conversion!(corelib::layout::LayoutAlignment, LayoutAlignment);
 declare_value_enum_conversion!(corelib::items::ImageFit, ImageFit);
+declare_value_enum_conversion!(corelib::items::PointerEventButton, PointerEventButton);
+declare_value_enum_conversion!(corelib::items::PointerEventKind, PointerEventKind);
+declare_value_enum_conversion!(corelib::items::MouseCursor, MouseCursor);
+declare_value_enum_conversion!(corelib::items::AccessibleRole, AccessibleRole);
+declare_value_enum_conversion!(corelib::items::SortOrder, SortOrder);
 
 impl TryFrom<corelib::animations::Instant> for Value {
     type Error = ();
@@ -289,6 +332,7 @@ pub fn eval_expression(e: &Expression, local_context: &mut EvalLocalContext) ->
         Expression::NumberLiteral(n, unit) => Value::Number(unit.normalize(*n)),
         Expression::BoolLiteral(b) => Value::Bool(*b),
         Expression::CallbackReference { .. } => panic!("callback in expression"),
+        Expression::FunctionReference { .. } => panic!("function reference must be called"),
         Expression::BuiltinFunctionReference(_) => panic!(
             "naked builtin function reference not allowed, should be handled by function call"
         ),
@@ -318,12 +362,39 @@ pub fn eval_expression(e: &Expression, local_context: &mut EvalLocalContext) ->
                 Value::Void
             }
         }
+        Expression::ArrayIndex { array, index } => {
+            let array = eval_expression(array, local_context);
+            let index = eval_expression(index, local_context);
+            let row = match index {
+                Value::Number(n) if n >= 0. => Some(n as usize),
+                _ => None,
+            };
+            let value = match (array, row) {
+                (Value::Array(mut a), Some(row)) if row < a.len() => Some(a.swap_remove(row)),
+                (Value::Model(m), Some(row)) => {
+                    m.0.track_changes();
+                    if row < m.0.row_count() {
+                        Some(m.0.row_data(row))
+                    } else {
+                        None
+                    }
+                }
+                (Value::Model(m), None) => {
+                    m.0.track_changes();
+                    None
+                }
+                _ => None,
+            };
+            value.unwrap_or_else(|| {
+                eval_expression(&Expression::default_value_for_type(&e.ty()), local_context)
+            })
+        }
         Expression::Cast { from, to } => {
             let v = eval_expression(&*from, local_context);
             match (v, to) {
                 (Value::Number(n), Type::Int32) => Value::Number(n.round()),
                 (Value::Number(n), Type::String) => {
-                    Value::String(SharedString::from(format!("{}", n).as_str()))
+                    Value::String(SharedString::from_number(n))
                 }
                 (Value::Number(n), Type::Color) => Value::Color(Color::from_argb_encoded(n as u32)),
                 (v, _) => v,
@@ -346,11 +417,9 @@ pub fn eval_expression(e: &Expression, local_context: &mut EvalLocalContext) ->
                         let item_info = &component_type.items[element.borrow().id.as_str()];
                         let item = unsafe { item_info.item_from_component(enclosing_component.as_ptr()) };
 
-                        if let Some(callback_offset) = item_info.rtti.callbacks.get(name.as_str()) {
-                            let callback =
-                                unsafe { &*(item.as_ptr().add(*callback_offset) as *const Callback<()>) };
-                            callback.emit(&());
-                            Value::Void
+                        if let Some(callback) = item_info.rtti.callbacks.get(name.as_str()) {
+                            let args = arguments.iter().map(|e| eval_expression(e, local_context));
+                            callback.call(item, args.collect::<Vec<_>>().as_slice())
                         } else if let Some(callback_offset) = component_type.custom_callbacks.get(name.as_str())
                         {
                             let callback = callback_offset.apply(&*enclosing_component.instance);
@@ -366,6 +435,18 @@ pub fn eval_expression(e: &Expression, local_context: &mut EvalLocalContext) ->
                     }
                 }
             }
+            Expression::FunctionReference(NamedReference { element, name }) => {
+                let element = element.upgrade().unwrap();
+                let function_arguments = arguments.iter().map(|e| eval_expression(e, local_context)).collect();
+                generativity::make_guard!(guard);
+                let mut function_context = EvalLocalContext {
+                    local_variables: Default::default(),
+                    function_arguments,
+                    component_instance: enclosing_component_instance_for_element(&element, local_context.component_instance, guard),
+                };
+                let element = element.borrow();
+                eval_expression(&element.bindings[name.as_str()].expression, &mut function_context)
+            }
             Expression::BuiltinFunctionReference(BuiltinFunction::GetWindowScaleFactor) => {
                 match local_context.component_instance {
                     ComponentInstance::InstanceRef(component) => Value::Number(window_ref(component).unwrap().scale_factor() as _),
@@ -461,6 +542,67 @@ pub fn eval_expression(e: &Expression, local_context: &mut EvalLocalContext) ->
                     panic!("Argument not a string");
                 }
             }
+            Expression::BuiltinFunctionReference(BuiltinFunction::Translate) => {
+                let n: i32 = eval_expression(&arguments[3], local_context).try_into().unwrap();
+                let mut string = |e| -> SharedString { eval_expression(e, local_context).try_into().unwrap() };
+                let original = string(&arguments[0]);
+                let context = string(&arguments[1]);
+                let plural = string(&arguments[2]);
+                let values = arguments[4..].iter().map(string).collect::<Vec<_>>();
+                Value::String(corelib::translations::translate(&original, &context, &plural, n, &values))
+            }
+            Expression::BuiltinFunctionReference(BuiltinFunction::ArrayLength) => {
+                match eval_expression(&arguments[0], local_context) {
+                    Value::Array(a) => Value::Number(a.len() as f64),
+                    Value::Model(m) => {
+                        m.0.track_changes();
+                        Value::Number(m.0.row_count() as f64)
+                    }
+                    _ => Value::Number(0.),
+                }
+            }
+            Expression::BuiltinFunctionReference(BuiltinFunction::StringLength) => {
+                let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+                Value::Number(s.chars().count() as f64)
+            }
+            Expression::BuiltinFunctionReference(BuiltinFunction::StringToUpper) => {
+                let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+                Value::String(s.to_uppercase().as_str().into())
+            }
+            Expression::BuiltinFunctionReference(BuiltinFunction::StringToLower) => {
+                let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+                Value::String(s.to_lowercase().as_str().into())
+            }
+            Expression::BuiltinFunctionReference(BuiltinFunction::StringContains) => {
+                let mut string = |e| -> SharedString { eval_expression(e, local_context).try_into().unwrap() };
+                let s = string(&arguments[0]);
+                let needle = string(&arguments[1]);
+                Value::Bool(s.contains(needle.as_str()))
+            }
+            Expression::BuiltinFunctionReference(BuiltinFunction::StringSubstring) => {
+                let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+                let mut toint = |e| -> i32 { eval_expression(e, local_context).try_into().unwrap() };
+                let start = toint(&arguments[1]).max(0) as usize;
+                let length = toint(&arguments[2]).max(0) as usize;
+                Value::String(s.chars().skip(start).take(length).collect::<String>().as_str().into())
+            }
+            Expression::BuiltinFunctionReference(BuiltinFunction::NumberToFixed) => {
+                let x: f64 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+                let digits: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+                Value::String(SharedString::from_formatted_number(x, 0, digits, false))
+            }
+            Expression::BuiltinFunctionReference(BuiltinFunction::FormatNumber) => {
+                let x: f64 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+                let mut toint = |e| -> i32 { eval_expression(e, local_context).try_into().unwrap() };
+                let width = toint(&arguments[1]).max(0) as usize;
+                let precision = toint(&arguments[2]);
+                let zero_padding: bool = eval_expression(&arguments[3], local_context).try_into().unwrap();
+                Value::String(SharedString::from_formatted_number(x, width, precision, zero_padding))
+            }
+            Expression::BuiltinFunctionReference(BuiltinFunction::AnimationElapsedTime) => {
+                let state: corelib::properties::StateInfo = eval_expression(&arguments[0], local_context).try_into().unwrap();
+                Value::Number(corelib::animations::animation_elapsed_time(state.change_time).map_or(f64::MAX, |d| d.as_millis() as f64))
+            }
             _ => panic!("call of something not a callback"),
         }
         Expression::SelfAssignment { lhs, rhs, op } => {
@@ -544,6 +686,16 @@ pub fn eval_expression(e: &Expression, local_context: &mut EvalLocalContext) ->
             EasingCurve::CubicBezier(a, b, c, d) => {
                 corelib::animations::EasingCurve::CubicBezier([*a, *b, *c, *d])
             }
+            EasingCurve::EaseInElastic => corelib::animations::EasingCurve::EaseInElastic,
+            EasingCurve::EaseOutElastic => corelib::animations::EasingCurve::EaseOutElastic,
+            EasingCurve::EaseInOutElastic => corelib::animations::EasingCurve::EaseInOutElastic,
+            EasingCurve::EaseInBounce => corelib::animations::EasingCurve::EaseInBounce,
+            EasingCurve::EaseOutBounce => corelib::animations::EasingCurve::EaseOutBounce,
+            EasingCurve::EaseInOutBounce => corelib::animations::EasingCurve::EaseInOutBounce,
+            EasingCurve::EaseInBack => corelib::animations::EasingCurve::EaseInBack,
+            EasingCurve::EaseOutBack => corelib::animations::EasingCurve::EaseOutBack,
+            EasingCurve::EaseInOutBack => corelib::animations::EasingCurve::EaseInOutBack,
+            EasingCurve::Steps(n) => corelib::animations::EasingCurve::Steps(*n),
         }),
         Expression::EnumerationValue(value) => {
             Value::EnumerationValue(value.enumeration.name.clone(), value.to_string())
@@ -589,7 +741,13 @@ fn eval_assignement(lhs: &Expression, op: char, rhs: Value, local_context: &mut
                                 let p = Pin::new_unchecked(
                                     &*enclosing_component.as_ptr().add(x.offset),
                                 );
-                                x.prop.set(p, eval(x.prop.get(p).unwrap()), None).unwrap();
+                                x.prop
+                                    .set(
+                                        p,
+                                        eval(x.prop.get(p).unwrap()),
+                                        AnimatedBindingKind::NotAnimated,
+                                    )
+                                    .unwrap();
                             }
                             return;
                         }
@@ -599,7 +757,7 @@ fn eval_assignement(lhs: &Expression, op: char, rhs: Value, local_context: &mut
                     let item =
                         unsafe { item_info.item_from_component(enclosing_component.as_ptr()) };
                     let p = &item_info.rtti.properties[name.as_str()];
-                    p.set(item, eval(p.get(item)), None);
+                    p.set(item, eval(p.get(item)), AnimatedBindingKind::NotAnimated);
                 }
                 ComponentInstance::GlobalComponent(global) => {
                     let val = if op == '=' {
@@ -710,14 +868,14 @@ pub fn store_property(
         if let Some(x) = enclosing_component.component_type.custom_properties.get(name) {
             unsafe {
                 let p = Pin::new_unchecked(&*enclosing_component.as_ptr().add(x.offset));
-                return x.prop.set(p, value, maybe_animation.as_animation());
+                return x.prop.set(p, value, maybe_animation);
             }
         }
     };
     let item_info = &enclosing_component.component_type.items[element.borrow().id.as_str()];
     let item = unsafe { item_info.item_from_component(enclosing_component.as_ptr()) };
     let p = &item_info.rtti.properties.get(name).ok_or(())?;
-    p.set(item, value, maybe_animation.as_animation());
+    p.set(item, value, maybe_animation);
     Ok(())
 }
 
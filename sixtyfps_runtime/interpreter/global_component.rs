@@ -74,14 +74,26 @@ fn instantiate(component: &Rc<Component>) -> Pin<Rc<Self>> {
             component: component.clone(),
         };
         for (name, decl) in &component.root_element.borrow().property_declarations {
-            if matches!(decl.property_type, Type::Callback{..}) {
+            if matches!(decl.property_type, Type::Function{..}) {
+                continue;
+            } else if matches!(decl.property_type, Type::Callback{..}) {
                 instance.callbacks.insert(name.clone(), Box::pin(Default::default()));
+            } else if let Type::Enumeration(e) = &decl.property_type {
+                let value = eval::Value::EnumerationValue(
+                    e.name.clone(),
+                    e.clone().default_value().to_string(),
+                );
+                instance.properties.insert(name.clone(), Box::pin(Property::new(value)));
             } else {
                 instance.properties.insert(name.clone(), Box::pin(Default::default()));
             }
         }
         let rc = Rc::pin(instance);
         for (k, expr) in &component.root_element.borrow().bindings {
+            if !rc.properties.contains_key(k) {
+                // functions are evaluated from the body in the bindings
+                continue;
+            }
             if expr.expression.is_constant() {
                 rc.properties[k].as_ref().set(eval::eval_expression(
                     &expr.expression,
@@ -116,7 +128,7 @@ fn get_property(self: Pin<&Self>, prop_name: &str) -> eval::Value {
 impl<T: rtti::BuiltinItem + 'static> GlobalComponent for T {
     fn set_property(self: Pin<&Self>, prop_name: &str, value: crate::Value) {
         let prop = Self::properties().into_iter().find(|(k, _)| *k == prop_name).unwrap().1;
-        prop.set(self, value, None).unwrap()
+        prop.set(self, value, rtti::AnimatedBindingKind::NotAnimated).unwrap()
     }
 
     fn get_property(self: Pin<&Self>, prop_name: &str) -> crate::Value {
@@ -22,8 +22,10 @@
 mod value_model;
 
 pub use eval::{ModelPtr, Value};
+pub use sixtyfps_corelib::model::{FilterModel, MapModel, SortModel};
 
 use dynamic_component::InstanceRef;
+use sixtyfps_compilerlib::object_tree::PropertyVisibility;
 pub use sixtyfps_compilerlib::CompilerConfiguration;
 use sixtyfps_corelib::component::{ComponentRef, ComponentRefPin, ComponentVTable};
 use std::{collections::HashMap, pin::Pin, rc::Rc};
@@ -47,6 +49,10 @@ pub fn properties(&self) -> HashMap<String, sixtyfps_compilerlib::langtype::Type
             .borrow()
             .property_declarations
             .iter()
+            .filter(|(_, v)| {
+                !matches!(v.property_type, sixtyfps_compilerlib::langtype::Type::Function { .. })
+                    && v.visibility != PropertyVisibility::Private
+            })
             .map(|(s, v)| (s.clone(), v.property_type.clone()))
             .collect()
     }
@@ -69,10 +75,17 @@ pub fn create(
         component_ref
     }
 
+    /// Returns the visibility of a property declared in the root element, or None for the
+    /// builtin properties
+    fn property_visibility(&self, name: &str) -> Option<PropertyVisibility> {
+        self.original.root_element.borrow().property_declarations.get(name).map(|d| d.visibility)
+    }
+
     /// Set a value to property.
     ///
     /// Returns an error if the component is not an instance corresponding to this ComponentDescription,
-    /// or if the property with this name does not exist in this component
+    /// or if the property with this name does not exist in this component, or is an `out` or
+    /// `private` property
     pub fn set_property(
         &self,
         component: ComponentRefPin,
@@ -82,6 +95,12 @@ pub fn set_property(
         if !core::ptr::eq((&self.ct) as *const _, component.get_vtable() as *const _) {
             return Err(());
         }
+        if matches!(
+            self.property_visibility(name),
+            Some(PropertyVisibility::Output) | Some(PropertyVisibility::Private)
+        ) {
+            return Err(());
+        }
         generativity::make_guard!(guard);
         let c = unsafe { InstanceRef::from_pin_ref(component, guard) };
         if let Some(alias) = self
@@ -101,7 +120,8 @@ pub fn set_property(
     /// Set a binding to a property
     ///
     /// Returns an error if the component is not an instance corresponding to this ComponentDescription,
-    /// or if the property with this name does not exist in this component
+    /// or if the property with this name does not exist in this component, or is an `out` or
+    /// `private` property
     pub fn set_binding(
         &self,
         component: ComponentRef,
@@ -111,6 +131,12 @@ pub fn set_binding(
         if !core::ptr::eq((&self.ct) as *const _, component.get_vtable() as *const _) {
             return Err(());
         }
+        if matches!(
+            self.property_visibility(name),
+            Some(PropertyVisibility::Output) | Some(PropertyVisibility::Private)
+        ) {
+            return Err(());
+        }
         let x = self.custom_properties.get(name).ok_or(())?;
         unsafe {
             x.prop
@@ -127,11 +153,14 @@ pub fn set_binding(
     /// Return the value of a property
     ///
     /// Returns an error if the component is not an instance corresponding to this ComponentDescription,
-    /// or if a callback with this name does not exist in this component
+    /// or if a property with this name does not exist in this component, or is `private`
     pub fn get_property(&self, component: ComponentRefPin, name: &str) -> Result<Value, ()> {
         if !core::ptr::eq((&self.ct) as *const _, component.get_vtable() as *const _) {
             return Err(());
         }
+        if self.property_visibility(name) == Some(PropertyVisibility::Private) {
+            return Err(());
+        }
         generativity::make_guard!(guard);
         // Safety: we just verified that the component has the right vtable
         let c = unsafe { InstanceRef::from_pin_ref(component, guard) };
@@ -208,3 +237,47 @@ pub async fn load(
     })
     .await
 }
+
+#[test]
+fn test_model_adapters() {
+    use sixtyfps_corelib::model::VecModel;
+    let (definition, diag) = spin_on::spin_on(load(
+        r#"
+        TestCase := Rectangle {
+            property <[int]> model;
+            property <int> first: model[0];
+            property <int> second: model[1];
+        }"#
+        .into(),
+        Default::default(),
+        new_compiler_configuration(),
+    ));
+    let definition = definition.unwrap_or_else(|()| panic!("{:?}", diag.to_string_vec()));
+    let instance = definition.clone().create();
+
+    let source = Rc::new(VecModel::from(
+        [3., 1., 4., 1., 5.].iter().map(|n| Value::Number(*n)).collect::<Vec<_>>(),
+    ));
+    let filtered: FilterModel<Value> =
+        FilterModel::new(source.clone(), |v| matches!(v, Value::Number(n) if *n > 1.));
+    let sorted: SortModel<Value> = SortModel::new(Rc::new(filtered), |a, b| match (a, b) {
+        (Value::Number(a), Value::Number(b)) => b.partial_cmp(a).unwrap(),
+        _ => core::cmp::Ordering::Equal,
+    });
+    let mapped: MapModel<Value, Value> = MapModel::new(Rc::new(sorted), |v| match v {
+        Value::Number(n) => Value::Number(n * 2.),
+        v => v,
+    });
+    definition
+        .set_property(instance.borrow(), "model", Value::Model(ModelPtr(Rc::new(mapped))))
+        .unwrap();
+    assert_eq!(definition.get_property(instance.borrow(), "first"), Ok(Value::Number(10.)));
+    assert_eq!(definition.get_property(instance.borrow(), "second"), Ok(Value::Number(8.)));
+
+    // The changes of the source model go through the adapters
+    source.push(Value::Number(9.));
+    assert_eq!(definition.get_property(instance.borrow(), "first"), Ok(Value::Number(18.)));
+    assert_eq!(definition.get_property(instance.borrow(), "second"), Ok(Value::Number(10.)));
+    source.remove(4);
+    assert_eq!(definition.get_property(instance.borrow(), "second"), Ok(Value::Number(8.)));
+}
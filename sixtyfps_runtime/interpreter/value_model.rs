@@ -60,6 +60,13 @@ fn attach_peer(&self, peer: sixtyfps_corelib::model::ModelPeer) {
         self.notify.attach(peer)
     }
 
+    fn track_changes(&self) {
+        if let Value::Model(ref model_ptr) = *self.value.borrow() {
+            model_ptr.track_changes()
+        }
+        self.notify.track_changes()
+    }
+
     fn set_row_data(&self, row: usize, data: Self::Data) {
         match &mut *self.value.borrow_mut() {
             Value::Array(a) => {
@@ -65,7 +65,9 @@ pub fn use_modules() -> usize {
     (widgets::NativeScrollView,
     (widgets::NativeStandardListViewItem,
     (widgets::NativeComboBox,
-            ())))))))));
+    (widgets::NativeTreeViewItem,
+    (widgets::NativeTableHeaderSection,
+            ())))))))))));
 
 #[cfg(not(no_qt))]
 #[rustfmt::skip]
@@ -33,7 +33,7 @@
     FocusEvent, InputEventResult, KeyEvent, KeyEventResult, MouseEvent, MouseEventType,
 };
 use sixtyfps_corelib::item_rendering::CachedRenderingData;
-use sixtyfps_corelib::items::{Item, ItemConsts, ItemRc, ItemVTable};
+use sixtyfps_corelib::items::{Item, ItemConsts, ItemRc, ItemVTable, VoidArg};
 use sixtyfps_corelib::layout::LayoutInfo;
 use sixtyfps_corelib::rtti::*;
 use sixtyfps_corelib::{Callback, ItemVTable_static, Property, SharedString, SharedVector};
@@ -114,7 +114,8 @@ pub struct NativeButton {
     pub text: Property<SharedString>,
     pub enabled: Property<bool>,
     pub pressed: Property<bool>,
-    pub clicked: Callback<()>,
+    pub focused: Property<bool>,
+    pub clicked: Callback<VoidArg>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -136,6 +137,7 @@ fn rendering_primitive(
         let down: bool = Self::FIELD_OFFSETS.pressed.apply_pin(self).get();
         let text: qttypes::QString = Self::FIELD_OFFSETS.text.apply_pin(self).get().as_str().into();
         let enabled = Self::FIELD_OFFSETS.enabled.apply_pin(self).get();
+        let focused = Self::FIELD_OFFSETS.focused.apply_pin(self).get();
         let size: qttypes::QSize = get_size!(self);
         let dpr = window.scale_factor();
 
@@ -146,6 +148,7 @@ fn rendering_primitive(
             img as "QImage*",
             text as "QString",
             enabled as "bool",
+            focused as "bool",
             size as "QSize",
             down as "bool",
             dpr as "float"
@@ -158,6 +161,8 @@ fn rendering_primitive(
                 option.state |= QStyle::State_Sunken;
             else
                 option.state |= QStyle::State_Raised;
+            if (focused)
+                option.state |= QStyle::State_HasFocus;
             if (enabled) {
                 option.state |= QStyle::State_Enabled;
             } else {
@@ -248,9 +253,10 @@ pub struct NativeCheckBox {
     pub width: Property<f32>,
     pub height: Property<f32>,
     pub enabled: Property<bool>,
-    pub toggled: Callback<()>,
+    pub toggled: Callback<VoidArg>,
     pub text: Property<SharedString>,
     pub checked: Property<bool>,
+    pub focused: Property<bool>,
     pub cached_rendering_data: CachedRenderingData,
 }
 
@@ -271,6 +277,7 @@ fn rendering_primitive(
     ) -> HighLevelRenderingPrimitive {
         let checked: bool = Self::FIELD_OFFSETS.checked.apply_pin(self).get();
         let enabled = Self::FIELD_OFFSETS.enabled.apply_pin(self).get();
+        let focused = Self::FIELD_OFFSETS.focused.apply_pin(self).get();
         let text: qttypes::QString = Self::FIELD_OFFSETS.text.apply_pin(self).get().as_str().into();
         let size: qttypes::QSize = get_size!(self);
         let dpr = window.scale_factor();
@@ -281,6 +288,7 @@ fn rendering_primitive(
         cpp!(unsafe [
             img as "QImage*",
             enabled as "bool",
+            focused as "bool",
             text as "QString",
             size as "QSize",
             checked as "bool",
@@ -291,6 +299,8 @@ fn rendering_primitive(
             option.text = std::move(text);
             option.rect = QRect(QPoint(), size / dpr);
             option.state |= checked ? QStyle::State_On : QStyle::State_Off;
+            if (focused)
+                option.state |= QStyle::State_HasFocus;
             if (enabled) {
                 option.state |= QStyle::State_Enabled;
             } else {
@@ -1682,6 +1692,290 @@ impl ItemConsts for NativeStandardListViewItem {
 
 ItemVTable_static! { #[no_mangle] pub static NativeStandardListViewItemVTable for NativeStandardListViewItem }
 
+/// The indentation of each level of a tree, in logical pixels. Must be kept in sync with the
+/// TreeView widget of the native style.
+const TREE_INDENTATION: f32 = 20.;
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, BuiltinItem)]
+#[pin]
+pub struct NativeTreeViewItem {
+    pub x: Property<f32>,
+    pub y: Property<f32>,
+    pub width: Property<f32>,
+    pub height: Property<f32>,
+    pub item: Property<sixtyfps_corelib::model::TreeViewItem>,
+    pub index: Property<i32>,
+    pub is_selected: Property<bool>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for NativeTreeViewItem {
+    fn init(self: Pin<&Self>, _window: &ComponentWindow) {}
+
+    fn geometry(self: Pin<&Self>) -> Rect {
+        euclid::rect(
+            Self::FIELD_OFFSETS.x.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.y.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.width.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.height.apply_pin(self).get(),
+        )
+    }
+    fn rendering_primitive(
+        self: Pin<&Self>,
+        window: &ComponentWindow,
+    ) -> HighLevelRenderingPrimitive {
+        let size: qttypes::QSize = get_size!(self);
+        let dpr = window.scale_factor();
+        let index: i32 = Self::FIELD_OFFSETS.index.apply_pin(self).get();
+        let is_selected: bool = Self::FIELD_OFFSETS.is_selected.apply_pin(self).get();
+        let item = Self::FIELD_OFFSETS.item.apply_pin(self).get();
+        let text: qttypes::QString = item.text.as_str().into();
+        let depth = item.depth;
+        let has_children = item.has_children;
+        let expanded = item.expanded;
+        let indentation = TREE_INDENTATION;
+
+        let mut imgarray = QImageWrapArray::new(size, dpr);
+        let img = &mut imgarray.img;
+
+        cpp!(unsafe [
+            img as "QImage*",
+            size as "QSize",
+            dpr as "float",
+            index as "int",
+            is_selected as "bool",
+            text as "QString",
+            depth as "int",
+            has_children as "bool",
+            expanded as "bool",
+            indentation as "float"
+        ] {
+            QPainter p(img);
+            QStyleOptionViewItem option;
+            option.rect = QRect(QPoint(), size / dpr);
+            option.state = QStyle::State_Enabled | QStyle::State_Active;
+            if (is_selected) {
+                option.state |= QStyle::State_Selected;
+            }
+            option.decorationPosition = QStyleOptionViewItem::Left;
+            option.decorationAlignment = Qt::AlignCenter;
+            option.displayAlignment = Qt::AlignLeft|Qt::AlignVCenter;
+            option.showDecorationSelected = qApp->style()->styleHint(QStyle::SH_ItemView_ShowDecorationSelected, nullptr, nullptr);
+            if (index % 2) {
+                option.features |= QStyleOptionViewItem::Alternate;
+            }
+            qApp->style()->drawPrimitive(QStyle::PE_PanelItemViewRow, &option, &p, nullptr);
+
+            QStyleOption branch;
+            branch.rect = QRect(int(depth * indentation), 0, int(indentation), option.rect.height());
+            branch.state = QStyle::State_Enabled | QStyle::State_Item;
+            if (has_children) {
+                branch.state |= QStyle::State_Children;
+            }
+            if (expanded) {
+                branch.state |= QStyle::State_Open;
+            }
+            qApp->style()->drawPrimitive(QStyle::PE_IndicatorBranch, &branch, &p, nullptr);
+
+            option.rect.setLeft(int((depth + 1) * indentation));
+            option.features |= QStyleOptionViewItem::HasDisplay;
+            option.text = text;
+            qApp->style()->drawControl(QStyle::CE_ItemViewItem, &option, &p, nullptr);
+        });
+        return HighLevelRenderingPrimitive::Image {
+            source: imgarray.to_resource(),
+            source_clip_rect: Default::default(),
+        };
+    }
+
+    fn rendering_variables(self: Pin<&Self>, _window: &ComponentWindow) -> RenderingVariables {
+        Default::default()
+    }
+
+    fn layouting_info(self: Pin<&Self>, window: &ComponentWindow) -> LayoutInfo {
+        let dpr = window.scale_factor();
+        let index: i32 = Self::FIELD_OFFSETS.index.apply_pin(self).get();
+        let item = Self::FIELD_OFFSETS.item.apply_pin(self).get();
+        let text: qttypes::QString = item.text.as_str().into();
+
+        let s = cpp!(unsafe [
+            index as "int",
+            text as "QString"
+        ] -> qttypes::QSize as "QSize" {
+            ensure_initialized();
+
+            QStyleOptionViewItem option;
+            option.decorationPosition = QStyleOptionViewItem::Left;
+            option.decorationAlignment = Qt::AlignCenter;
+            option.displayAlignment = Qt::AlignLeft|Qt::AlignVCenter;
+            option.showDecorationSelected = qApp->style()->styleHint(QStyle::SH_ItemView_ShowDecorationSelected, nullptr, nullptr);
+            if (index % 2) {
+                option.features |= QStyleOptionViewItem::Alternate;
+            }
+            option.features |= QStyleOptionViewItem::HasDisplay;
+            option.text = text;
+            return qApp->style()->sizeFromContents(QStyle::CT_ItemViewItem, &option, QSize{}, nullptr);
+        });
+        let result = LayoutInfo {
+            min_width: (s.width as f32 + (item.depth + 1) as f32 * TREE_INDENTATION) * dpr,
+            min_height: s.height as f32 * dpr,
+            ..LayoutInfo::default()
+        };
+        result
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _event: MouseEvent,
+        _window: &ComponentWindow,
+        _self_rc: &sixtyfps_corelib::items::ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &ComponentWindow) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &ComponentWindow) {}
+}
+
+impl ItemConsts for NativeTreeViewItem {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+ItemVTable_static! { #[no_mangle] pub static NativeTreeViewItemVTable for NativeTreeViewItem }
+
+#[repr(C)]
+#[derive(FieldOffsets, Default, BuiltinItem)]
+#[pin]
+pub struct NativeTableHeaderSection {
+    pub x: Property<f32>,
+    pub y: Property<f32>,
+    pub width: Property<f32>,
+    pub height: Property<f32>,
+    pub index: Property<i32>,
+    pub title: Property<SharedString>,
+    pub sort_order: Property<sixtyfps_corelib::items::SortOrder>,
+    pub pressed: Property<bool>,
+    pub has_hover: Property<bool>,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for NativeTableHeaderSection {
+    fn init(self: Pin<&Self>, _window: &ComponentWindow) {}
+
+    fn geometry(self: Pin<&Self>) -> Rect {
+        euclid::rect(
+            Self::FIELD_OFFSETS.x.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.y.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.width.apply_pin(self).get(),
+            Self::FIELD_OFFSETS.height.apply_pin(self).get(),
+        )
+    }
+    fn rendering_primitive(
+        self: Pin<&Self>,
+        window: &ComponentWindow,
+    ) -> HighLevelRenderingPrimitive {
+        let size: qttypes::QSize = get_size!(self);
+        let dpr = window.scale_factor();
+        let index: i32 = Self::FIELD_OFFSETS.index.apply_pin(self).get();
+        let title: qttypes::QString =
+            Self::FIELD_OFFSETS.title.apply_pin(self).get().as_str().into();
+        let sort_order = Self::FIELD_OFFSETS.sort_order.apply_pin(self).get() as i32;
+        let pressed: bool = Self::FIELD_OFFSETS.pressed.apply_pin(self).get();
+        let has_hover: bool = Self::FIELD_OFFSETS.has_hover.apply_pin(self).get();
+
+        let mut imgarray = QImageWrapArray::new(size, dpr);
+        let img = &mut imgarray.img;
+
+        cpp!(unsafe [
+            img as "QImage*",
+            size as "QSize",
+            dpr as "float",
+            index as "int",
+            title as "QString",
+            sort_order as "int",
+            pressed as "bool",
+            has_hover as "bool"
+        ] {
+            QPainter p(img);
+            QStyleOptionHeader option;
+            option.rect = QRect(QPoint(), size / dpr);
+            option.state = QStyle::State_Enabled | QStyle::State_Active | QStyle::State_Horizontal;
+            option.state |= pressed ? QStyle::State_Sunken : QStyle::State_Raised;
+            if (has_hover) {
+                option.state |= QStyle::State_MouseOver;
+            }
+            option.orientation = Qt::Horizontal;
+            option.section = index;
+            option.position = index == 0 ? QStyleOptionHeader::Beginning : QStyleOptionHeader::Middle;
+            option.text = title;
+            option.textAlignment = Qt::AlignLeft | Qt::AlignVCenter;
+            // Same as QHeaderView: the ascending order shows the "down" indicator
+            option.sortIndicator = sort_order == 1 ? QStyleOptionHeader::SortDown
+                : sort_order == 2 ? QStyleOptionHeader::SortUp : QStyleOptionHeader::None;
+            qApp->style()->drawControl(QStyle::CE_Header, &option, &p, nullptr);
+        });
+        return HighLevelRenderingPrimitive::Image {
+            source: imgarray.to_resource(),
+            source_clip_rect: Default::default(),
+        };
+    }
+
+    fn rendering_variables(self: Pin<&Self>, _window: &ComponentWindow) -> RenderingVariables {
+        Default::default()
+    }
+
+    fn layouting_info(self: Pin<&Self>, window: &ComponentWindow) -> LayoutInfo {
+        let dpr = window.scale_factor();
+        let title: qttypes::QString =
+            Self::FIELD_OFFSETS.title.apply_pin(self).get().as_str().into();
+
+        let s = cpp!(unsafe [
+            title as "QString"
+        ] -> qttypes::QSize as "QSize" {
+            ensure_initialized();
+
+            QStyleOptionHeader option;
+            option.orientation = Qt::Horizontal;
+            option.text = title;
+            option.sortIndicator = QStyleOptionHeader::SortDown;
+            return qApp->style()->sizeFromContents(QStyle::CT_HeaderSection, &option, QSize{}, nullptr);
+        });
+        let result = LayoutInfo {
+            min_height: s.height as f32 * dpr,
+            max_height: s.height as f32 * dpr,
+            ..LayoutInfo::default()
+        };
+        result
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _event: MouseEvent,
+        _window: &ComponentWindow,
+        _self_rc: &sixtyfps_corelib::items::ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(self: Pin<&Self>, _: &KeyEvent, _window: &ComponentWindow) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(self: Pin<&Self>, _: &FocusEvent, _window: &ComponentWindow) {}
+}
+
+impl ItemConsts for NativeTableHeaderSection {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+ItemVTable_static! { #[no_mangle] pub static NativeTableHeaderSectionVTable for NativeTableHeaderSection }
+
 #[repr(C)]
 #[derive(FieldOffsets, Default, BuiltinItem)]
 #[pin]
@@ -1695,7 +1989,7 @@ pub struct NativeComboBox {
     pub is_open: Property<bool>,
     pub current_value: Property<SharedString>,
     pub cached_rendering_data: CachedRenderingData,
-    pub open_popup: Callback<()>,
+    pub open_popup: Callback<VoidArg>,
 }
 
 impl Item for NativeComboBox {
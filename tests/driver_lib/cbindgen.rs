@@ -32,7 +32,16 @@ fn default_config() -> cbindgen::Config {
         cpp_compat: true,
         documentation: true,
         export: cbindgen::ExportConfig {
-            rename: [("Callback".into(), "Callback<>".into())].iter().cloned().collect(),
+            rename: [
+                ("Callback".into(), "private_api::CallbackHelper".into()),
+                ("VoidArg".into(), "void".into()),
+                ("PointerEventArg".into(), "PointerEvent".into()),
+                ("DropEventArg".into(), "DropEvent".into()),
+                ("KeyboardEventArg".into(), "KeyboardEvent".into()),
+            ]
+            .iter()
+            .cloned()
+            .collect(),
             ..Default::default()
         },
         ..Default::default()
@@ -55,9 +64,26 @@ fn gen_corelib(include_dir: &Path) -> anyhow::Result<()> {
         "ComponentWindowOpaque",
         "PropertyAnimation",
         "EasingCurve",
+        "KeyFrame",
         "TextHorizontalAlignment",
         "TextVerticalAlignment",
         "ImageFit",
+        "PointerEventButton",
+        "PointerEventKind",
+        "PointerEvent",
+        "MouseCursor",
+        "AccessibleRole",
+        "SortOrder",
+        "TouchPhase",
+        "PinchArea",
+        "DragArea",
+        "DropArea",
+        "DropEvent",
+        "FocusScope",
+        "KeyboardEvent",
+        "KeyboardModifierState",
+        "Shortcut",
+        "TimerItem",
         "Window",
         "TextInput",
         "Clip",
@@ -82,8 +108,13 @@ fn gen_corelib(include_dir: &Path) -> anyhow::Result<()> {
         "sixtyfps_property_listener_scope_evaluate",
         "sixtyfps_property_listener_scope_is_dirty",
         "PropertyTrackerOpaque",
+        "ChangeTrackerOpaque",
         "CallbackOpaque",
         "ComponentWindow",
+        "VoidArg",
+        "PointerEventArg",
+        "DropEventArg",
+        "KeyboardEventArg",
     ]
     .iter()
     .map(|x| x.to_string())
@@ -159,6 +190,8 @@ fn gen_corelib(include_dir: &Path) -> anyhow::Result<()> {
             "sixtyfps_component_window_show_popup",
             "sixtyfps_new_path_elements",
             "sixtyfps_new_path_events",
+            "sixtyfps_set_animation_speed",
+            "sixtyfps_animation_elapsed_time",
         ]
         .iter()
         .filter(|exclusion| rust_types.iter().find(|inclusion| inclusion == exclusion).is_none())
@@ -204,7 +237,9 @@ fn gen_corelib(include_dir: &Path) -> anyhow::Result<()> {
     config.export.body.insert(
         "EasingCurve".to_owned(),
         "    constexpr EasingCurve() : tag(Tag::Linear), cubic_bezier{{0,0,1,1}} {}
-    constexpr explicit EasingCurve(EasingCurve::Tag tag, float a, float b, float c, float d) : tag(tag), cubic_bezier{{a,b,c,d}} {}".into()
+    constexpr explicit EasingCurve(EasingCurve::Tag tag) : tag(tag), cubic_bezier{{0,0,1,1}} {}
+    constexpr explicit EasingCurve(EasingCurve::Tag tag, float a, float b, float c, float d) : tag(tag), cubic_bezier{{a,b,c,d}} {}
+    constexpr explicit EasingCurve(EasingCurve::Tag tag, uint32_t n) : tag(tag), steps{n} {}".into()
     );
     config.export.body.insert(
         "LayoutInfo".to_owned(),
@@ -215,7 +250,19 @@ fn gen_corelib(include_dir: &Path) -> anyhow::Result<()> {
         .body
         .insert("Flickable".to_owned(), "    inline Flickable(); inline ~Flickable();".into());
     config.export.pre_body.insert("FlickableDataBox".to_owned(), "struct FlickableData;".into());
+    config
+        .export
+        .body
+        .insert("TimerItem".to_owned(), "    inline TimerItem(); inline ~TimerItem();".into());
+    config.export.pre_body.insert("TimerItemDataBox".to_owned(), "struct TimerItemData;".into());
+    config
+        .export
+        .body
+        .insert("Shortcut".to_owned(), "    inline Shortcut(); inline ~Shortcut();".into());
+    config.export.pre_body.insert("ShortcutDataBox".to_owned(), "struct ShortcutData;".into());
     config.export.include.push("StandardListViewItem".into());
+    config.export.include.push("TreeViewItem".into());
+    config.export.include.push("TableColumn".into());
     cbindgen::Builder::new()
         .with_config(config)
         .with_src(crate_dir.join("lib.rs"))
@@ -253,6 +300,8 @@ fn gen_backend_qt(include_dir: &Path) -> anyhow::Result<()> {
         "NativeLineEdit",
         "NativeScrollView",
         "NativeStandardListViewItem",
+        "NativeTreeViewItem",
+        "NativeTableHeaderSection",
         "NativeComboBox",
     ]
     .iter()
@@ -11,6 +11,8 @@
 use std::io::Write;
 use structopt::StructOpt;
 
+mod pot;
+
 #[derive(StructOpt)]
 struct Cli {
     /// Set output format
@@ -37,6 +39,11 @@ struct Cli {
     /// Sets the output file ('-' for stdout)
     #[structopt(name = "file to generate", short = "o", default_value = "-")]
     output: std::path::PathBuf,
+
+    /// Instead of generating code, write the strings marked with @tr() in the file and
+    /// the files it imports to this gettext template (.pot) file
+    #[structopt(name = "pot file", long = "extract-translations", parse(from_os_str))]
+    extract_translations: Option<std::path::PathBuf>,
 }
 
 fn main() -> std::io::Result<()> {
@@ -53,6 +60,22 @@ fn main() -> std::io::Result<()> {
 
     let mut diag = diag.check_and_exit_on_error();
 
+    if let Some(pot_file) = args.extract_translations {
+        let mut messages = pot::Messages::default();
+        messages.extract_from_file(&args.path)?;
+        for file in diag.files() {
+            if file.is_absolute()
+                && file.extension().map_or(false, |ext| ext == "60")
+                && !same_file(file, &args.path)
+            {
+                messages.extract_from_file(file)?;
+            }
+        }
+        messages.write_pot(&mut std::fs::File::create(pot_file)?)?;
+        diag.print_warnings_and_exit_on_error();
+        return Ok(());
+    }
+
     if args.output == std::path::Path::new("-") {
         generator::generate(args.format, &mut std::io::stdout(), &doc, &mut diag)?;
     } else {
@@ -81,3 +104,10 @@ fn main() -> std::io::Result<()> {
     diag.print_warnings_and_exit_on_error();
     Ok(())
 }
+
+fn same_file(a: &std::path::Path, b: &std::path::Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
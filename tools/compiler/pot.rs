@@ -0,0 +1,106 @@
+/* LICENSE BEGIN
+    This file is part of the SixtyFPS Project -- https://sixtyfps.io
+    Copyright (c) 2020 Olivier Goffart <olivier.goffart@sixtyfps.io>
+    Copyright (c) 2020 Simon Hausmann <simon.hausmann@sixtyfps.io>
+
+    SPDX-License-Identifier: GPL-3.0-only
+    This file is also available under commercial licensing terms.
+    Please contact info@sixtyfps.io for more information.
+LICENSE END */
+//! Extraction of the strings marked with `@tr(...)` into a gettext template (`.pot` file)
+
+use sixtyfps_compilerlib::parser::{SyntaxKind, SyntaxNode, SyntaxNodeEx};
+use std::io::Write;
+use std::path::Path;
+
+/// A translatable string, and the places where it is used
+struct Message {
+    context: String,
+    id: String,
+    plural: Option<String>,
+    locations: Vec<String>,
+}
+
+/// The messages extracted from a set of files, in the order of their first use
+#[derive(Default)]
+pub struct Messages(Vec<Message>);
+
+/// Returns the value of the string literal token of the node
+fn string_literal(node: &SyntaxNode) -> Option<String> {
+    sixtyfps_compilerlib::lexer::unescape_string(&node.child_text(SyntaxKind::StringLiteral)?)
+}
+
+impl Messages {
+    /// Adds the messages of all the `@tr(...)` in the file
+    pub fn extract_from_file(&mut self, path: &Path) -> std::io::Result<()> {
+        let source = std::fs::read_to_string(path)?;
+        let (syntax_node, _) = sixtyfps_compilerlib::parser::parse(source.clone(), Some(path));
+        for node in syntax_node.node.descendants().filter(|n| n.kind() == SyntaxKind::AtTr) {
+            let id = match string_literal(&node) {
+                Some(id) => id,
+                None => continue,
+            };
+            let context = node
+                .child_node(SyntaxKind::TrContext)
+                .and_then(|n| string_literal(&n))
+                .unwrap_or_default();
+            let plural = node.child_node(SyntaxKind::TrPlural).and_then(|n| string_literal(&n));
+            let offset: usize = node.text_range().start().into();
+            let line = source[..offset].matches('\n').count() + 1;
+            let location = format!("{}:{}", path.display(), line);
+
+            match self.0.iter_mut().find(|m| m.context == context && m.id == id) {
+                Some(message) => {
+                    message.locations.push(location);
+                    if message.plural.is_none() {
+                        message.plural = plural;
+                    }
+                }
+                None => self.0.push(Message { context, id, plural, locations: vec![location] }),
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the messages in the `.pot` format
+    pub fn write_pot(&self, out: &mut impl Write) -> std::io::Result<()> {
+        writeln!(out, "msgid \"\"")?;
+        writeln!(out, "msgstr \"\"")?;
+        writeln!(out, "\"Content-Type: text/plain; charset=UTF-8\\n\"")?;
+        writeln!(out, "\"Content-Transfer-Encoding: 8bit\\n\"")?;
+        for message in &self.0 {
+            writeln!(out)?;
+            writeln!(out, "#: {}", message.locations.join(" "))?;
+            if !message.context.is_empty() {
+                writeln!(out, "msgctxt {}", po_string(&message.context))?;
+            }
+            writeln!(out, "msgid {}", po_string(&message.id))?;
+            match &message.plural {
+                Some(plural) => {
+                    writeln!(out, "msgid_plural {}", po_string(plural))?;
+                    writeln!(out, "msgstr[0] \"\"")?;
+                    writeln!(out, "msgstr[1] \"\"")?;
+                }
+                None => writeln!(out, "msgstr \"\"")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Quote and escape a string for the `.pot` file
+fn po_string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => result += "\\\"",
+            '\\' => result += "\\\\",
+            '\n' => result += "\\n",
+            '\t' => result += "\\t",
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}